@@ -0,0 +1,43 @@
+-- Local documents ingested into a chat (chat_id set) or into the global library (chat_id NULL)
+CREATE TABLE IF NOT EXISTS documents (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    user_id TEXT NOT NULL,
+    chat_id TEXT,                 -- NULL = global library
+    file_name TEXT NOT NULL,
+    file_path TEXT NOT NULL,
+    file_type TEXT NOT NULL,      -- markdown | text | code | pdf | docx
+    content_hash TEXT NOT NULL,   -- SHA-256 of the extracted text
+    chunk_count INTEGER NOT NULL DEFAULT 0,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (chat_id) REFERENCES chats(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_documents_user_id ON documents(user_id);
+CREATE INDEX IF NOT EXISTS idx_documents_chat_id ON documents(chat_id);
+
+CREATE TABLE IF NOT EXISTS document_chunks (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    document_id TEXT NOT NULL,
+    chunk_index INTEGER NOT NULL,
+    content TEXT NOT NULL,
+    start_line INTEGER NOT NULL,  -- 1-based, inclusive
+    end_line INTEGER NOT NULL,    -- 1-based, inclusive
+    FOREIGN KEY (document_id) REFERENCES documents(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_document_chunks_document_id ON document_chunks(document_id, chunk_index);
+
+-- Full-text index over chunk content, kept in sync by triggers
+CREATE VIRTUAL TABLE IF NOT EXISTS document_chunks_fts USING fts5(
+    content,
+    chunk_id UNINDEXED,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER IF NOT EXISTS document_chunks_ai AFTER INSERT ON document_chunks BEGIN
+    INSERT INTO document_chunks_fts (content, chunk_id) VALUES (new.content, new.id);
+END;
+
+CREATE TRIGGER IF NOT EXISTS document_chunks_ad AFTER DELETE ON document_chunks BEGIN
+    DELETE FROM document_chunks_fts WHERE chunk_id = old.id;
+END;
@@ -0,0 +1,58 @@
+-- Local code repositories indexed for project-aware answers
+CREATE TABLE IF NOT EXISTS workspaces (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    user_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    root_path TEXT NOT NULL,
+    last_indexed_at DATETIME,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(user_id, root_path)
+);
+
+CREATE TABLE IF NOT EXISTS workspace_files (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    workspace_id TEXT NOT NULL,
+    relative_path TEXT NOT NULL,
+    language TEXT NOT NULL,
+    mtime INTEGER NOT NULL,       -- epoch seconds, first-pass change detection
+    content_hash TEXT NOT NULL,   -- SHA-256, avoids re-chunking on touch-only changes
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE,
+    UNIQUE(workspace_id, relative_path)
+);
+
+CREATE TABLE IF NOT EXISTS workspace_chunks (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    workspace_id TEXT NOT NULL,
+    file_id TEXT NOT NULL,
+    symbol TEXT,                  -- function/type/section name when known
+    content TEXT NOT NULL,
+    start_line INTEGER NOT NULL,
+    end_line INTEGER NOT NULL,
+    FOREIGN KEY (file_id) REFERENCES workspace_files(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_workspace_chunks_file_id ON workspace_chunks(file_id);
+
+CREATE VIRTUAL TABLE IF NOT EXISTS workspace_chunks_fts USING fts5(
+    content,
+    path,
+    symbol,
+    chunk_id UNINDEXED,
+    workspace_id UNINDEXED,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER IF NOT EXISTS workspace_chunks_ai AFTER INSERT ON workspace_chunks BEGIN
+    INSERT INTO workspace_chunks_fts (content, path, symbol, chunk_id, workspace_id)
+    VALUES (
+        new.content,
+        (SELECT relative_path FROM workspace_files WHERE id = new.file_id),
+        COALESCE(new.symbol, ''),
+        new.id,
+        new.workspace_id
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS workspace_chunks_ad AFTER DELETE ON workspace_chunks BEGIN
+    DELETE FROM workspace_chunks_fts WHERE chunk_id = old.id;
+END;
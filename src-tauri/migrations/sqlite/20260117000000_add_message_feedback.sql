@@ -0,0 +1,19 @@
+-- Thumbs up/down feedback on assistant messages.
+-- prompt_preset_id and model are snapshotted from the chat so stats survive preset/model changes.
+CREATE TABLE IF NOT EXISTS message_feedback (
+    id TEXT PRIMARY KEY,                 -- UUID as TEXT
+    message_id TEXT NOT NULL UNIQUE,     -- One feedback per message, re-rating replaces it
+    chat_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    rating INTEGER NOT NULL,             -- 1 = positive, -1 = negative
+    reason TEXT,                         -- e.g. "wrong", "unhelpful", "too_long"
+    comment TEXT,
+    prompt_preset_id TEXT,
+    model TEXT,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (message_id) REFERENCES messages(id) ON DELETE CASCADE,
+    FOREIGN KEY (chat_id) REFERENCES chats(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_message_feedback_chat ON message_feedback (chat_id);
+CREATE INDEX IF NOT EXISTS idx_message_feedback_preset_model ON message_feedback (prompt_preset_id, model);
@@ -0,0 +1,3 @@
+-- User-defined template parameters for prompt presets: JSON array of
+-- {"name": string, "default_value": string, "description": string | null}
+ALTER TABLE prompt_presets ADD COLUMN parameters TEXT NOT NULL DEFAULT '[]';
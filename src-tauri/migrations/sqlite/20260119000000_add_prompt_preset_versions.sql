@@ -0,0 +1,14 @@
+-- Snapshot of a custom preset after every create/update/rollback/import
+CREATE TABLE IF NOT EXISTS prompt_preset_versions (
+    id TEXT PRIMARY KEY,              -- UUID as TEXT
+    preset_id TEXT NOT NULL,
+    version INTEGER NOT NULL,         -- 1-based, increasing per preset
+    name TEXT NOT NULL,
+    description TEXT,
+    prompt TEXT NOT NULL,
+    parameters TEXT NOT NULL DEFAULT '[]',
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (preset_id, version),
+    FOREIGN KEY (preset_id) REFERENCES prompt_presets(id) ON DELETE CASCADE
+);
+
@@ -0,0 +1,19 @@
+-- Compare mode: several assistant replies to the same user turn.
+-- Alternatives stay out of the chat thread (and the AI context) until one is picked.
+ALTER TABLE messages ADD COLUMN reply_to_id TEXT;
+ALTER TABLE messages ADD COLUMN is_alternative INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_messages_reply_to ON messages (reply_to_id);
+
+-- Which provider/model produced an assistant message, and what it cost
+CREATE TABLE IF NOT EXISTS message_generations (
+    message_id TEXT PRIMARY KEY,      -- UUID as TEXT
+    provider TEXT NOT NULL,
+    model TEXT NOT NULL,
+    latency_ms INTEGER NOT NULL,
+    prompt_tokens INTEGER NOT NULL DEFAULT 0,
+    completion_tokens INTEGER NOT NULL DEFAULT 0,
+    total_tokens INTEGER NOT NULL DEFAULT 0,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (message_id) REFERENCES messages(id) ON DELETE CASCADE
+);
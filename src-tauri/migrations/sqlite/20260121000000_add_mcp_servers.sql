@@ -0,0 +1,17 @@
+-- External Model Context Protocol servers the chat can call tools on
+CREATE TABLE IF NOT EXISTS mcp_servers (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    user_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    transport TEXT NOT NULL,      -- 'stdio' | 'http'
+    command TEXT,                 -- stdio: executable to launch
+    args TEXT NOT NULL DEFAULT '[]',  -- stdio: JSON array of arguments
+    env TEXT NOT NULL DEFAULT '{}',   -- stdio: JSON object of extra environment variables
+    url TEXT,                     -- http: local endpoint (Streamable HTTP / SSE)
+    enabled INTEGER NOT NULL DEFAULT 1,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(user_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_servers_user_id ON mcp_servers(user_id);
@@ -0,0 +1,22 @@
+-- Side effects the model asked for; they only run once the user approves them
+CREATE TABLE IF NOT EXISTS proposed_actions (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    message_id TEXT NOT NULL,     -- assistant message that proposed the action
+    chat_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    tool TEXT NOT NULL,           -- 'calendar_event' | 'notion_page' | 'mcp'
+    title TEXT NOT NULL,          -- human-readable summary shown in the approval UI
+    arguments TEXT NOT NULL,      -- JSON, editable until the action is resolved
+    status TEXT NOT NULL DEFAULT 'pending', -- 'pending' | 'executed' | 'failed' | 'rejected'
+    result TEXT,
+    provider_name TEXT,           -- model that proposed it, reused for MCP follow-up replies
+    model TEXT,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    resolved_at DATETIME,
+    FOREIGN KEY (message_id) REFERENCES messages(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_proposed_actions_chat_id ON proposed_actions(chat_id);
+
+-- Tools that run without asking (e.g. 'calendar_event', 'mcp:server/tool')
+ALTER TABLE app_config ADD COLUMN auto_approve_tools TEXT NOT NULL DEFAULT '[]';
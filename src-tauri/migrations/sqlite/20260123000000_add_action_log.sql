@@ -0,0 +1,19 @@
+-- Every tool the assistant ran on the user's behalf, successful or not
+CREATE TABLE IF NOT EXISTS action_log (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    user_id TEXT NOT NULL,
+    chat_id TEXT NOT NULL,
+    message_id TEXT NOT NULL,     -- assistant message that proposed the action
+    action_id TEXT,               -- proposed_actions.id
+    tool TEXT NOT NULL,
+    arguments TEXT NOT NULL,      -- JSON, as executed (after user edits)
+    result TEXT,
+    succeeded INTEGER NOT NULL,
+    undo_handle TEXT,             -- JSON describing how to revert, NULL when irreversible
+    undone_at DATETIME,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Kept after chats are deleted on purpose: it is an audit trail
+CREATE INDEX IF NOT EXISTS idx_action_log_user_id ON action_log(user_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_action_log_chat_id ON action_log(chat_id);
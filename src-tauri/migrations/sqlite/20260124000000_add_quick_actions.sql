@@ -0,0 +1,66 @@
+-- Built-in presets for the clipboard / selection quick actions.
+-- The captured text is sent as the user message and is also available as {{clipboard}}.
+INSERT INTO prompt_presets (id, name, description, prompt, is_built_in, preset_type, parameters) VALUES
+(
+    'quick_explain',
+    'Explain',
+    'Explains the selected text in plain words.',
+    'The user selected a piece of text and wants to understand it.
+Explain what it means in plain words, briefly and without repeating the text back.
+If it is code, describe what it does. If it contains jargon, define the terms.',
+    1,
+    'quick_action',
+    '[]'
+),
+(
+    'quick_translate',
+    'Translate',
+    'Translates the selected text.',
+    'Translate the text sent by the user into {{target_language}}.
+Reply with the translation only, keeping the original formatting, tone and line breaks.',
+    1,
+    'quick_action',
+    '[{"name": "target_language", "default_value": "English", "description": "Language to translate into"}]'
+),
+(
+    'quick_fix_grammar',
+    'Fix grammar',
+    'Fixes spelling and grammar in the selected text.',
+    'Fix the spelling, grammar and punctuation of the text sent by the user.
+Keep its language, meaning, tone and formatting. Reply with the corrected text only.',
+    1,
+    'quick_action',
+    '[]'
+),
+(
+    'quick_review_code',
+    'Review code',
+    'Reviews the selected code snippet.',
+    'Review the code sent by the user.
+Point out bugs, edge cases and readability issues, most important first, and suggest concrete fixes.
+Keep it short; do not rewrite the whole snippet unless it is necessary.',
+    1,
+    'quick_action',
+    '[]'
+);
+
+-- Global shortcuts that run a preset on the clipboard or the selected text
+CREATE TABLE IF NOT EXISTS quick_actions (
+    id TEXT PRIMARY KEY,              -- UUID as TEXT
+    name TEXT NOT NULL,
+    shortcut TEXT NOT NULL UNIQUE,    -- accelerator, e.g. 'CommandOrControl+Alt+E'
+    prompt_preset_id TEXT NOT NULL REFERENCES prompt_presets(id) ON DELETE CASCADE,
+    source TEXT NOT NULL DEFAULT 'selection', -- 'clipboard' | 'selection' (PRIMARY on Linux, clipboard elsewhere)
+    template_values TEXT NOT NULL DEFAULT '{}', -- JSON object of preset parameter values
+    provider_name TEXT,               -- NULL: first configured API key
+    model TEXT,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO quick_actions (id, name, shortcut, prompt_preset_id, source) VALUES
+('0b6f3c52-6f0e-4d0a-9a51-5a1f3e7c0e01', 'Explain', 'CommandOrControl+Alt+E', 'quick_explain', 'selection'),
+('0b6f3c52-6f0e-4d0a-9a51-5a1f3e7c0e02', 'Translate', 'CommandOrControl+Alt+T', 'quick_translate', 'selection'),
+('0b6f3c52-6f0e-4d0a-9a51-5a1f3e7c0e03', 'Fix grammar', 'CommandOrControl+Alt+G', 'quick_fix_grammar', 'selection'),
+('0b6f3c52-6f0e-4d0a-9a51-5a1f3e7c0e04', 'Review code', 'CommandOrControl+Alt+R', 'quick_review_code', 'selection');
@@ -0,0 +1,3 @@
+-- Keyset pagination walks (created_at, id) from the newest row backwards
+CREATE INDEX IF NOT EXISTS idx_messages_chat_id_created_at_id ON messages(chat_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_chats_user_id_created_at_id ON chats(user_id, created_at DESC, id DESC);
@@ -0,0 +1,22 @@
+-- Chats the user wants to keep, and chats set aside by a retention rule
+ALTER TABLE chats ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE chats ADD COLUMN tags TEXT NOT NULL DEFAULT '[]'; -- JSON array of strings
+ALTER TABLE chats ADD COLUMN archived_at DATETIME;
+
+-- Automatic cleanup rules, evaluated by the background retention job
+CREATE TABLE IF NOT EXISTS retention_rules (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    user_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    action TEXT NOT NULL,         -- 'delete' | 'archive' | 'drop_content'
+    older_than_days INTEGER NOT NULL, -- measured from the chat's last activity (updated_at)
+    keep_pinned INTEGER NOT NULL DEFAULT 1,
+    keep_tagged INTEGER NOT NULL DEFAULT 1,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    last_run_at DATETIME,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_retention_rules_user_id ON retention_rules(user_id);
+CREATE INDEX IF NOT EXISTS idx_chats_user_id_updated_at_pinned ON chats(user_id, updated_at, pinned);
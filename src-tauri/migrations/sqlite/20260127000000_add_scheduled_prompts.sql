@@ -0,0 +1,24 @@
+-- Prompts run on a cron-like schedule by the background scheduler
+CREATE TABLE IF NOT EXISTS scheduled_prompts (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    user_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    cron TEXT NOT NULL,           -- 'minute hour day month weekday', local time
+    prompt_preset_id TEXT REFERENCES prompt_presets(id) ON DELETE SET NULL,
+    prompt TEXT NOT NULL DEFAULT '',
+    context_sources TEXT NOT NULL DEFAULT '[]', -- JSON array, e.g. [{"type":"calendar","days":1}]
+    provider_name TEXT,
+    model TEXT,
+    chat_id TEXT REFERENCES chats(id) ON DELETE SET NULL, -- chat the results are appended to
+    deliver_email INTEGER NOT NULL DEFAULT 0,
+    deliver_notification INTEGER NOT NULL DEFAULT 1,
+    catch_up TEXT NOT NULL DEFAULT 'run_once', -- 'skip' | 'run_once' | 'run_all'
+    enabled INTEGER NOT NULL DEFAULT 1,
+    last_run_at DATETIME,
+    next_run_at DATETIME,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_scheduled_prompts_user_id ON scheduled_prompts(user_id);
+CREATE INDEX IF NOT EXISTS idx_scheduled_prompts_next_run_at ON scheduled_prompts(enabled, next_run_at);
@@ -0,0 +1,19 @@
+-- Todos and action items, extracted from chats or added by hand
+CREATE TABLE IF NOT EXISTS tasks (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    user_id TEXT NOT NULL,
+    chat_id TEXT REFERENCES chats(id) ON DELETE SET NULL,
+    source_message_id TEXT REFERENCES messages(id) ON DELETE SET NULL,
+    title TEXT NOT NULL,
+    notes TEXT,
+    due_at DATETIME,
+    due_all_day INTEGER NOT NULL DEFAULT 0, -- due_at is local midnight of the due day
+    status TEXT NOT NULL DEFAULT 'open',    -- 'open' | 'done' | 'dismissed'
+    calendar_event_id TEXT,
+    completed_at DATETIME,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_tasks_user_id_status ON tasks(user_id, status, due_at);
+CREATE INDEX IF NOT EXISTS idx_tasks_chat_id ON tasks(chat_id);
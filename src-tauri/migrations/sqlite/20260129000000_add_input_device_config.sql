@@ -0,0 +1,2 @@
+-- Microphone chosen by the user (cpal device name); NULL records from the system default
+ALTER TABLE app_config ADD COLUMN input_device TEXT;
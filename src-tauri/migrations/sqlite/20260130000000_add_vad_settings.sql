@@ -0,0 +1,6 @@
+-- Voice activity detection tuning per user (JSON-serialized VadConfig)
+CREATE TABLE IF NOT EXISTS vad_settings (
+    user_id TEXT PRIMARY KEY,
+    config TEXT NOT NULL,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,8 @@
+-- Transcription provider chosen per user; API keys come from user_api_keys
+CREATE TABLE IF NOT EXISTS transcription_settings (
+    user_id TEXT PRIMARY KEY,
+    provider TEXT NOT NULL DEFAULT 'local',
+    model TEXT,
+    base_url TEXT,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,18 @@
+-- Recording sessions with their stored audio and transcript
+CREATE TABLE IF NOT EXISTS meetings (
+    id TEXT PRIMARY KEY,          -- UUID as TEXT
+    user_id TEXT NOT NULL,
+    title TEXT NOT NULL,
+    audio_path TEXT NOT NULL,
+    duration_ms INTEGER NOT NULL DEFAULT 0,
+    language TEXT,
+    segments TEXT NOT NULL DEFAULT '[]', -- JSON array of transcript segments
+    calendar_event_id TEXT,
+    chat_id TEXT REFERENCES chats(id) ON DELETE SET NULL,
+    started_at DATETIME NOT NULL,
+    ended_at DATETIME,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_meetings_user_id_started_at ON meetings(user_id, started_at);
@@ -0,0 +1,3 @@
+-- Add citations column to messages table
+-- Stored as JSON string of the document/workspace ranges the answer drew on
+ALTER TABLE messages ADD COLUMN citations TEXT;
@@ -0,0 +1,11 @@
+-- The seeded Ctrl+Alt shortcuts collide with desktop bindings (Ctrl+Alt+T opens a
+-- terminal on GNOME) and with AltGr characters on Windows (Ctrl+Alt+E is € on
+-- many layouts). Leave them off until the user turns them on or picks another chord.
+UPDATE quick_actions SET enabled = 0
+WHERE (id, shortcut) IN (
+    VALUES
+    ('0b6f3c52-6f0e-4d0a-9a51-5a1f3e7c0e01', 'CommandOrControl+Alt+E'),
+    ('0b6f3c52-6f0e-4d0a-9a51-5a1f3e7c0e02', 'CommandOrControl+Alt+T'),
+    ('0b6f3c52-6f0e-4d0a-9a51-5a1f3e7c0e03', 'CommandOrControl+Alt+G'),
+    ('0b6f3c52-6f0e-4d0a-9a51-5a1f3e7c0e04', 'CommandOrControl+Alt+R')
+);
@@ -15,6 +15,7 @@ use crate::{
                 service::chat_service::ChatService,
             },
             provider::AiProvider,
+            audio::repository::{TranscriptionSettingsRepository, VadSettingsRepository},
         },
         notification::email::{
             repository::email_sender::EmailSender,
@@ -33,6 +34,19 @@ use crate::{
         changelog::repository::ChangelogRepository,
         calendar::repository::CalendarRepository,
         notion::repository::NotionRepository,
+        document::repository::DocumentRepository,
+        workspace::repository::WorkspaceRepository,
+        feedback::repository::FeedbackRepository,
+        mcp::repository::McpServerRepository,
+        action::{
+            notifier::ActionNotifier,
+            repository::{ActionLogRepository, ProposedActionRepository},
+        },
+        quick_action::repository::QuickActionRepository,
+        retention::repository::RetentionRuleRepository,
+        schedule::repository::ScheduledPromptRepository,
+        task::repository::TaskRepository,
+        meeting::repository::MeetingRepository,
     },
     infrastructure::{
         ai::{
@@ -40,12 +54,21 @@ use crate::{
                 chat_service_impl::ChatServiceImpl,
                 sqlite_chat_repository::SqliteChatRepository,
                 sqlite_message_repository::SqliteMessageRepository,
+                incognito_store::IncognitoStore,
+                incognito_chat_repository::IncognitoChatRepository,
+                incognito_message_repository::IncognitoMessageRepository,
             },
             provider::{
                 gemini::GeminiClient,
                 openai::OpenAIProvider,
                 openrouter::OpenRouterProvider,
             },
+            audio::{
+                sqlite_transcription_settings_repository::SqliteTranscriptionSettingsRepository,
+                sqlite_vad_settings_repository::SqliteVadSettingsRepository,
+                whisper_local::WhisperEngine,
+                whisper_models::WhisperDownloads,
+            },
         },
         database::{
             sqlite::{connect_sqlite, migrate_sqlite},
@@ -72,6 +95,22 @@ use crate::{
             noop_repository::NoOpNotionRepository,
             client::NotionClient,
         },
+        document::sqlite_repository::SqliteDocumentRepository,
+        workspace::sqlite_repository::SqliteWorkspaceRepository,
+        feedback::sqlite_repository::SqliteFeedbackRepository,
+        mcp::{
+            sqlite_repository::SqliteMcpServerRepository,
+            manager::McpManager,
+        },
+        action::{
+            sqlite_repository::SqliteProposedActionRepository,
+            sqlite_log_repository::SqliteActionLogRepository,
+        },
+        quick_action::sqlite_repository::SqliteQuickActionRepository,
+        retention::sqlite_repository::SqliteRetentionRuleRepository,
+        schedule::sqlite_repository::SqliteScheduledPromptRepository,
+        task::sqlite_repository::SqliteTaskRepository,
+        meeting::sqlite_repository::SqliteMeetingRepository,
 
     },
 };
@@ -83,6 +122,7 @@ pub struct AppState {
 
     pub sqlite_chat_repo: Arc<dyn ChatRepository>,
     pub sqlite_message_repo: Arc<dyn MessageRepository>,
+    pub incognito_store: Arc<IncognitoStore>,
 
     pub config_repo: Arc<dyn ConfigRepository>,
     pub prompt_preset_repo: Arc<dyn PromptPresetRepository>,
@@ -91,6 +131,23 @@ pub struct AppState {
     pub calendar_repo: Arc<dyn CalendarRepository>,
     pub notion_repo: Arc<dyn NotionRepository>,
     pub notion_client: Arc<NotionClient>,
+    pub document_repo: Arc<dyn DocumentRepository>,
+    pub workspace_repo: Arc<dyn WorkspaceRepository>,
+    pub feedback_repo: Arc<dyn FeedbackRepository>,
+    pub mcp_server_repo: Arc<dyn McpServerRepository>,
+    pub mcp_manager: Arc<McpManager>,
+    pub action_repo: Arc<dyn ProposedActionRepository>,
+    pub action_notifier: Arc<ActionNotifier>,
+    pub action_log_repo: Arc<dyn ActionLogRepository>,
+    pub quick_action_repo: Arc<dyn QuickActionRepository>,
+    pub retention_rule_repo: Arc<dyn RetentionRuleRepository>,
+    pub scheduled_prompt_repo: Arc<dyn ScheduledPromptRepository>,
+    pub task_repo: Arc<dyn TaskRepository>,
+    pub meeting_repo: Arc<dyn MeetingRepository>,
+    pub vad_settings_repo: Arc<dyn VadSettingsRepository>,
+    pub transcription_settings_repo: Arc<dyn TranscriptionSettingsRepository>,
+    pub whisper_engine: Arc<WhisperEngine>,
+    pub whisper_downloads: Arc<WhisperDownloads>,
 
     pub chat_service: Arc<dyn ChatService>,
 
@@ -110,10 +167,16 @@ impl AppState {
         migrate_sqlite(&sqlite_pool).await?;
 
         // Initialize Sqlite Repos (always needed)
-        let sqlite_chat_repo: Arc<dyn ChatRepository> =
-            Arc::new(SqliteChatRepository::new(sqlite_pool.clone()));
-        let sqlite_message_repo: Arc<dyn MessageRepository> =
-            Arc::new(SqliteMessageRepository::new(sqlite_pool.clone()));
+        // Incognito chats are routed to memory before they can reach SQLite
+        let incognito_store = Arc::new(IncognitoStore::new());
+        let sqlite_chat_repo: Arc<dyn ChatRepository> = Arc::new(IncognitoChatRepository::new(
+            Arc::new(SqliteChatRepository::new(sqlite_pool.clone())),
+            incognito_store.clone(),
+        ));
+        let sqlite_message_repo: Arc<dyn MessageRepository> = Arc::new(IncognitoMessageRepository::new(
+            Arc::new(SqliteMessageRepository::new(sqlite_pool.clone())),
+            incognito_store.clone(),
+        ));
         let session_repo: Arc<dyn SessionRepository> =
             Arc::new(SqliteSessionRepository::new(sqlite_pool.clone()));
         
@@ -126,6 +189,45 @@ impl AppState {
         let maintenance_repo: Arc<dyn MaintenanceRepository> =
             Arc::new(SqliteMaintenanceRepository::new(sqlite_pool.clone()));
 
+        let document_repo: Arc<dyn DocumentRepository> =
+            Arc::new(SqliteDocumentRepository::new(sqlite_pool.clone()));
+
+        let workspace_repo: Arc<dyn WorkspaceRepository> =
+            Arc::new(SqliteWorkspaceRepository::new(sqlite_pool.clone()));
+
+        let feedback_repo: Arc<dyn FeedbackRepository> =
+            Arc::new(SqliteFeedbackRepository::new(sqlite_pool.clone()));
+
+        let mcp_server_repo: Arc<dyn McpServerRepository> =
+            Arc::new(SqliteMcpServerRepository::new(sqlite_pool.clone()));
+        let mcp_manager = Arc::new(McpManager::new(mcp_server_repo.clone()));
+
+        let action_repo: Arc<dyn ProposedActionRepository> =
+            Arc::new(SqliteProposedActionRepository::new(sqlite_pool.clone()));
+        let action_notifier = Arc::new(ActionNotifier::new());
+        let action_log_repo: Arc<dyn ActionLogRepository> =
+            Arc::new(SqliteActionLogRepository::new(sqlite_pool.clone()));
+
+        let quick_action_repo: Arc<dyn QuickActionRepository> =
+            Arc::new(SqliteQuickActionRepository::new(sqlite_pool.clone()));
+
+        let retention_rule_repo: Arc<dyn RetentionRuleRepository> =
+            Arc::new(SqliteRetentionRuleRepository::new(sqlite_pool.clone()));
+
+        let scheduled_prompt_repo: Arc<dyn ScheduledPromptRepository> =
+            Arc::new(SqliteScheduledPromptRepository::new(sqlite_pool.clone()));
+
+        let task_repo: Arc<dyn TaskRepository> =
+            Arc::new(SqliteTaskRepository::new(sqlite_pool.clone()));
+
+        let meeting_repo: Arc<dyn MeetingRepository> =
+            Arc::new(SqliteMeetingRepository::new(sqlite_pool.clone()));
+
+        let vad_settings_repo: Arc<dyn VadSettingsRepository> =
+            Arc::new(SqliteVadSettingsRepository::new(sqlite_pool.clone()));
+        let transcription_settings_repo: Arc<dyn TranscriptionSettingsRepository> =
+            Arc::new(SqliteTranscriptionSettingsRepository::new(sqlite_pool.clone()));
+
         // ALWAYS use Sqlite for User Api Keys (Local Only)
         let user_api_key_repo: Arc<dyn UserApiKeyRepository> =
             Arc::new(SqliteUserApiKeyRepository::new(sqlite_pool.clone()));
@@ -172,6 +274,13 @@ impl AppState {
             openrouter_provider,
             create_event_usecase,
             create_page_usecase,
+            document_repo.clone(),
+            workspace_repo.clone(),
+            mcp_manager.clone(),
+            action_repo.clone(),
+            action_notifier.clone(),
+            action_log_repo.clone(),
+            task_repo.clone(),
         );
         let chat_service: Arc<dyn ChatService> = Arc::new(chat_service_impl);
 
@@ -196,6 +305,7 @@ impl AppState {
             session_repo,
             sqlite_chat_repo,
             sqlite_message_repo,
+            incognito_store,
             config_repo,
             prompt_preset_repo,
             maintenance_repo,
@@ -203,6 +313,23 @@ impl AppState {
             calendar_repo,
             notion_repo,
             notion_client,
+            document_repo,
+            workspace_repo,
+            feedback_repo,
+            mcp_server_repo,
+            mcp_manager,
+            action_repo,
+            action_notifier,
+            action_log_repo,
+            quick_action_repo,
+            retention_rule_repo,
+            scheduled_prompt_repo,
+            task_repo,
+            meeting_repo,
+            vad_settings_repo,
+            transcription_settings_repo,
+            whisper_engine: Arc::new(WhisperEngine::new()),
+            whisper_downloads: Arc::new(WhisperDownloads::new()),
             chat_service,
             email_service,
         })
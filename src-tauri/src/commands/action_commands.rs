@@ -0,0 +1,96 @@
+use tauri::State;
+use crate::app_state::AppState;
+use crate::domain::action::{
+    entity::{ActionLogEntry, ActionOutcome, ProposedAction},
+    usecase::{reject_action::RejectActionUseCase, undo_action::UndoActionUseCase},
+};
+use crate::domain::calendar::usecase::delete_event::DeleteEventUseCase;
+use crate::domain::notion::usecase::archive_page::ArchivePageUseCase;
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct ApproveActionDto {
+    pub user_id: String,
+    pub action_id: String,
+    pub arguments: Option<serde_json::Value>, // Edited arguments; the proposal is used as-is when absent
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetActionLogDto {
+    pub user_id: String,
+    pub chat_id: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[tauri::command]
+pub async fn get_chat_actions(chat_id: String, state: State<'_, AppState>) -> Result<Vec<ProposedAction>, String> {
+    let chat_id = Uuid::parse_str(&chat_id)
+        .map_err(|e| format!("Invalid chat_id format: {}", e))?;
+
+    state.action_repo.find_by_chat_id(chat_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn approve_action(dto: ApproveActionDto, state: State<'_, AppState>) -> Result<ActionOutcome, String> {
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let action_id = Uuid::parse_str(&dto.action_id)
+        .map_err(|e| format!("Invalid action_id format: {}", e))?;
+
+    state.chat_service.approve_action(user_id, action_id, dto.arguments)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn reject_action(user_id: String, action_id: String, state: State<'_, AppState>) -> Result<ProposedAction, String> {
+    let reject_action_usecase = RejectActionUseCase::new(
+        state.action_repo.clone(),
+        state.action_notifier.clone(),
+    );
+
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let action_id = Uuid::parse_str(&action_id)
+        .map_err(|e| format!("Invalid action_id format: {}", e))?;
+
+    reject_action_usecase.execute(user_id, action_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_action_log(dto: GetActionLogDto, state: State<'_, AppState>) -> Result<Vec<ActionLogEntry>, String> {
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let chat_id = dto.chat_id
+        .map(|id| Uuid::parse_str(&id).map_err(|e| format!("Invalid chat_id format: {}", e)))
+        .transpose()?;
+
+    state.action_log_repo.find_by_user_id(user_id, chat_id, dto.limit.unwrap_or(50), dto.offset.unwrap_or(0))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Reverts a logged action: deletes the created calendar event or archives the Notion page.
+#[tauri::command]
+pub async fn undo_action(log_id: String, state: State<'_, AppState>) -> Result<ActionLogEntry, String> {
+    let undo_action_usecase = UndoActionUseCase::new(
+        state.action_log_repo.clone(),
+        state.action_repo.clone(),
+        state.action_notifier.clone(),
+        DeleteEventUseCase::new(state.calendar_repo.clone(), state.session_repo.clone()),
+        ArchivePageUseCase::new(state.notion_repo.clone(), state.notion_client.clone()),
+    );
+
+    let log_id = Uuid::parse_str(&log_id)
+        .map_err(|e| format!("Invalid log_id format: {}", e))?;
+
+    undo_action_usecase.execute(log_id)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -1,14 +1,193 @@
-use tauri::{AppHandle, Runtime, Emitter};
+use tauri::{AppHandle, Runtime, Emitter, State};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use std::sync::atomic::{AtomicBool, Ordering};
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-use log::{info, error};
+use chrono::Utc;
+use log::{info, error, warn};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::app_state::AppState;
+use crate::commands::{meeting_commands::meetings_dir, transcription_commands::transcription_provider};
+use crate::domain::ai::audio::{
+    audio_input::{
+        AudioBuffer, AudioDevice, CaptureSource, NormalizedWavWriter, Resampler, Speaker, WHISPER_SAMPLE_RATE,
+        f32_to_i16, i16_to_f32, u16_to_f32,
+    },
+    streaming::{StreamingConfig, StreamingTranscription, TranscriptEvent},
+    transcribe_audio::{TranscriptionProvider, language_code},
+    transcript::{Transcript, TranscriptSegment},
+    vad::{VadConfig, VadEvent, VoiceActivityDetector},
+};
+use crate::domain::meeting::{
+    repository::MeetingRepository,
+    usecase::manage_meeting::ManageMeetingUseCase,
+};
+use crate::infrastructure::ai::audio::capture::{
+    input_device_names, list_devices, open_microphone, open_system_audio, select_input_device,
+};
 
 // Simple global state for recording control (in a real app, manage via AppState)
 lazy_static::lazy_static! {
     static ref IS_RECORDING: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+    static ref CURRENT_RECORDING: Mutex<Option<PathBuf>> = Mutex::new(None);
+}
+
+/// Saves the recording as a meeting: its title and, for meetings scheduled in
+/// the calendar, the event it belongs to.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MeetingOptions {
+    pub title: Option<String>,
+    pub calendar_event_id: Option<String>,
+}
+
+/// Where a recording goes and the meeting that keeps it. Without a user there's
+/// no meeting and only the file is kept.
+#[derive(Clone)]
+struct RecordingTarget {
+    id: Uuid,
+    path: PathBuf,
+    meeting_repo: Option<Arc<dyn MeetingRepository>>,
+}
+
+impl RecordingTarget {
+    /// Stores the length of the finalized recording on the meeting.
+    fn finished(&self) {
+        let Some(meeting_repo) = self.meeting_repo.clone() else {
+            return;
+        };
+        let (id, path) = (self.id, self.path.clone());
+        tauri::async_runtime::spawn(async move {
+            let duration_ms = AudioBuffer::wav_duration_ms(&path).unwrap_or_else(|e| {
+                warn!("[Audio] {}", e);
+                0
+            });
+            if let Err(e) = meeting_repo.finish_recording(id, duration_ms as i64, Utc::now()).await {
+                error!("[Audio] Failed to save meeting {}: {}", id, e);
+            }
+        });
+    }
+
+    /// Drops the meeting of a recording that failed to start.
+    fn discard(&self) {
+        let _ = std::fs::remove_file(&self.path);
+        let Some(meeting_repo) = self.meeting_repo.clone() else {
+            return;
+        };
+        let id = self.id;
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = meeting_repo.delete(id).await {
+                error!("[Audio] Failed to delete meeting {}: {}", id, e);
+            }
+        });
+    }
+
+    /// Keeps what was transcribed live on the meeting.
+    async fn save_transcript(&self, transcript: &Transcript) {
+        if let Some(meeting_repo) = &self.meeting_repo {
+            if let Err(e) = meeting_repo.save_transcript(self.id, transcript).await {
+                error!("[Audio] Failed to save the transcript of meeting {}: {}", self.id, e);
+            }
+        }
+    }
+}
+
+/// Transcribe while recording; the transcript arrives as events.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StreamingOptions {
+    /// Whisper model, when the user transcribes with local whisper
+    pub model: String,
+    pub language: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct TranscriptionUpdate {
+    session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speaker: Option<Speaker>,
+    #[serde(flatten)]
+    event: TranscriptEvent,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct TranscriptionResult {
+    session_id: String,
+    text: String,
+    #[serde(flatten)]
+    transcript: Transcript,
+}
+
+/// Mono audio of one speaker, filled by the capture thread and drained by the transcriber.
+struct LiveSource {
+    speaker: Option<Speaker>,
+    buffer: Arc<Mutex<AudioBuffer>>,
+}
+
+/// Takes the microphone's frames: writes them normalized to the recording,
+/// stops the recording after the user's silence timeout and hands the audio to
+/// the streaming transcriber.
+struct MicMonitor<R: Runtime> {
+    writer: Arc<Mutex<Option<NormalizedWavWriter>>>,
+    vad: Mutex<VoiceActivityDetector>,
+    live: Option<Arc<Mutex<AudioBuffer>>>,
+    channels: u16,
+    is_recording: Arc<AtomicBool>,
+    app: AppHandle<R>,
+}
+
+impl<R: Runtime> MicMonitor<R> {
+    fn feed(&self, interleaved: &[f32]) {
+        let mut mono = AudioBuffer::new(0);
+        mono.push_interleaved(interleaved, self.channels);
+
+        let samples = match self.writer.lock() {
+            Ok(mut guard) => match guard.as_mut() {
+                Some(writer) => writer.write(&mono.samples).unwrap_or_else(|e| {
+                    error!("[Audio] {}", e);
+                    Vec::new()
+                }),
+                None => return,
+            },
+            Err(_) => return,
+        };
+
+        let events = match self.vad.lock() {
+            Ok(mut vad) => vad.push(&samples),
+            Err(_) => Vec::new(),
+        };
+        for event in events {
+            if let VadEvent::SilenceTimeout { .. } = event {
+                info!("[Audio] Silence detected, stopping recording automatically.");
+                self.is_recording.store(false, Ordering::SeqCst);
+                let _ = self.app.emit("recording_silence_detected", ());
+            }
+            let _ = self.app.emit("recording_voice_activity", event);
+        }
+
+        if let Some(live) = &self.live {
+            if let Ok(mut buffer) = live.lock() {
+                buffer.samples.extend_from_slice(&samples);
+            }
+        }
+    }
+}
+
+/// The saved input device wasn't found and the default one is recording instead.
+#[derive(Debug, Clone, Serialize)]
+struct DeviceFallback {
+    requested: String,
+    used: String,
+}
+
+/// System audio is dropped once it runs this far ahead of the microphone.
+const MAX_SYSTEM_LAG_MS: u64 = 500;
+
+#[derive(Debug, Clone, Serialize)]
+struct TranscriptionError {
+    session_id: String,
+    error: String,
 }
 
 #[tauri::command]
@@ -16,73 +195,214 @@ pub async fn get_recording_status() -> Result<bool, String> {
     Ok(IS_RECORDING.load(Ordering::SeqCst))
 }
 
+/// Input and monitor devices; the saved choice is `AppConfig::input_device`.
 #[tauri::command]
-pub async fn start_recording<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+pub async fn list_audio_devices() -> Result<Vec<AudioDevice>, String> {
+    tauri::async_runtime::spawn_blocking(|| list_devices(&cpal::default_host()))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// Polls the input devices and emits `audio-devices-changed` with the new list
+/// when one is plugged in or removed. cpal has no hotplug notifications.
+/// Polling pauses while recording: a device in use can drop out of the list,
+/// and changes made meanwhile are reported once recording stops.
+pub fn watch_audio_devices<R: Runtime>(app: AppHandle<R>) {
+    thread::spawn(move || {
+        let host = cpal::default_host();
+        let mut known = input_device_names(&host);
+        loop {
+            thread::sleep(Duration::from_secs(3));
+            if IS_RECORDING.load(Ordering::SeqCst) {
+                continue;
+            }
+            let names = input_device_names(&host);
+            if names == known {
+                continue;
+            }
+            info!("[Audio] Input devices changed: {:?}", names);
+            known = names;
+            match list_devices(&host) {
+                Ok(devices) => {
+                    let _ = app.emit("audio-devices-changed", devices);
+                }
+                Err(e) => error!("[Audio] Failed to list devices: {}", e),
+            }
+        }
+    });
+}
+
+/// Emits `recording_device_fallback` when `used` isn't the device the user picked.
+fn report_fallback<R: Runtime>(app: &AppHandle<R>, requested: Option<&str>, used: &str) {
+    if let Some(requested) = requested {
+        if requested != used {
+            let _ = app.emit("recording_device_fallback", DeviceFallback {
+                requested: requested.to_string(),
+                used: used.to_string(),
+            });
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_vad_settings(user_id: String, state: State<'_, AppState>) -> Result<VadConfig, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    state.vad_settings_repo.find_by_user_id(user_id)
+        .await
+        .map(|config| config.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn save_vad_settings(user_id: String, settings: VadConfig, state: State<'_, AppState>) -> Result<(), String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    state.vad_settings_repo.save(user_id, &settings)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Records the default input device to a WAV file. With `streaming`, the audio is
+/// also transcribed as it comes in: `transcription-partial` carries the text so
+/// far, `transcription-segment` each finalized segment and `transcription-final`
+/// the complete transcript once recording stops. Streaming uses the
+/// transcription provider `user_id` picked in settings.
+///
+/// Every recording is kept as a meeting of `user_id` (the signed-in user by
+/// default) in the app's data directory, named after `meeting`'s title or
+/// calendar event; the live transcript is saved with it. Returns the meeting id,
+/// which is also the `session_id` of the transcription events.
+///
+/// The microphone is the one saved in settings, or the default device when none
+/// is saved or it's unplugged (`recording_device_fallback` reports the latter).
+/// Recording stops by itself after the silence timeout of `user_id`'s voice
+/// activity settings; `recording_voice_activity` reports speech starting and ending.
+/// `source` picks what is recorded (microphone by default). `system` records what
+/// the computer plays; `mixed` records both into a stereo file, microphone left
+/// and system audio right, and labels transcript segments `me` or `them`.
+#[tauri::command]
+pub async fn start_recording<R: Runtime>(
+    app: AppHandle<R>,
+    streaming: Option<StreamingOptions>,
+    source: Option<CaptureSource>,
+    user_id: Option<String>,
+    meeting: Option<MeetingOptions>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
     if IS_RECORDING.load(Ordering::SeqCst) {
         return Err("Already recording".to_string());
     }
 
+    let input_device = state.config_repo.get()
+        .await
+        .map_err(|e| e.to_string())?
+        .input_device;
+
+    let user_id = user_id
+        .map(|user_id| Uuid::parse_str(&user_id).map_err(|e| format!("Invalid user_id format: {}", e)))
+        .transpose()?;
+    let vad_config = match user_id {
+        Some(user_id) => state.vad_settings_repo.find_by_user_id(user_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .unwrap_or_default(),
+        None => VadConfig::default(),
+    };
+
+    // Resolve the provider first so a missing model or key is reported before recording starts
+    let transcriber = match &streaming {
+        Some(options) => {
+            let transcriber = transcription_provider(&app, &state, user_id, Some(&options.model), None).await?;
+            transcriber.warm_up();
+            Some(transcriber)
+        }
+        None => None,
+    };
+
+    let target = start_meeting(&app, &state, user_id, meeting.unwrap_or_default()).await?;
+    let session_id = target.id.to_string();
+    if let Ok(mut current) = CURRENT_RECORDING.lock() {
+        *current = Some(target.path.clone());
+    }
+
     IS_RECORDING.store(true, Ordering::SeqCst);
     let is_recording = IS_RECORDING.clone();
     let app_handle = app.clone();
 
+    let source = source.unwrap_or(CaptureSource::Microphone);
+    if source != CaptureSource::Microphone {
+        let language = streaming.map(|o| o.language).unwrap_or_default();
+        thread::spawn(move || record_system_audio(app_handle, source, input_device, transcriber, language, target, is_recording));
+        return Ok(session_id);
+    }
+
     // Spawn a thread to handle recording
     thread::spawn(move || {
         let host = cpal::default_host();
-        let device = match host.default_input_device() {
-            Some(d) => d,
-            None => {
-                error!("[Audio] No input device available");
+        let device = match select_input_device(&host, input_device.as_deref()) {
+            Ok(d) => d,
+            Err(e) => {
+                error!("[Audio] {}", e);
                 is_recording.store(false, Ordering::SeqCst);
-                let _ = app_handle.emit("recording_error", "No input device available");
+                target.discard();
+                let _ = app_handle.emit("recording_error", e.to_string());
                 return;
             }
         };
+        report_fallback(&app_handle, input_device.as_deref(), &device.name().unwrap_or_default());
 
         let config = match device.default_input_config() {
             Ok(c) => c,
             Err(e) => {
                 error!("[Audio] Failed to get default input config: {:?}", e);
                 is_recording.store(false, Ordering::SeqCst);
+                target.discard();
                 let _ = app_handle.emit("recording_error", format!("Config error: {:?}", e));
                 return;
             }
         };
 
-        // We'll use WAV format: 16-bit, mono or stereo depending on config.
-        let spec = hound::WavSpec {
-            channels: config.channels(),
-            sample_rate: config.sample_rate().0,
-            bits_per_sample: 16,
-            sample_format: hound::SampleFormat::Int,
-        };
-
-        let temp_dir = std::env::temp_dir();
-        let file_path = temp_dir.join("primer_recording.wav");
-        
+        let file_path = target.path.clone();
         info!("[Audio] Recording to {:?}", file_path);
 
-        let writer = match hound::WavWriter::create(&file_path, spec) {
+        // Whatever the device delivers is written as 16 kHz mono 16-bit
+        let writer = match NormalizedWavWriter::create(&file_path, config.sample_rate().0) {
             Ok(w) => Arc::new(Mutex::new(Some(w))),
             Err(e) => {
                 error!("[Audio] Failed to create WAV writer: {:?}", e);
                 is_recording.store(false, Ordering::SeqCst);
+                target.discard();
                 return;
             }
         };
 
-        let writer_clone = writer.clone();
+        // Copy of the normalized audio for the streaming transcriber
+        let live = transcriber.map(|transcriber| {
+            let live = Arc::new(Mutex::new(AudioBuffer::new(WHISPER_SAMPLE_RATE)));
+            let language = streaming.map(|o| o.language).unwrap_or_default();
+            tauri::async_runtime::spawn(run_streaming_transcription(
+                app_handle.clone(),
+                transcriber,
+                vec![LiveSource { speaker: None, buffer: live.clone() }],
+                language,
+                target.clone(),
+                is_recording.clone(),
+            ));
+            live
+        });
         
-        // VAD Variables
-        let silence_start = Arc::new(Mutex::new(None::<Instant>));
-        let silence_threshold = 0.015; // Adjustable threshold (0.0 to 1.0)
-        let silence_duration_limit = Duration::from_millis(1500); // 1.5 seconds silence triggers stop
-        let speaking_started = Arc::new(AtomicBool::new(false)); // Don't stop before user starts talking
-
-        let silence_start_clone = silence_start.clone();
-        let speaking_started_clone = speaking_started.clone();
-        let is_recording_clone_for_stream = is_recording.clone();
-        let app_handle_clone = app_handle.clone();
+        let monitor = MicMonitor {
+            writer: writer.clone(),
+            vad: Mutex::new(VoiceActivityDetector::new(WHISPER_SAMPLE_RATE, vad_config)),
+            live,
+            channels: config.channels(),
+            is_recording: is_recording.clone(),
+            app: app_handle.clone(),
+        };
 
         let err_fn = move |err| {
             error!("[Audio] A stream error occurred: {}", err);
@@ -91,113 +411,42 @@ pub async fn start_recording<R: Runtime>(app: AppHandle<R>) -> Result<(), String
         let stream = match config.sample_format() {
             cpal::SampleFormat::F32 => device.build_input_stream(
                 &config.into(),
-                move |data: &[f32], _: &_| {
-                    // Calculate RMS for VAD
-                    let mut sum_squares = 0.0;
-                    for &sample in data {
-                        sum_squares += sample * sample;
-                    }
-                    let rms = (sum_squares / data.len() as f32).sqrt();
-                    
-                    // VAD Logic
-                    if rms > silence_threshold {
-                        // User is speaking
-                        speaking_started_clone.store(true, Ordering::Relaxed);
-                        let mut start = silence_start_clone.lock().unwrap();
-                        *start = None;
-                    } else if speaking_started_clone.load(Ordering::Relaxed) {
-                        // Silence detected AFTER speech started
-                        let mut start = silence_start_clone.lock().unwrap();
-                        if start.is_none() {
-                            *start = Some(Instant::now());
-                        } else if let Some(s) = *start {
-                            if s.elapsed() >= silence_duration_limit {
-                                info!("[Audio] Silence detected, stopping recording automatically.");
-                                is_recording_clone_for_stream.store(false, Ordering::SeqCst);
-                                let _ = app_handle_clone.emit("recording_silence_detected", ());
-                            }
-                        }
-                    }
-
-                    // Write to file
-                    if let Ok(mut guard) = writer_clone.lock() {
-                         if let Some(writer) = guard.as_mut() {
-                             for &sample in data {
-                                 // Convert f32 to i16
-                                 let s = (sample * i16::MAX as f32) as i16;
-                                 writer.write_sample(s).ok();
-                             }
-                         }
-                    }
-                },
+                move |data: &[f32], _: &_| monitor.feed(data),
                 err_fn,
                 None 
             ),
             cpal::SampleFormat::I16 => device.build_input_stream(
                 &config.into(),
                 move |data: &[i16], _: &_| {
-                    // Calculate RMS for VAD
-                    let mut sum_squares = 0.0;
-                    for &sample in data {
-                         let normalized = sample as f32 / i16::MAX as f32;
-                        sum_squares += normalized * normalized;
-                    }
-                    let rms = (sum_squares / data.len() as f32).sqrt();
-
-                    // VAD Logic (Duplicated for simplicity due to types)
-                     if rms > silence_threshold {
-                        speaking_started_clone.store(true, Ordering::Relaxed);
-                        let mut start = silence_start_clone.lock().unwrap();
-                        *start = None;
-                    } else if speaking_started_clone.load(Ordering::Relaxed) {
-                        let mut start = silence_start_clone.lock().unwrap();
-                        if start.is_none() {
-                            *start = Some(Instant::now());
-                        } else if let Some(s) = *start {
-                            if s.elapsed() >= silence_duration_limit {
-                                info!("[Audio] Silence detected (I16), stopping recording automatically.");
-                                is_recording_clone_for_stream.store(false, Ordering::SeqCst);
-                                let _ = app_handle_clone.emit("recording_silence_detected", ());
-                            }
-                        }
-                    }
-
-                    if let Ok(mut guard) = writer_clone.lock() {
-                        if let Some(writer) = guard.as_mut() {
-                            for &sample in data {
-                                writer.write_sample(sample).ok();
-                            }
-                        }
-                    }
+                    let samples: Vec<f32> = data.iter().map(|&s| i16_to_f32(s)).collect();
+                    monitor.feed(&samples);
                 },
                 err_fn,
                 None
             ),
-             // Ignoring U16 for VAD brevity, assuming most mics are F32 or I16. 
-             // If needed, can implement similarly.
-             _ => {
-                // Fallback without VAD for other formats
-                 device.build_input_stream(
-                    &config.into(),
-                    move |data: &[u16], _: &_| {
-                         if let Ok(mut guard) = writer_clone.lock() {
-                            if let Some(writer) = guard.as_mut() {
-                                for &sample in data {
-                                    writer.write_sample((sample as i16).wrapping_sub(i16::MAX)).ok();
-                                }
-                            }
-                        }
-                    },
-                    move |err| error!("[Audio] Stream error: {}", err),
-                    None
-                )
-            },
+            cpal::SampleFormat::U16 => device.build_input_stream(
+                &config.into(),
+                move |data: &[u16], _: &_| {
+                    let samples: Vec<f32> = data.iter().map(|&s| u16_to_f32(s)).collect();
+                    monitor.feed(&samples);
+                },
+                err_fn,
+                None
+            ),
+            other => {
+                error!("[Audio] Unsupported sample format: {:?}", other);
+                is_recording.store(false, Ordering::SeqCst);
+                target.discard();
+                let _ = app_handle.emit("recording_error", format!("Unsupported sample format: {:?}", other));
+                return;
+            }
         };
 
         if let Ok(stream) = stream {
             if let Err(e) = stream.play() {
                  error!("[Audio] Failed to play stream: {:?}", e);
                  is_recording.store(false, Ordering::SeqCst);
+                 target.discard();
                  return;
             }
 
@@ -211,20 +460,277 @@ pub async fn start_recording<R: Runtime>(app: AppHandle<R>) -> Result<(), String
         } else if let Err(e) = stream {
             error!("[Audio] Failed to build input stream: {:?}", e);
             is_recording.store(false, Ordering::SeqCst);
+            target.discard();
             return;
         }
         
         // Finalize writer
         if let Ok(mut guard) = writer.lock() {
             if let Some(w) = guard.take() {
-                w.finalize().ok();
+                if let Err(e) = w.finalize() {
+                    error!("[Audio] {}", e);
+                }
             }
         }
+        target.finished();
         
         info!("[Audio] Recording finished.");
     });
 
-    Ok(())
+    Ok(session_id)
+}
+
+/// Picks the recording's file in the meetings directory and, when there's a
+/// user, saves the meeting it belongs to.
+async fn start_meeting<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+    user_id: Option<Uuid>,
+    options: MeetingOptions,
+) -> Result<RecordingTarget, String> {
+    let id = Uuid::new_v4();
+    let dir = meetings_dir(app)?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create meetings directory: {}", e))?;
+    let path = dir.join(format!("{}.wav", id));
+
+    let user_id = match user_id {
+        Some(user_id) => Some(user_id),
+        None => state.session_repo.get().await.ok().flatten().map(|session| session.user_id),
+    };
+    let Some(user_id) = user_id else {
+        return Ok(RecordingTarget { id, path, meeting_repo: None });
+    };
+
+    let calendar_event_id = options.calendar_event_id
+        .filter(|event_id| !event_id.trim().is_empty())
+        .map(|event_id| Uuid::parse_str(&event_id).map_err(|e| format!("Invalid calendar_event_id format: {}", e)))
+        .transpose()?;
+    ManageMeetingUseCase::new(state.meeting_repo.clone(), state.calendar_repo.clone())
+        .start(id, user_id, path.to_string_lossy().to_string(), options.title, calendar_event_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(RecordingTarget { id, path, meeting_repo: Some(state.meeting_repo.clone()) })
+}
+
+/// Records system audio, alone or next to the microphone, until recording stops.
+///
+/// In mixed mode the microphone is the clock: every tick takes what it captured
+/// and pairs it with as much system audio, padding with silence when the system
+/// stream is behind. Both are resampled to 16 kHz first; the mixed file is stereo
+/// so each speaker keeps a channel.
+fn record_system_audio<R: Runtime>(
+    app_handle: AppHandle<R>,
+    source: CaptureSource,
+    input_device: Option<String>,
+    transcriber: Option<Arc<dyn TranscriptionProvider>>,
+    language: String,
+    target: RecordingTarget,
+    is_recording: Arc<AtomicBool>,
+) {
+    let fail = |message: String| {
+        error!("[Audio] {}", message);
+        is_recording.store(false, Ordering::SeqCst);
+        target.discard();
+        let _ = app_handle.emit("recording_error", message);
+    };
+
+    let host = cpal::default_host();
+    let system = match open_system_audio(&host) {
+        Ok(capture) => capture,
+        Err(e) => return fail(e.to_string()),
+    };
+    let microphone = match source {
+        CaptureSource::Mixed => match open_microphone(&host, input_device.as_deref()) {
+            Ok(capture) => {
+                report_fallback(&app_handle, input_device.as_deref(), &capture.device_name);
+                Some(capture)
+            }
+            Err(e) => return fail(e.to_string()),
+        },
+        _ => None,
+    };
+    info!("[Audio] Capturing system audio from {}", system.device_name);
+
+    let mut system_resampler = Resampler::new(system.sample_rate(), WHISPER_SAMPLE_RATE);
+    let mut microphone = microphone.map(|m| {
+        let resampler = Resampler::new(m.sample_rate(), WHISPER_SAMPLE_RATE);
+        (m, resampler)
+    });
+    let spec = hound::WavSpec {
+        channels: if microphone.is_some() { 2 } else { 1 },
+        sample_rate: WHISPER_SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    info!("[Audio] Recording to {:?}", target.path);
+    let mut writer = match hound::WavWriter::create(&target.path, spec) {
+        Ok(w) => w,
+        Err(e) => return fail(format!("Failed to create WAV writer: {:?}", e)),
+    };
+
+    let speakers: &[Option<Speaker>] = if microphone.is_some() {
+        &[Some(Speaker::Me), Some(Speaker::Them)]
+    } else {
+        &[None]
+    };
+    let live: Vec<Arc<Mutex<AudioBuffer>>> = match transcriber {
+        Some(transcriber) => {
+            let sources: Vec<LiveSource> = speakers.iter()
+                .map(|&speaker| LiveSource { speaker, buffer: Arc::new(Mutex::new(AudioBuffer::new(WHISPER_SAMPLE_RATE))) })
+                .collect();
+            let buffers = sources.iter().map(|s| s.buffer.clone()).collect();
+            tauri::async_runtime::spawn(run_streaming_transcription(
+                app_handle.clone(),
+                transcriber,
+                sources,
+                language,
+                target.clone(),
+                is_recording.clone(),
+            ));
+            buffers
+        }
+        None => Vec::new(),
+    };
+
+    let max_lag = AudioBuffer::new(WHISPER_SAMPLE_RATE).ms_to_samples(MAX_SYSTEM_LAG_MS);
+    let mut pending: Vec<f32> = Vec::new();
+    let mut tick = |last: bool| {
+        let mut them = system_resampler.process(&system.drain());
+        if last {
+            them.extend(system_resampler.finish());
+        }
+        let channels: Vec<Vec<f32>> = match &mut microphone {
+            Some((microphone, resampler)) => {
+                let mut me = resampler.process(&microphone.drain());
+                if last {
+                    me.extend(resampler.finish());
+                }
+                pending.extend(them);
+                let take = me.len().min(pending.len());
+                let mut them: Vec<f32> = pending.drain(..take).collect();
+                them.resize(me.len(), 0.0);
+                if pending.len() > max_lag {
+                    pending.drain(..pending.len() - max_lag);
+                }
+                vec![me, them]
+            }
+            None => vec![them],
+        };
+
+        for frame in 0..channels[0].len() {
+            for channel in &channels {
+                writer.write_sample(f32_to_i16(channel[frame])).ok();
+            }
+        }
+        for (buffer, samples) in live.iter().zip(&channels) {
+            if let Ok(mut buffer) = buffer.lock() {
+                buffer.samples.extend_from_slice(samples);
+            }
+        }
+    };
+
+    while is_recording.load(Ordering::SeqCst) {
+        thread::sleep(Duration::from_millis(100));
+        tick(false);
+    }
+    tick(true);
+
+    drop(microphone);
+    drop(system);
+    if let Err(e) = writer.finalize() {
+        error!("[Audio] Failed to finalize WAV file: {}", e);
+    }
+    target.finished();
+    info!("[Audio] Recording finished.");
+}
+
+/// Feeds the captured audio to one streaming transcriber per source until
+/// recording stops, then closes the last segments and emits the full transcript.
+/// With speakers, the final text is the segments in time order, each prefixed
+/// with who said it. The transcript is saved with the recording's meeting.
+async fn run_streaming_transcription<R: Runtime>(
+    app: AppHandle<R>,
+    transcriber: Arc<dyn TranscriptionProvider>,
+    sources: Vec<LiveSource>,
+    language: String,
+    target: RecordingTarget,
+    is_recording: Arc<AtomicBool>,
+) {
+    let session_id = target.id.to_string();
+    let mut transcriptions: Vec<StreamingTranscription> = sources.iter()
+        .map(|source| {
+            let sample_rate = source.buffer.lock().map(|buffer| buffer.sample_rate).unwrap_or(16_000);
+            StreamingTranscription::new(sample_rate, StreamingConfig::default())
+        })
+        .collect();
+    info!("[Audio] Streaming transcription {} started", session_id);
+
+    loop {
+        let finish = !is_recording.load(Ordering::SeqCst);
+        if finish {
+            // Let the capture thread flush its last callback
+            tokio::time::sleep(Duration::from_millis(300)).await;
+        }
+
+        for (source, transcription) in sources.iter().zip(transcriptions.iter_mut()) {
+            let samples = match source.buffer.lock() {
+                Ok(mut buffer) => std::mem::take(&mut buffer.samples),
+                Err(_) => Vec::new(),
+            };
+            transcription.push(&samples);
+
+            match transcription.step(transcriber.as_ref(), &language, finish).await {
+                Ok(events) => {
+                    for event in events {
+                        let name = match event {
+                            TranscriptEvent::Partial { .. } => "transcription-partial",
+                            TranscriptEvent::Segment { .. } => "transcription-segment",
+                        };
+                        let _ = app.emit(name, TranscriptionUpdate {
+                            session_id: session_id.clone(),
+                            speaker: source.speaker,
+                            event,
+                        });
+                    }
+                }
+                Err(e) => {
+                    error!("[Audio] Streaming transcription failed: {}", e);
+                    let _ = app.emit("transcription-error", TranscriptionError {
+                        session_id: session_id.clone(),
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        if finish {
+            let mut segments: Vec<TranscriptSegment> = sources.iter()
+                .zip(&transcriptions)
+                .flat_map(|(source, transcription)| {
+                    transcription.segments().iter().map(|segment| TranscriptSegment {
+                        speaker: source.speaker,
+                        ..segment.clone()
+                    })
+                })
+                .collect();
+            segments.sort_by_key(|s| s.start_ms);
+
+            let transcript = Transcript { language: language_code(&language).to_string(), segments };
+            target.save_transcript(&transcript).await;
+            let _ = app.emit("transcription-final", TranscriptionResult {
+                session_id: session_id.clone(),
+                text: transcript.text(),
+                transcript,
+            });
+            info!("[Audio] Streaming transcription {} finished", session_id);
+            break;
+        }
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
 }
 
 #[tauri::command]
@@ -236,9 +742,11 @@ pub async fn stop_recording() -> Result<String, String> {
         thread::sleep(Duration::from_millis(500));
     }
 
-    let temp_dir = std::env::temp_dir();
-    let file_path = temp_dir.join("primer_recording.wav");
-    
+    let file_path = CURRENT_RECORDING.lock()
+        .map_err(|_| "Recording state is unavailable".to_string())?
+        .clone()
+        .ok_or_else(|| "Recording file not found".to_string())?;
+
     if !file_path.exists() {
         return Err("Recording file not found".to_string());
     }
@@ -6,18 +6,57 @@ use crate::domain::ai::chat::{
         get_chats::GetChatsUseCase,
         get_messages::GetMessagesUseCase,
         delete_chat::DeleteChatUseCase,
+        pick_reply::PickReplyUseCase,
     },
+    entity::{message::Message, page::PageCursor},
     dto::{
         CreateChatDto, CreateChatResponse,
         SendMessageDto, SendMessageResponse, MessageDto,
         GetChatsDto, GetChatsResponse, ChatDto,
         GetMessagesDto, GetMessagesResponse,
         DeleteChatDto, DeleteChatResponse,
+        UpdateChatOrganizationDto,
+        CompareModelsDto, CompareModelsResponse, AlternativeDto,
     },
     service::chat_service::ChatServiceRequest, // Added this line
 };
 use crate::app_state::AppState;
 use uuid::Uuid;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use std::collections::HashMap;
+use crate::domain::prompt_preset::template;
+
+/// `{{clipboard}}` falls back to the system clipboard when the frontend didn't
+/// send it. The clipboard is only read when the chat's preset uses it.
+async fn with_clipboard(
+    app: &tauri::AppHandle,
+    state: &AppState,
+    chat_id: Uuid,
+    mut template_values: HashMap<String, String>,
+) -> HashMap<String, String> {
+    if template_values.contains_key("clipboard") {
+        return template_values;
+    }
+
+    let preset_id = match state.sqlite_chat_repo.find_by_id(chat_id).await {
+        Ok(Some(chat)) => chat.prompt_preset_id,
+        _ => None,
+    };
+    let Some(preset_id) = preset_id else {
+        return template_values;
+    };
+    let uses_clipboard = matches!(
+        state.prompt_preset_repo.find_by_id(&preset_id).await,
+        Ok(Some(preset)) if template::uses_variable(&preset.prompt, "clipboard")
+    );
+
+    if uses_clipboard {
+        if let Ok(text) = app.clipboard().read_text() {
+            template_values.insert("clipboard".to_string(), text);
+        }
+    }
+    template_values
+}
 
 
 #[tauri::command]
@@ -28,15 +67,16 @@ pub async fn create_chat(dto: CreateChatDto, state: State<'_, AppState>) -> Resu
 
     let user_id = Uuid::parse_str(&dto.user_id)
         .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let is_incognito = dto.is_incognito.unwrap_or(false);
 
     // Attempt to create chat. If it fails due to FK constraint (likely invalid prompt_preset_id), retry with None.
-    match create_chat_usecase.execute(user_id, dto.title.clone(), dto.prompt_preset_id.clone(), dto.model.clone()).await {
+    match create_chat_usecase.execute(user_id, dto.title.clone(), dto.prompt_preset_id.clone(), dto.model.clone(), is_incognito).await {
         Ok(chat) => Ok(CreateChatResponse { chat_id: chat.id.to_string() }),
         Err(e) => {
              let err_msg = e.to_string();
              if err_msg.contains("FOREIGN KEY constraint failed") {
                  log::warn!("Create chat failed with preset {:?}, retrying with None. Error: {}", dto.prompt_preset_id, err_msg);
-                 create_chat_usecase.execute(user_id, dto.title, None, dto.model)
+                 create_chat_usecase.execute(user_id, dto.title, None, dto.model, is_incognito)
                     .await
                     .map(|chat| CreateChatResponse { chat_id: chat.id.to_string() })
                     .map_err(|e| e.to_string())
@@ -48,7 +88,7 @@ pub async fn create_chat(dto: CreateChatDto, state: State<'_, AppState>) -> Resu
 }
 
 #[tauri::command]
-pub async fn send_message(dto: SendMessageDto, state: State<'_, AppState>) -> Result<SendMessageResponse, String> {
+pub async fn send_message(dto: SendMessageDto, app: tauri::AppHandle, state: State<'_, AppState>) -> Result<SendMessageResponse, String> {
     let send_message_usecase = SendMessageUseCase::new(
         state.chat_service.clone(),
     );
@@ -57,6 +97,12 @@ pub async fn send_message(dto: SendMessageDto, state: State<'_, AppState>) -> Re
         .map_err(|e| format!("Invalid user_id format: {}", e))?;
     let chat_id = Uuid::parse_str(&dto.chat_id)
         .map_err(|e| format!("Invalid chat_id format: {}", e))?;
+    let workspace_id = dto.workspace_id
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| Uuid::parse_str(&s).map_err(|e| format!("Invalid workspace_id format: {}", e)))
+        .transpose()?;
+
+    let template_values = with_clipboard(&app, &state, chat_id, dto.template_values.unwrap_or_default()).await;
 
     let request = ChatServiceRequest {
         user_id,
@@ -68,6 +114,9 @@ pub async fn send_message(dto: SendMessageDto, state: State<'_, AppState>) -> Re
         max_tokens: dto.max_tokens,
         image: dto.image,
         output_language: dto.output_language,
+        workspace_id,
+        template_values: Some(template_values),
+        mcp_resources: dto.mcp_resources.unwrap_or_default(),
     };
 
     send_message_usecase.execute(request)
@@ -82,6 +131,7 @@ pub async fn send_message(dto: SendMessageDto, state: State<'_, AppState>) -> Re
             created_at: message.created_at,
             follow_ups: message.follow_ups,
             tip: message.tip,
+            citations: message.citations,
         },
         follow_ups,
     })
@@ -96,15 +146,21 @@ pub async fn get_chats(dto: GetChatsDto, state: State<'_, AppState>) -> Result<G
 
     let user_id = Uuid::parse_str(&dto.user_id)
         .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let before = parse_cursor(dto.before)?;
 
-    get_chats_usecase.execute(user_id)
+    get_chats_usecase.execute(user_id, before, dto.limit)
         .await
-        .map(|chats| GetChatsResponse {
-            chats: chats.into_iter().map(|c| ChatDto {
+        .map(|page| GetChatsResponse {
+            next_cursor: page.next_cursor.map(|c| c.to_string()),
+            chats: page.items.into_iter().map(|c| ChatDto {
                 id: c.id.to_string(),
                 user_id: c.user_id.to_string(),
                 title: c.title.unwrap_or_else(|| "New Chat".to_string()),
                 model: c.model,
+                is_incognito: c.is_incognito,
+                is_pinned: c.is_pinned,
+                tags: c.tags,
+                archived_at: c.archived_at,
                 created_at: c.created_at,
                 updated_at: c.updated_at,
             }).collect()
@@ -120,11 +176,13 @@ pub async fn get_messages(dto: GetMessagesDto, state: State<'_, AppState>) -> Re
 
     let chat_id = Uuid::parse_str(&dto.chat_id)
         .map_err(|e| format!("Invalid chat_id format: {}", e))?;
+    let before = parse_cursor(dto.before)?;
 
-    get_messages_usecase.execute(chat_id)
+    get_messages_usecase.execute(chat_id, before, dto.limit)
         .await
-        .map(|messages| GetMessagesResponse {
-            messages: messages.into_iter().map(|m| MessageDto {
+        .map(|page| GetMessagesResponse {
+            next_cursor: page.next_cursor.map(|c| c.to_string()),
+            messages: page.items.into_iter().map(|m| MessageDto {
                 id: m.id.to_string(),
                 chat_id: m.chat_id.to_string(),
                 user_id: None,
@@ -133,6 +191,7 @@ pub async fn get_messages(dto: GetMessagesDto, state: State<'_, AppState>) -> Re
                 created_at: m.created_at,
                 follow_ups: m.follow_ups,
                 tip: m.tip,
+                citations: m.citations,
             }).collect()
         })
         .map_err(|e| e.to_string())
@@ -152,3 +211,145 @@ pub async fn delete_chat(dto: DeleteChatDto, state: State<'_, AppState>) -> Resu
         .map(|_| DeleteChatResponse { message: "Chat deleted successfully".to_string() })
         .map_err(|e| e.to_string())
 }
+
+/// Pins, tags or (un)archives a chat; only the fields that are set change.
+/// Pinned and tagged chats can be kept out of retention rules.
+#[tauri::command]
+pub async fn update_chat_organization(dto: UpdateChatOrganizationDto, state: State<'_, AppState>) -> Result<(), String> {
+    let chat_id = Uuid::parse_str(&dto.chat_id)
+        .map_err(|e| format!("Invalid chat_id format: {}", e))?;
+
+    if let Some(pinned) = dto.pinned {
+        state.sqlite_chat_repo.set_pinned(chat_id, pinned).await.map_err(|e| e.to_string())?;
+    }
+    if let Some(tags) = dto.tags {
+        let mut tags: Vec<String> = tags.into_iter()
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+        tags.sort();
+        tags.dedup();
+        state.sqlite_chat_repo.set_tags(chat_id, tags).await.map_err(|e| e.to_string())?;
+    }
+    if let Some(archived) = dto.archived {
+        let archived_at = if archived { Some(chrono::Utc::now()) } else { None };
+        state.sqlite_chat_repo.set_archived(chat_id, archived_at).await.map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Wipes an incognito chat from memory. The frontend calls this when the chat is
+/// closed; persisted chats are left untouched.
+#[tauri::command]
+pub async fn close_incognito_chat(chat_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let chat_id = Uuid::parse_str(&chat_id)
+        .map_err(|e| format!("Invalid chat_id format: {}", e))?;
+
+    state.incognito_store.close(chat_id);
+    Ok(())
+}
+
+fn parse_cursor(value: Option<String>) -> Result<Option<PageCursor>, String> {
+    value
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| s.parse().map_err(|e: anyhow::Error| format!("Invalid cursor: {}", e)))
+        .transpose()
+}
+
+fn to_message_dto(message: Message) -> MessageDto {
+    MessageDto {
+        id: message.id.to_string(),
+        chat_id: message.chat_id.to_string(),
+        user_id: None,
+        role: message.role,
+        content: message.content,
+        created_at: message.created_at,
+        follow_ups: message.follow_ups,
+        tip: message.tip,
+        citations: message.citations,
+    }
+}
+
+#[tauri::command]
+pub async fn compare_models(dto: CompareModelsDto, app: tauri::AppHandle, state: State<'_, AppState>) -> Result<CompareModelsResponse, String> {
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let chat_id = Uuid::parse_str(&dto.chat_id)
+        .map_err(|e| format!("Invalid chat_id format: {}", e))?;
+    let workspace_id = dto.workspace_id
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| Uuid::parse_str(&s).map_err(|e| format!("Invalid workspace_id format: {}", e)))
+        .transpose()?;
+
+    let template_values = with_clipboard(&app, &state, chat_id, dto.template_values.unwrap_or_default()).await;
+
+    // provider_name/model are per target; the first one is only a placeholder here
+    let first = dto.targets.first().cloned().ok_or("No models selected for comparison")?;
+    let request = ChatServiceRequest {
+        user_id,
+        chat_id,
+        provider_name: first.provider_name,
+        prompt: dto.content,
+        model: first.model,
+        temperature: dto.temperature,
+        max_tokens: dto.max_tokens,
+        image: dto.image,
+        output_language: dto.output_language,
+        workspace_id,
+        template_values: Some(template_values),
+        mcp_resources: dto.mcp_resources.unwrap_or_default(),
+    };
+
+    state.chat_service.compare_models(request, dto.targets)
+        .await
+        .map(|result| CompareModelsResponse {
+            user_message: to_message_dto(result.user_message),
+            alternatives: result.candidates.into_iter().map(|c| AlternativeDto {
+                provider_name: c.provider_name,
+                model: c.model,
+                message: c.message.map(to_message_dto),
+                generation: c.generation,
+                is_canonical: false,
+                error: c.error,
+            }).collect(),
+        })
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_message_alternatives(user_message_id: String, state: State<'_, AppState>) -> Result<Vec<AlternativeDto>, String> {
+    let pick_reply_usecase = PickReplyUseCase::new(
+        state.sqlite_message_repo.clone(),
+    );
+
+    let user_message_id = Uuid::parse_str(&user_message_id)
+        .map_err(|e| format!("Invalid message_id format: {}", e))?;
+
+    pick_reply_usecase.alternatives(user_message_id)
+        .await
+        .map(|replies| replies.into_iter().map(|(message, generation)| AlternativeDto {
+            provider_name: generation.as_ref().map(|g| g.provider.clone()).unwrap_or_default(),
+            model: generation.as_ref().map(|g| g.model.clone()).unwrap_or_default(),
+            is_canonical: !message.is_alternative,
+            message: Some(to_message_dto(message)),
+            generation,
+            error: None,
+        }).collect())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn pick_reply(message_id: String, state: State<'_, AppState>) -> Result<MessageDto, String> {
+    let pick_reply_usecase = PickReplyUseCase::new(
+        state.sqlite_message_repo.clone(),
+    );
+
+    let message_id = Uuid::parse_str(&message_id)
+        .map_err(|e| format!("Invalid message_id format: {}", e))?;
+
+    pick_reply_usecase.execute(message_id)
+        .await
+        .map(to_message_dto)
+        .map_err(|e| e.to_string())
+}
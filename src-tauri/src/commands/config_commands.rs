@@ -24,6 +24,22 @@ pub async fn set_enable_smart_rag(enabled: bool, state: State<'_, AppState>) ->
         .map_err(|e| e.to_string())
 }
 
+/// `tool` is `calendar_event`, `notion_page` or `mcp:<server>/<tool>`.
+#[tauri::command]
+pub async fn set_tool_auto_approve(tool: String, enabled: bool, state: State<'_, AppState>) -> Result<(), String> {
+    state.config_repo.set_tool_auto_approve(&tool, enabled)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// `None` goes back to the system default microphone.
+#[tauri::command]
+pub async fn set_input_device(device: Option<String>, state: State<'_, AppState>) -> Result<(), String> {
+    state.config_repo.set_input_device(device.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn open_system_settings(setting_type: String) -> Result<(), String> {
     #[cfg(target_os = "macos")]
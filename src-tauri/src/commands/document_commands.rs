@@ -0,0 +1,120 @@
+use tauri::State;
+use crate::app_state::AppState;
+use crate::domain::document::{
+    entity::{Document, DocumentChunk},
+    usecase::ingest_document::IngestDocumentUseCase,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct IngestDocumentDto {
+    pub user_id: String,
+    pub chat_id: Option<String>, // None = global library
+    pub file_path: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetDocumentsDto {
+    pub user_id: String,
+    pub chat_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AttachDocumentDto {
+    pub user_id: String,
+    pub document_id: String,
+    pub chat_id: Option<String>, // None = move to global library
+}
+
+fn parse_optional_uuid(value: Option<String>, field: &str) -> Result<Option<Uuid>, String> {
+    value
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| Uuid::parse_str(&s).map_err(|e| format!("Invalid {} format: {}", field, e)))
+        .transpose()
+}
+
+#[tauri::command]
+pub async fn ingest_document(dto: IngestDocumentDto, state: State<'_, AppState>) -> Result<Document, String> {
+    let ingest_document_usecase = IngestDocumentUseCase::new(
+        state.document_repo.clone(),
+    );
+
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let chat_id = parse_optional_uuid(dto.chat_id, "chat_id")?;
+    if chat_id.is_some_and(|id| state.incognito_store.contains_chat(id)) {
+        return Err("Documents can't be attached to incognito chats".to_string());
+    }
+
+    ingest_document_usecase.execute(user_id, chat_id, dto.file_path)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_documents(dto: GetDocumentsDto, state: State<'_, AppState>) -> Result<Vec<Document>, String> {
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let chat_id = parse_optional_uuid(dto.chat_id, "chat_id")?;
+
+    state.document_repo.find_by_user_id(user_id, chat_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_document_chunks(document_id: String, state: State<'_, AppState>) -> Result<Vec<DocumentChunk>, String> {
+    let document_id = Uuid::parse_str(&document_id)
+        .map_err(|e| format!("Invalid document_id format: {}", e))?;
+
+    state.document_repo.find_chunks(document_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn attach_document(dto: AttachDocumentDto, state: State<'_, AppState>) -> Result<(), String> {
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let document_id = Uuid::parse_str(&dto.document_id)
+        .map_err(|e| format!("Invalid document_id format: {}", e))?;
+    let chat_id = parse_optional_uuid(dto.chat_id, "chat_id")?;
+    if chat_id.is_some_and(|id| state.incognito_store.contains_chat(id)) {
+        return Err("Documents can't be attached to incognito chats".to_string());
+    }
+
+    if let Some(chat_id) = chat_id {
+        state.sqlite_chat_repo.find_by_id(chat_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .filter(|chat| chat.user_id == user_id)
+            .ok_or_else(|| "Chat not found".to_string())?;
+    }
+
+    let attached = state.document_repo.set_chat(user_id, document_id, chat_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if !attached {
+        return Err("Document not found".to_string());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn delete_document(user_id: String, document_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let document_id = Uuid::parse_str(&document_id)
+        .map_err(|e| format!("Invalid document_id format: {}", e))?;
+
+    let deleted = state.document_repo.delete(user_id, document_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if !deleted {
+        return Err("Document not found".to_string());
+    }
+
+    Ok(())
+}
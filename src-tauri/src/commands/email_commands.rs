@@ -34,6 +34,7 @@ pub async fn send_chat_summary(dto: SendChatSummaryEmailDto, state: State<'_, Ap
         state.user_repo.clone(),
         state.prompt_preset_repo.clone(),
         state.user_api_key_repo.clone(),
+        state.config_repo.clone(),
     );
 
     let user_id = Uuid::parse_str(&dto.user_id)
@@ -0,0 +1,76 @@
+use tauri::State;
+use crate::app_state::AppState;
+use crate::domain::feedback::{
+    entity::{FeedbackStats, MessageFeedback},
+    usecase::submit_feedback::SubmitFeedbackUseCase,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitFeedbackDto {
+    pub user_id: String,
+    pub message_id: String,
+    pub rating: i32, // 1 = thumbs up, -1 = thumbs down
+    pub reason: Option<String>,
+    pub comment: Option<String>,
+    pub model: Option<String>,
+}
+
+#[tauri::command]
+pub async fn submit_message_feedback(dto: SubmitFeedbackDto, state: State<'_, AppState>) -> Result<MessageFeedback, String> {
+    let submit_feedback_usecase = SubmitFeedbackUseCase::new(
+        state.feedback_repo.clone(),
+        state.sqlite_message_repo.clone(),
+        state.sqlite_chat_repo.clone(),
+    );
+
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let message_id = Uuid::parse_str(&dto.message_id)
+        .map_err(|e| format!("Invalid message_id format: {}", e))?;
+
+    submit_feedback_usecase.execute(user_id, message_id, dto.rating, dto.reason, dto.comment, dto.model)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn clear_message_feedback(user_id: String, message_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let submit_feedback_usecase = SubmitFeedbackUseCase::new(
+        state.feedback_repo.clone(),
+        state.sqlite_message_repo.clone(),
+        state.sqlite_chat_repo.clone(),
+    );
+
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let message_id = Uuid::parse_str(&message_id)
+        .map_err(|e| format!("Invalid message_id format: {}", e))?;
+
+    submit_feedback_usecase.clear(user_id, message_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_chat_feedback(user_id: String, chat_id: String, state: State<'_, AppState>) -> Result<Vec<MessageFeedback>, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let chat_id = Uuid::parse_str(&chat_id)
+        .map_err(|e| format!("Invalid chat_id format: {}", e))?;
+
+    state.feedback_repo.find_by_chat_id(user_id, chat_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_feedback_stats(user_id: String, state: State<'_, AppState>) -> Result<Vec<FeedbackStats>, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    state.feedback_repo.get_stats(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
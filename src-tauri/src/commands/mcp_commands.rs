@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use tauri::State;
+use crate::app_state::AppState;
+use crate::domain::mcp::{
+    entity::{McpCapabilities, McpServer},
+    usecase::manage_server::{ManageMcpServerUseCase, McpServerInput},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct McpServerDto {
+    pub user_id: String,
+    pub server_id: Option<String>, // Set when updating
+    pub name: String,
+    pub transport: String, // "stdio" | "http"
+    pub command: Option<String>,
+    pub args: Option<Vec<String>>,
+    pub env: Option<HashMap<String, String>>,
+    pub url: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetMcpPromptDto {
+    pub server_id: String,
+    pub name: String,
+    pub arguments: Option<HashMap<String, String>>,
+}
+
+impl McpServerDto {
+    fn into_input(self) -> Result<McpServerInput, String> {
+        Ok(McpServerInput {
+            name: self.name,
+            transport: self.transport.parse().map_err(|e: anyhow::Error| e.to_string())?,
+            command: self.command,
+            args: self.args.unwrap_or_default(),
+            env: self.env.unwrap_or_default(),
+            url: self.url,
+            enabled: self.enabled.unwrap_or(true),
+        })
+    }
+}
+
+#[tauri::command]
+pub async fn add_mcp_server(dto: McpServerDto, state: State<'_, AppState>) -> Result<McpServer, String> {
+    let manage_server_usecase = ManageMcpServerUseCase::new(state.mcp_server_repo.clone());
+
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    manage_server_usecase.add(user_id, dto.into_input()?)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_mcp_server(dto: McpServerDto, state: State<'_, AppState>) -> Result<McpServer, String> {
+    let manage_server_usecase = ManageMcpServerUseCase::new(state.mcp_server_repo.clone());
+
+    let server_id = dto.server_id.as_deref()
+        .ok_or("server_id is required")
+        .and_then(|id| Uuid::parse_str(id).map_err(|_| "Invalid server_id format"))?;
+
+    let server = manage_server_usecase.update(server_id, dto.into_input()?)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Reconnect with the new configuration on next use
+    state.mcp_manager.disconnect(server_id).await;
+
+    Ok(server)
+}
+
+#[tauri::command]
+pub async fn get_mcp_servers(user_id: String, state: State<'_, AppState>) -> Result<Vec<McpServer>, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    state.mcp_server_repo.find_by_user_id(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_mcp_server(server_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let server_id = Uuid::parse_str(&server_id)
+        .map_err(|e| format!("Invalid server_id format: {}", e))?;
+
+    state.mcp_manager.disconnect(server_id).await;
+    state.mcp_server_repo.delete(server_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Connects to the server and lists its tools, resources and prompts.
+/// e.g. `npx -y @modelcontextprotocol/server-everything` as a stdio reference server.
+#[tauri::command]
+pub async fn test_mcp_server(server_id: String, state: State<'_, AppState>) -> Result<McpCapabilities, String> {
+    let server_id = Uuid::parse_str(&server_id)
+        .map_err(|e| format!("Invalid server_id format: {}", e))?;
+
+    state.mcp_manager.test(server_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn read_mcp_resource(server_id: String, uri: String, state: State<'_, AppState>) -> Result<String, String> {
+    let server_id = Uuid::parse_str(&server_id)
+        .map_err(|e| format!("Invalid server_id format: {}", e))?;
+
+    state.mcp_manager.read_resource(server_id, &uri)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Renders a server prompt so the frontend can drop it into the message box.
+#[tauri::command]
+pub async fn get_mcp_prompt(dto: GetMcpPromptDto, state: State<'_, AppState>) -> Result<String, String> {
+    let server_id = Uuid::parse_str(&dto.server_id)
+        .map_err(|e| format!("Invalid server_id format: {}", e))?;
+
+    state.mcp_manager.get_prompt(server_id, &dto.name, dto.arguments.unwrap_or_default())
+        .await
+        .map_err(|e| e.to_string())
+}
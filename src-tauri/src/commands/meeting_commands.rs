@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf};
+use base64::{Engine as _, engine::general_purpose};
+use tauri::{AppHandle, Manager, Runtime, State};
+use uuid::Uuid;
+use crate::app_state::AppState;
+use crate::commands::transcription_commands::transcription_provider;
+use crate::domain::ai::audio::{
+    audio_input::{AudioBuffer, Speaker},
+    transcript::Transcript,
+};
+use crate::domain::meeting::{
+    entity::{Meeting, MeetingChatResult, MeetingSummary},
+    usecase::{
+        manage_meeting::ManageMeetingUseCase,
+        meeting_to_chat::MeetingToChatUseCase,
+    },
+};
+
+/// Longest piece of a recording sent in one transcription request. Uploads are
+/// 16 kHz 16-bit WAV, so five minutes stay well under OpenAI's 25 MB and
+/// Gemini's 20 MB inline limits.
+const TRANSCRIBE_CHUNK_MS: u64 = 5 * 60 * 1000;
+/// How far back from the end of a piece to look for a pause to cut at.
+const TRANSCRIBE_CUT_SEARCH_MS: u64 = 15_000;
+
+/// Where recordings are kept, one `<meeting id>.wav` per meeting.
+pub fn meetings_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    Ok(app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve meetings path: {}", e))?
+        .join("meetings"))
+}
+
+async fn find_meeting(meeting_id: &str, state: &AppState) -> Result<Meeting, String> {
+    let meeting_id = Uuid::parse_str(meeting_id)
+        .map_err(|e| format!("Invalid meeting_id format: {}", e))?;
+
+    state.meeting_repo.find_by_id(meeting_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Meeting not found".to_string())
+}
+
+#[tauri::command]
+pub async fn list_meetings(user_id: String, state: State<'_, AppState>) -> Result<Vec<MeetingSummary>, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    state.meeting_repo.find_by_user_id(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_meeting(meeting_id: String, state: State<'_, AppState>) -> Result<Meeting, String> {
+    find_meeting(&meeting_id, &state).await
+}
+
+/// The meeting's recording as base64 WAV, for playback.
+#[tauri::command]
+pub async fn get_meeting_audio(meeting_id: String, state: State<'_, AppState>) -> Result<String, String> {
+    let meeting = find_meeting(&meeting_id, &state).await?;
+
+    let audio = std::fs::read(&meeting.audio_path)
+        .map_err(|e| format!("Failed to read the recording: {}", e))?;
+    Ok(general_purpose::STANDARD.encode(&audio))
+}
+
+/// Renames the meeting or links it to a calendar event. Linking an event to a
+/// meeting without a new title names the meeting after the event.
+#[tauri::command]
+pub async fn update_meeting(
+    meeting_id: String,
+    title: Option<String>,
+    calendar_event_id: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Meeting, String> {
+    let manage_meeting_usecase = ManageMeetingUseCase::new(state.meeting_repo.clone(), state.calendar_repo.clone());
+
+    let meeting_id = Uuid::parse_str(&meeting_id)
+        .map_err(|e| format!("Invalid meeting_id format: {}", e))?;
+    let calendar_event_id = calendar_event_id
+        .filter(|id| !id.trim().is_empty())
+        .map(|id| Uuid::parse_str(&id).map_err(|e| format!("Invalid calendar_event_id format: {}", e)))
+        .transpose()?;
+
+    manage_meeting_usecase.update(meeting_id, title, calendar_event_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Transcribes the recording again with the provider the meeting's user picked
+/// in settings and replaces the saved transcript. Both speakers of a mixed
+/// recording are transcribed apart, so segments keep who said them. Long
+/// recordings are sent a few minutes at a time, cut at pauses.
+#[tauri::command]
+pub async fn retranscribe_meeting<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    language: String,
+    state: State<'_, AppState>,
+) -> Result<Transcript, String> {
+    let meeting = find_meeting(&meeting_id, &state).await?;
+    if meeting.ended_at.is_none() {
+        return Err("The meeting is still being recorded".to_string());
+    }
+
+    let path = Path::new(&meeting.audio_path);
+    if !path.is_file() {
+        return Err(format!("Recording not found: {}", meeting.audio_path));
+    }
+    let channels = AudioBuffer::read_wav_channels(path).map_err(|e| e.to_string())?;
+    let provider = transcription_provider(&app, &state, Some(meeting.user_id), None, None).await?;
+
+    // Long recordings go in pieces; providers reject uploads past a few minutes
+    let transcribe = |audio: AudioBuffer| {
+        let provider = provider.clone();
+        let language = language.clone();
+        async move {
+            let mut transcript = Transcript::default();
+            for (offset_ms, piece) in audio.split_at_pauses(TRANSCRIBE_CHUNK_MS, TRANSCRIBE_CUT_SEARCH_MS) {
+                let part = provider.transcribe_segments(&piece, &language)
+                    .await
+                    .map_err(|e| {
+                        log::error!("[Meeting] Transcription failed: {}", e);
+                        e.to_string()
+                    })?
+                    .offset(offset_ms);
+                if transcript.language.is_empty() {
+                    transcript.language = part.language;
+                }
+                transcript.segments.extend(part.segments);
+            }
+            Ok::<_, String>(transcript)
+        }
+    };
+
+    let transcript = match <[AudioBuffer; 2]>::try_from(channels) {
+        Ok([me, them]) => {
+            let mut transcript = transcribe(me).await?;
+            let other = transcribe(them).await?;
+            for segment in &mut transcript.segments {
+                segment.speaker = Some(Speaker::Me);
+            }
+            transcript.segments.extend(other.segments.into_iter().map(|mut segment| {
+                segment.speaker = Some(Speaker::Them);
+                segment
+            }));
+            transcript.segments.sort_by_key(|segment| segment.start_ms);
+            transcript
+        }
+        Err(mut channels) if channels.len() == 1 => transcribe(channels.remove(0)).await?,
+        Err(_) => transcribe(AudioBuffer::read_wav(path).map_err(|e| e.to_string())?).await?,
+    };
+
+    state.meeting_repo.save_transcript(meeting.id, &transcript)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(transcript)
+}
+
+/// Deletes the meeting and its recording.
+#[tauri::command]
+pub async fn delete_meeting(meeting_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let manage_meeting_usecase = ManageMeetingUseCase::new(state.meeting_repo.clone(), state.calendar_repo.clone());
+
+    let meeting_id = Uuid::parse_str(&meeting_id)
+        .map_err(|e| format!("Invalid meeting_id format: {}", e))?;
+
+    manage_meeting_usecase.delete(meeting_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Opens a chat about the meeting whose first answer is a summary written
+/// with `summary_preset_id`.
+#[tauri::command]
+pub async fn meeting_to_chat(
+    meeting_id: String,
+    summary_preset_id: String,
+    provider_name: Option<String>,
+    model: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<MeetingChatResult, String> {
+    let meeting_to_chat_usecase = MeetingToChatUseCase::new(
+        state.meeting_repo.clone(),
+        state.chat_service.clone(),
+        state.sqlite_chat_repo.clone(),
+        state.prompt_preset_repo.clone(),
+        state.user_api_key_repo.clone(),
+    );
+
+    let meeting_id = Uuid::parse_str(&meeting_id)
+        .map_err(|e| format!("Invalid meeting_id format: {}", e))?;
+
+    meeting_to_chat_usecase.execute(meeting_id, &summary_preset_id, provider_name, model)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -8,7 +8,18 @@ pub mod log_commands;
 pub mod prompt_preset_commands;
 pub mod audio_commands;
 pub mod whisper_commands;
+pub mod transcription_commands;
 pub mod ollama_commands;
 pub mod changelog_commands;
 pub mod calendar_commands;
-pub mod notion_commands;
\ No newline at end of file
+pub mod notion_commands;
+pub mod document_commands;
+pub mod workspace_commands;
+pub mod feedback_commands;
+pub mod mcp_commands;
+pub mod action_commands;
+pub mod quick_action_commands;
+pub mod retention_commands;
+pub mod schedule_commands;
+pub mod task_commands;
+pub mod meeting_commands;
@@ -1,6 +1,13 @@
 use tauri::State;
 use crate::app_state::AppState;
-use crate::domain::prompt_preset::entity::PromptPreset;
+use crate::domain::prompt_preset::{
+    entity::{
+        BundleFormat, ImportConflictStrategy, ImportReport, PresetDiff, PresetParameter,
+        PromptPreset, PromptPresetVersion,
+    },
+    template,
+    usecase::{preset_bundle::PresetBundleUseCase, preset_history::PresetHistoryUseCase},
+};
 use serde::Deserialize;
 use uuid::Uuid;
 use chrono::Utc;
@@ -12,6 +19,8 @@ pub struct CreatePromptPresetDto {
     pub prompt: String,
     #[serde(default = "default_preset_type")]
     pub preset_type: Option<String>,
+    #[serde(default)]
+    pub parameters: Vec<PresetParameter>,
 }
 
 fn default_preset_type() -> Option<String> {
@@ -24,6 +33,36 @@ pub struct UpdatePromptPresetDto {
     pub name: String,
     pub description: Option<String>,
     pub prompt: String,
+    #[serde(default)]
+    pub parameters: Vec<PresetParameter>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportPromptPresetsDto {
+    pub ids: Option<Vec<String>>, // None = all custom presets
+    pub format: BundleFormat,
+    pub file_path: Option<String>, // When set, the bundle is also written there
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportPromptPresetsDto {
+    pub content: Option<String>,
+    pub file_path: Option<String>,
+    pub format: Option<BundleFormat>, // Detected from the file extension/content when omitted
+    pub strategy: ImportConflictStrategy,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DiffPromptPresetDto {
+    pub preset_id: String,
+    pub from_version: i64,
+    pub to_version: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RollbackPromptPresetDto {
+    pub preset_id: String,
+    pub version: i64,
 }
 
 #[tauri::command]
@@ -42,6 +81,9 @@ pub async fn get_summary_presets(state: State<'_, AppState>) -> Result<Vec<Promp
 
 #[tauri::command]
 pub async fn create_prompt_preset(dto: CreatePromptPresetDto, state: State<'_, AppState>) -> Result<PromptPreset, String> {
+    template::validate(&dto.prompt, &dto.parameters)
+        .map_err(|e| format!("Invalid prompt template: {}", e))?;
+
     let preset = PromptPreset {
         id: Uuid::new_v4().to_string(),
         name: dto.name,
@@ -49,14 +91,21 @@ pub async fn create_prompt_preset(dto: CreatePromptPresetDto, state: State<'_, A
         prompt: dto.prompt,
         is_built_in: false,
         preset_type: dto.preset_type,
+        parameters: dto.parameters,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
 
     state.prompt_preset_repo.save(&preset)
         .await
-        .map(|_| preset)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+
+    PresetHistoryUseCase::new(state.prompt_preset_repo.clone())
+        .record(None, &preset)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(preset)
 }
 
 #[tauri::command]
@@ -70,15 +119,26 @@ pub async fn update_prompt_preset(dto: UpdatePromptPresetDto, state: State<'_, A
         return Err("Cannot update built-in preset".to_string());
     }
 
+    template::validate(&dto.prompt, &dto.parameters)
+        .map_err(|e| format!("Invalid prompt template: {}", e))?;
+
+    let previous = preset.clone();
     preset.name = dto.name;
     preset.description = dto.description;
     preset.prompt = dto.prompt;
+    preset.parameters = dto.parameters;
     preset.updated_at = Utc::now();
 
     state.prompt_preset_repo.update(&preset)
         .await
-        .map(|_| preset)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+
+    PresetHistoryUseCase::new(state.prompt_preset_repo.clone())
+        .record(Some(&previous), &preset)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(preset)
 }
 
 #[tauri::command]
@@ -96,3 +156,76 @@ pub async fn delete_prompt_preset(id: String, state: State<'_, AppState>) -> Res
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+pub async fn export_prompt_presets(dto: ExportPromptPresetsDto, state: State<'_, AppState>) -> Result<String, String> {
+    let preset_bundle_usecase = PresetBundleUseCase::new(
+        state.prompt_preset_repo.clone(),
+    );
+
+    let content = preset_bundle_usecase.export(dto.ids, dto.format)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if let Some(path) = dto.file_path {
+        tokio::fs::write(&path, &content)
+            .await
+            .map_err(|e| format!("Failed to write preset bundle: {}", e))?;
+    }
+
+    Ok(content)
+}
+
+#[tauri::command]
+pub async fn import_prompt_presets(dto: ImportPromptPresetsDto, state: State<'_, AppState>) -> Result<ImportReport, String> {
+    let preset_bundle_usecase = PresetBundleUseCase::new(
+        state.prompt_preset_repo.clone(),
+    );
+
+    let content = match (dto.content, &dto.file_path) {
+        (Some(content), _) => content,
+        (None, Some(path)) => tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| format!("Failed to read preset bundle: {}", e))?,
+        (None, None) => return Err("Either content or file_path is required".to_string()),
+    };
+
+    let format = dto.format.unwrap_or_else(|| {
+        let is_yaml_file = dto.file_path.as_deref()
+            .map(|p| p.ends_with(".yaml") || p.ends_with(".yml"))
+            .unwrap_or(false);
+        if is_yaml_file || !content.trim_start().starts_with('{') {
+            BundleFormat::Yaml
+        } else {
+            BundleFormat::Json
+        }
+    });
+
+    preset_bundle_usecase.import(&content, format, dto.strategy)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_prompt_preset_history(preset_id: String, state: State<'_, AppState>) -> Result<Vec<PromptPresetVersion>, String> {
+    PresetHistoryUseCase::new(state.prompt_preset_repo.clone())
+        .history(&preset_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn diff_prompt_preset_versions(dto: DiffPromptPresetDto, state: State<'_, AppState>) -> Result<PresetDiff, String> {
+    PresetHistoryUseCase::new(state.prompt_preset_repo.clone())
+        .diff(&dto.preset_id, dto.from_version, dto.to_version)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn rollback_prompt_preset(dto: RollbackPromptPresetDto, state: State<'_, AppState>) -> Result<PromptPreset, String> {
+    PresetHistoryUseCase::new(state.prompt_preset_repo.clone())
+        .rollback(&dto.preset_id, dto.version)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use uuid::Uuid;
+use crate::app_state::AppState;
+use crate::domain::quick_action::{
+    entity::{QuickAction, QuickActionSource},
+    usecase::{
+        manage_quick_action::{ManageQuickActionUseCase, QuickActionInput},
+        run_quick_action::RunQuickActionUseCase,
+    },
+};
+use crate::selection;
+
+lazy_static! {
+    /// Accelerators currently bound to quick actions, so they can be released on reload
+    static ref REGISTERED_SHORTCUTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuickActionDto {
+    pub action_id: Option<String>, // Set when updating
+    pub name: String,
+    pub shortcut: String,
+    pub prompt_preset_id: String,
+    pub source: Option<String>, // "clipboard" | "selection"
+    pub template_values: Option<HashMap<String, String>>,
+    pub provider_name: Option<String>,
+    pub model: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+impl QuickActionDto {
+    fn into_input(self) -> Result<QuickActionInput, String> {
+        self.shortcut.trim().parse::<Shortcut>()
+            .map_err(|e| format!("Invalid shortcut {}: {}", self.shortcut, e))?;
+
+        Ok(QuickActionInput {
+            name: self.name,
+            shortcut: self.shortcut,
+            prompt_preset_id: self.prompt_preset_id,
+            source: match self.source {
+                Some(source) => source.parse().map_err(|e: anyhow::Error| e.to_string())?,
+                None => QuickActionSource::Selection,
+            },
+            template_values: self.template_values.unwrap_or_default(),
+            provider_name: self.provider_name,
+            model: self.model,
+            enabled: self.enabled.unwrap_or(true),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct QuickActionStarted {
+    action_id: String,
+    name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct QuickActionError {
+    action_id: String,
+    error: String,
+}
+
+/// Binds every enabled quick action to its global shortcut, replacing the previous
+/// bindings. Returns the shortcuts that could not be registered (e.g. taken by
+/// another application) so the caller can report them.
+pub fn register_quick_action_shortcuts(app: &AppHandle, actions: &[QuickAction]) -> Vec<String> {
+    let mut registered = REGISTERED_SHORTCUTS.lock().unwrap();
+    for shortcut in registered.drain(..) {
+        if let Err(e) = app.global_shortcut().unregister(shortcut.as_str()) {
+            log::warn!("[QuickAction] Failed to unregister {}: {}", shortcut, e);
+        }
+    }
+
+    let mut failed = Vec::new();
+    for action in actions.iter().filter(|a| a.enabled) {
+        let action_id = action.id;
+        let result = app.global_shortcut().on_shortcut(action.shortcut.as_str(), move |app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                let app_handle = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    trigger_quick_action(app_handle, action_id).await;
+                });
+            }
+        });
+
+        match result {
+            Ok(()) => registered.push(action.shortcut.clone()),
+            Err(e) => {
+                log::warn!("[QuickAction] Failed to register {} for '{}': {}", action.shortcut, action.name, e);
+                failed.push(action.shortcut.clone());
+            }
+        }
+    }
+
+    failed
+}
+
+/// Reloads the quick actions from the database and re-binds their shortcuts.
+pub async fn reload_quick_action_shortcuts(app: &AppHandle) -> Result<Vec<String>, String> {
+    let actions = app.state::<AppState>().quick_action_repo.find_all()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(register_quick_action_shortcuts(app, &actions))
+}
+
+/// Captures the input text, brings the overlay up and sends the text through the
+/// action's preset. Progress is reported with `quick-action-started`,
+/// `quick-action-result` and `quick-action-error` events.
+pub async fn trigger_quick_action(app: AppHandle, action_id: Uuid) {
+    let state = app.state::<AppState>();
+    let action = match state.quick_action_repo.find_by_id(action_id).await {
+        Ok(Some(action)) => action,
+        Ok(None) => return,
+        Err(e) => {
+            log::error!("[QuickAction] Failed to load quick action {}: {}", action_id, e);
+            return;
+        }
+    };
+
+    // Read the input before the overlay takes focus
+    let text = match selection::read_text(&app, action.source).await {
+        Ok(text) => text,
+        Err(e) => {
+            let _ = app.emit("quick-action-error", QuickActionError { action_id: action_id.to_string(), error: e });
+            return;
+        }
+    };
+
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    let _ = app.emit("quick-action-started", QuickActionStarted {
+        action_id: action_id.to_string(),
+        name: action.name.clone(),
+    });
+
+    let run_quick_action_usecase = RunQuickActionUseCase::new(
+        state.chat_service.clone(),
+        state.sqlite_chat_repo.clone(),
+        state.session_repo.clone(),
+        state.user_api_key_repo.clone(),
+    );
+
+    match run_quick_action_usecase.execute(&action, text).await {
+        Ok(result) => {
+            let _ = app.emit("quick-action-result", result);
+        }
+        Err(e) => {
+            log::warn!("[QuickAction] '{}' failed: {}", action.name, e);
+            let _ = app.emit("quick-action-error", QuickActionError { action_id: action_id.to_string(), error: e.to_string() });
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_quick_actions(state: State<'_, AppState>) -> Result<Vec<QuickAction>, String> {
+    state.quick_action_repo.find_all()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn create_quick_action(dto: QuickActionDto, app: AppHandle, state: State<'_, AppState>) -> Result<QuickAction, String> {
+    let manage_quick_action_usecase = ManageQuickActionUseCase::new(
+        state.quick_action_repo.clone(),
+        state.prompt_preset_repo.clone(),
+    );
+
+    let action = manage_quick_action_usecase.add(dto.into_input()?)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let failed = reload_quick_action_shortcuts(&app).await?;
+    if failed.contains(&action.shortcut) {
+        return Err(format!("Quick action saved, but {} is already in use by another application", action.shortcut));
+    }
+    Ok(action)
+}
+
+#[tauri::command]
+pub async fn update_quick_action(dto: QuickActionDto, app: AppHandle, state: State<'_, AppState>) -> Result<QuickAction, String> {
+    let manage_quick_action_usecase = ManageQuickActionUseCase::new(
+        state.quick_action_repo.clone(),
+        state.prompt_preset_repo.clone(),
+    );
+
+    let action_id = dto.action_id.as_deref()
+        .ok_or("action_id is required")
+        .and_then(|id| Uuid::parse_str(id).map_err(|_| "Invalid action_id format"))?;
+
+    let action = manage_quick_action_usecase.update(action_id, dto.into_input()?)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let failed = reload_quick_action_shortcuts(&app).await?;
+    if failed.contains(&action.shortcut) {
+        return Err(format!("Quick action saved, but {} is already in use by another application", action.shortcut));
+    }
+    Ok(action)
+}
+
+#[tauri::command]
+pub async fn delete_quick_action(action_id: String, app: AppHandle, state: State<'_, AppState>) -> Result<(), String> {
+    let action_id = Uuid::parse_str(&action_id)
+        .map_err(|e| format!("Invalid action_id format: {}", e))?;
+
+    state.quick_action_repo.delete(action_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    reload_quick_action_shortcuts(&app).await?;
+    Ok(())
+}
+
+/// Runs a quick action from the UI, exactly as if its shortcut had been pressed.
+#[tauri::command]
+pub async fn run_quick_action(action_id: String, app: AppHandle) -> Result<(), String> {
+    let action_id = Uuid::parse_str(&action_id)
+        .map_err(|e| format!("Invalid action_id format: {}", e))?;
+
+    trigger_quick_action(app, action_id).await;
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use tauri::State;
+use crate::app_state::AppState;
+use crate::domain::retention::{
+    entity::{RetentionReport, RetentionRule},
+    usecase::{
+        apply_retention::ApplyRetentionUseCase,
+        manage_rule::{ManageRetentionRuleUseCase, RetentionRuleInput},
+    },
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct RetentionRuleDto {
+    pub user_id: String,
+    pub rule_id: Option<String>, // Set when updating
+    pub name: String,
+    pub action: String, // "delete" | "archive" | "drop_content"
+    pub older_than_days: i64,
+    pub keep_pinned: Option<bool>,
+    pub keep_tagged: Option<bool>,
+    pub enabled: Option<bool>,
+}
+
+impl RetentionRuleDto {
+    fn into_input(self) -> Result<RetentionRuleInput, String> {
+        Ok(RetentionRuleInput {
+            name: self.name,
+            action: self.action.parse().map_err(|e: anyhow::Error| e.to_string())?,
+            older_than_days: self.older_than_days,
+            keep_pinned: self.keep_pinned.unwrap_or(true),
+            keep_tagged: self.keep_tagged.unwrap_or(true),
+            enabled: self.enabled.unwrap_or(true),
+        })
+    }
+}
+
+#[tauri::command]
+pub async fn get_retention_rules(user_id: String, state: State<'_, AppState>) -> Result<Vec<RetentionRule>, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    state.retention_rule_repo.find_by_user_id(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn add_retention_rule(dto: RetentionRuleDto, state: State<'_, AppState>) -> Result<RetentionRule, String> {
+    let manage_rule_usecase = ManageRetentionRuleUseCase::new(state.retention_rule_repo.clone());
+
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    manage_rule_usecase.add(user_id, dto.into_input()?)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_retention_rule(dto: RetentionRuleDto, state: State<'_, AppState>) -> Result<RetentionRule, String> {
+    let manage_rule_usecase = ManageRetentionRuleUseCase::new(state.retention_rule_repo.clone());
+
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let rule_id = dto.rule_id.as_deref()
+        .ok_or("rule_id is required")
+        .and_then(|id| Uuid::parse_str(id).map_err(|_| "Invalid rule_id format"))?;
+
+    manage_rule_usecase.update(user_id, rule_id, dto.into_input()?)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_retention_rule(user_id: String, rule_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let rule_id = Uuid::parse_str(&rule_id)
+        .map_err(|e| format!("Invalid rule_id format: {}", e))?;
+
+    let deleted = state.retention_rule_repo.delete(user_id, rule_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if !deleted {
+        return Err("Retention rule not found".to_string());
+    }
+
+    Ok(())
+}
+
+/// Dry run: reports which chats the enabled rules would remove, without changing anything.
+#[tauri::command]
+pub async fn preview_retention(user_id: String, state: State<'_, AppState>) -> Result<Vec<RetentionReport>, String> {
+    let apply_retention_usecase = ApplyRetentionUseCase::new(state.retention_rule_repo.clone());
+
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    apply_retention_usecase.preview(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Applies the enabled rules now instead of waiting for the background job.
+#[tauri::command]
+pub async fn apply_retention(user_id: String, state: State<'_, AppState>) -> Result<Vec<RetentionReport>, String> {
+    let apply_retention_usecase = ApplyRetentionUseCase::new(state.retention_rule_repo.clone());
+
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    apply_retention_usecase.apply(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,181 @@
+use tauri::{AppHandle, Runtime, State};
+use tauri_plugin_notification::NotificationExt;
+use crate::app_state::AppState;
+use crate::domain::schedule::{
+    entity::{CatchUpPolicy, ContextSource, ScheduledPrompt, ScheduledRunResult},
+    usecase::{
+        manage_schedule::{ManageScheduledPromptUseCase, ScheduledPromptInput},
+        run_scheduled_prompt::RunScheduledPromptUseCase,
+    },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct ScheduledPromptDto {
+    pub user_id: String,
+    pub schedule_id: Option<String>, // Set when updating
+    pub name: String,
+    pub cron: String,
+    pub prompt_preset_id: Option<String>,
+    pub prompt: Option<String>,
+    pub context_sources: Option<Vec<ContextSource>>,
+    pub provider_name: Option<String>,
+    pub model: Option<String>,
+    pub deliver_email: Option<bool>,
+    pub deliver_notification: Option<bool>,
+    pub catch_up: Option<String>, // "skip" | "run_once" | "run_all"
+    pub enabled: Option<bool>,
+}
+
+impl ScheduledPromptDto {
+    fn into_input(self) -> Result<ScheduledPromptInput, String> {
+        Ok(ScheduledPromptInput {
+            name: self.name,
+            cron: self.cron,
+            prompt_preset_id: self.prompt_preset_id,
+            prompt: self.prompt.unwrap_or_default(),
+            context_sources: self.context_sources.unwrap_or_default(),
+            provider_name: self.provider_name,
+            model: self.model,
+            deliver_email: self.deliver_email.unwrap_or(false),
+            deliver_notification: self.deliver_notification.unwrap_or(true),
+            catch_up: match self.catch_up {
+                Some(policy) => policy.parse().map_err(|e: anyhow::Error| e.to_string())?,
+                None => CatchUpPolicy::RunOnce,
+            },
+            enabled: self.enabled.unwrap_or(true),
+        })
+    }
+}
+
+/// A failed run, emitted as `scheduled-prompt-error`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduledRunError {
+    pub schedule_id: String,
+    pub schedule_name: String,
+    pub error: String,
+}
+
+/// Longest answer excerpt shown in a run's desktop notification.
+const NOTIFICATION_BODY_CHARS: usize = 200;
+
+/// Raises a desktop notification with the start of the run's answer.
+pub fn show_run_notification<R: Runtime>(app: &AppHandle<R>, run: &ScheduledRunResult) {
+    let content = run.message.content.trim();
+    let mut body: String = content.chars().take(NOTIFICATION_BODY_CHARS).collect();
+    if body.len() < content.len() {
+        body.push('…');
+    }
+
+    if let Err(e) = app.notification()
+        .builder()
+        .title(&run.schedule_name)
+        .body(body)
+        .show()
+    {
+        log::warn!("[Schedule] Failed to show notification for '{}': {}", run.schedule_name, e);
+    }
+}
+
+pub fn run_scheduled_prompt_usecase(state: &AppState) -> RunScheduledPromptUseCase {
+    RunScheduledPromptUseCase::new(
+        state.scheduled_prompt_repo.clone(),
+        state.chat_service.clone(),
+        state.sqlite_chat_repo.clone(),
+        state.sqlite_message_repo.clone(),
+        state.calendar_repo.clone(),
+        state.session_repo.clone(),
+        state.user_repo.clone(),
+        state.user_api_key_repo.clone(),
+        state.email_service.clone(),
+    )
+}
+
+#[tauri::command]
+pub async fn get_scheduled_prompts(user_id: String, state: State<'_, AppState>) -> Result<Vec<ScheduledPrompt>, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    state.scheduled_prompt_repo.find_by_user_id(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn add_scheduled_prompt(dto: ScheduledPromptDto, state: State<'_, AppState>) -> Result<ScheduledPrompt, String> {
+    let manage_schedule_usecase = ManageScheduledPromptUseCase::new(
+        state.scheduled_prompt_repo.clone(),
+        state.prompt_preset_repo.clone(),
+    );
+
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    manage_schedule_usecase.add(user_id, dto.into_input()?)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_scheduled_prompt(dto: ScheduledPromptDto, state: State<'_, AppState>) -> Result<ScheduledPrompt, String> {
+    let manage_schedule_usecase = ManageScheduledPromptUseCase::new(
+        state.scheduled_prompt_repo.clone(),
+        state.prompt_preset_repo.clone(),
+    );
+
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let schedule_id = dto.schedule_id.as_deref()
+        .ok_or("schedule_id is required")
+        .and_then(|id| Uuid::parse_str(id).map_err(|_| "Invalid schedule_id format"))?;
+
+    manage_schedule_usecase.update(user_id, schedule_id, dto.into_input()?)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_scheduled_prompt(user_id: String, schedule_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let schedule_id = Uuid::parse_str(&schedule_id)
+        .map_err(|e| format!("Invalid schedule_id format: {}", e))?;
+
+    let deleted = state.scheduled_prompt_repo.delete(user_id, schedule_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if !deleted {
+        return Err("Scheduled prompt not found".to_string());
+    }
+
+    Ok(())
+}
+
+/// Next occurrences of a cron expression, so the UI can show what it means.
+#[tauri::command]
+pub async fn preview_schedule(cron: String, count: Option<usize>) -> Result<Vec<DateTime<Utc>>, String> {
+    ManageScheduledPromptUseCase::preview(&cron, count.unwrap_or(5))
+        .map_err(|e| e.to_string())
+}
+
+/// Runs a scheduled prompt immediately. Its regular schedule is left unchanged.
+#[tauri::command]
+pub async fn run_scheduled_prompt_now(user_id: String, schedule_id: String, state: State<'_, AppState>) -> Result<ScheduledRunResult, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let schedule_id = Uuid::parse_str(&schedule_id)
+        .map_err(|e| format!("Invalid schedule_id format: {}", e))?;
+
+    let schedule = state.scheduled_prompt_repo.find_by_id(schedule_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .filter(|schedule| schedule.user_id == user_id)
+        .ok_or("Scheduled prompt not found")?;
+
+    run_scheduled_prompt_usecase(&state)
+        .execute(&schedule, Utc::now())
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,166 @@
+use tauri::State;
+use crate::app_state::AppState;
+use crate::domain::calendar::entity::GoogleCalendarEvent;
+use crate::domain::task::{
+    entity::{Task, TaskStatus},
+    usecase::{
+        manage_task::{ManageTaskUseCase, TaskInput},
+        push_to_calendar::PushTaskToCalendarUseCase,
+    },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct TaskDto {
+    pub user_id: String,
+    pub task_id: Option<String>, // Set when updating
+    pub title: String,
+    pub notes: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub due_all_day: Option<bool>,
+    pub status: Option<String>, // "open" | "done" | "dismissed"
+}
+
+impl TaskDto {
+    fn into_input(self) -> Result<TaskInput, String> {
+        Ok(TaskInput {
+            title: self.title,
+            notes: self.notes,
+            due_at: self.due_at,
+            due_all_day: self.due_all_day.unwrap_or(false),
+            status: match self.status {
+                Some(status) => status.parse().map_err(|e: anyhow::Error| e.to_string())?,
+                None => TaskStatus::Open,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExtractTasksDto {
+    pub user_id: String,
+    pub chat_id: String,
+    pub provider_name: String,
+    pub model: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PushTaskToCalendarDto {
+    pub task_id: String,
+    /// Defaults to the task's due time
+    pub start_at: Option<DateTime<Utc>>,
+    pub duration_minutes: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PushTaskToCalendarResponse {
+    pub task: Task,
+    pub event: GoogleCalendarEvent,
+}
+
+#[tauri::command]
+pub async fn get_tasks(user_id: String, status: Option<String>, state: State<'_, AppState>) -> Result<Vec<Task>, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let status = status
+        .map(|s| s.parse::<TaskStatus>())
+        .transpose()
+        .map_err(|e| e.to_string())?;
+
+    state.task_repo.find_by_user_id(user_id, status)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_chat_tasks(chat_id: String, state: State<'_, AppState>) -> Result<Vec<Task>, String> {
+    let chat_id = Uuid::parse_str(&chat_id)
+        .map_err(|e| format!("Invalid chat_id format: {}", e))?;
+
+    state.task_repo.find_by_chat_id(chat_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn add_task(dto: TaskDto, state: State<'_, AppState>) -> Result<Task, String> {
+    let manage_task_usecase = ManageTaskUseCase::new(state.task_repo.clone());
+
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    manage_task_usecase.add(user_id, dto.into_input()?)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_task(dto: TaskDto, state: State<'_, AppState>) -> Result<Task, String> {
+    let manage_task_usecase = ManageTaskUseCase::new(state.task_repo.clone());
+
+    let task_id = dto.task_id.as_deref()
+        .ok_or("task_id is required")
+        .and_then(|id| Uuid::parse_str(id).map_err(|_| "Invalid task_id format"))?;
+
+    manage_task_usecase.update(task_id, dto.into_input()?)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_task_status(task_id: String, status: String, state: State<'_, AppState>) -> Result<Task, String> {
+    let manage_task_usecase = ManageTaskUseCase::new(state.task_repo.clone());
+
+    let task_id = Uuid::parse_str(&task_id)
+        .map_err(|e| format!("Invalid task_id format: {}", e))?;
+    let status = status.parse::<TaskStatus>()
+        .map_err(|e| e.to_string())?;
+
+    manage_task_usecase.set_status(task_id, status)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_task(task_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let task_id = Uuid::parse_str(&task_id)
+        .map_err(|e| format!("Invalid task_id format: {}", e))?;
+
+    state.task_repo.delete(task_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Extracts todos from the chat now instead of waiting for the background analysis.
+/// Returns only the tasks that weren't known yet.
+#[tauri::command]
+pub async fn extract_tasks(dto: ExtractTasksDto, state: State<'_, AppState>) -> Result<Vec<Task>, String> {
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let chat_id = Uuid::parse_str(&dto.chat_id)
+        .map_err(|e| format!("Invalid chat_id format: {}", e))?;
+
+    state.chat_service.extract_tasks(user_id, chat_id, &dto.provider_name, &dto.model)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn push_task_to_calendar(dto: PushTaskToCalendarDto, state: State<'_, AppState>) -> Result<PushTaskToCalendarResponse, String> {
+    let push_task_usecase = PushTaskToCalendarUseCase::new(
+        state.task_repo.clone(),
+        state.calendar_repo.clone(),
+        state.session_repo.clone(),
+    );
+
+    let task_id = Uuid::parse_str(&dto.task_id)
+        .map_err(|e| format!("Invalid task_id format: {}", e))?;
+
+    let (task, event) = push_task_usecase.execute(task_id, dto.start_at, dto.duration_minutes)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(PushTaskToCalendarResponse { task, event })
+}
@@ -0,0 +1,131 @@
+use std::path::Path;
+use std::sync::Arc;
+use tauri::{AppHandle, Runtime, State};
+use uuid::Uuid;
+use crate::app_state::AppState;
+use crate::commands::whisper_commands::whisper_transcriber;
+use crate::domain::ai::audio::{
+    audio_input::AudioBuffer,
+    transcribe_audio::{TranscriptionProvider, TranscriptionProviderType, TranscriptionSettings},
+    transcript::Transcript,
+};
+use crate::infrastructure::ai::audio::{
+    gemini_transcription::GeminiTranscriptionClient,
+    openai_transcription::{OPENAI_API_BASE_URL, OpenAITranscriptionClient},
+    whisper_local::ProgressCallback,
+};
+
+#[tauri::command]
+pub async fn get_transcription_settings(
+    user_id: String,
+    state: State<'_, AppState>,
+) -> Result<TranscriptionSettings, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    state.transcription_settings_repo.find_by_user_id(user_id)
+        .await
+        .map(|settings| settings.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn save_transcription_settings(
+    user_id: String,
+    settings: TranscriptionSettings,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    if settings.provider == TranscriptionProviderType::OpenAICompatible
+        && settings.base_url.as_deref().map(str::trim).unwrap_or_default().is_empty()
+    {
+        return Err("An OpenAI-compatible provider needs the server's base URL".to_string());
+    }
+
+    state.transcription_settings_repo.save(user_id, &settings)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Transcribes a WAV file with the provider `user_id` picked in settings.
+#[tauri::command]
+pub async fn transcribe_audio_file<R: Runtime>(
+    app: AppHandle<R>,
+    user_id: String,
+    audio_path: String,
+    language: String,
+    state: State<'_, AppState>,
+) -> Result<Transcript, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    let path = Path::new(&audio_path);
+    if !path.is_file() {
+        return Err(format!("Audio file not found: {}", audio_path));
+    }
+    let audio = AudioBuffer::read_wav(path).map_err(|e| e.to_string())?;
+
+    let provider = transcription_provider(&app, &state, Some(user_id), None, None).await?;
+    provider.transcribe_segments(&audio, &language)
+        .await
+        .map_err(|e| {
+            log::error!("[Audio] Transcription failed: {}", e);
+            e.to_string()
+        })
+}
+
+/// The provider `user_id` picked in settings, local whisper without a user.
+/// `whisper_model` overrides the model saved for local whisper.
+pub async fn transcription_provider<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &AppState,
+    user_id: Option<Uuid>,
+    whisper_model: Option<&str>,
+    progress: Option<ProgressCallback>,
+) -> Result<Arc<dyn TranscriptionProvider>, String> {
+    let Some(user_id) = user_id else {
+        let settings = TranscriptionSettings::default();
+        return whisper_transcriber(app, whisper_model.unwrap_or(settings.model()), progress);
+    };
+
+    let settings = state.transcription_settings_repo.find_by_user_id(user_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .unwrap_or_default();
+    let model = settings.model().to_string();
+    let api_key = match settings.provider.api_key_provider() {
+        Some(key_provider) => state.user_api_key_repo.find_by_user_id(user_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .find(|key| key.provider == key_provider)
+            .map(|key| key.api_key),
+        None => None,
+    };
+    let require_key = |api_key: Option<String>| {
+        api_key.ok_or_else(|| format!("API key not found for provider: {}", settings.provider.to_string_key()))
+    };
+
+    let provider: Arc<dyn TranscriptionProvider> = match settings.provider {
+        TranscriptionProviderType::Local => {
+            return whisper_transcriber(app, whisper_model.unwrap_or(&model), progress);
+        }
+        TranscriptionProviderType::OpenAI => {
+            Arc::new(OpenAITranscriptionClient::new(OPENAI_API_BASE_URL, Some(require_key(api_key)?), model))
+        }
+        TranscriptionProviderType::Gemini => {
+            Arc::new(GeminiTranscriptionClient::new(require_key(api_key)?, model))
+        }
+        // Local servers usually take no key
+        TranscriptionProviderType::OpenAICompatible => {
+            let base_url = settings.base_url.as_deref()
+                .map(str::trim)
+                .filter(|url| !url.is_empty())
+                .ok_or_else(|| "No base URL set for the OpenAI-compatible provider".to_string())?;
+            Arc::new(OpenAITranscriptionClient::new(base_url, api_key, model))
+        }
+    };
+    Ok(provider)
+}
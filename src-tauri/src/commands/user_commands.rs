@@ -1,4 +1,4 @@
-use tauri::State;
+use tauri::{AppHandle, Runtime, State};
 use crate::domain::user::{
     usecase::{
         add_api_key::AddApiKeyUseCase,
@@ -15,6 +15,7 @@ use crate::domain::user::{
 };
 use crate::domain::maintenance::entity::UserStats;
 use crate::app_state::AppState;
+use crate::commands::meeting_commands::meetings_dir;
 use uuid::Uuid;
 
 #[tauri::command]
@@ -243,13 +244,25 @@ pub async fn sync_session(dto: SyncSessionDto, state: State<'_, AppState>) -> Re
 
 
 #[tauri::command]
-pub async fn clear_all_data(dto: ClearAllDataDto, state: State<'_, AppState>) -> Result<ClearAllDataResponse, String> {
+pub async fn clear_all_data<R: Runtime>(
+    app: AppHandle<R>,
+    dto: ClearAllDataDto,
+    state: State<'_, AppState>,
+) -> Result<ClearAllDataResponse, String> {
     let user_id = Uuid::parse_str(&dto.user_id)
         .map_err(|e| format!("Invalid user_id format: {}", e))?;
 
     state.maintenance_repo.clear_all_data(user_id)
         .await
         .map_err(|e| format!("Failed to clear all data: {}", e))?;
+    state.incognito_store.clear();
+    if let Ok(dir) = meetings_dir(&app) {
+        if let Err(e) = std::fs::remove_dir_all(&dir) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                log::warn!("Failed to delete meeting recordings: {}", e);
+            }
+        }
+    }
 
     Ok(ClearAllDataResponse { message: "All data cleared successfully".to_string() })
 }
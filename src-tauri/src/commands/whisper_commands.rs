@@ -1,17 +1,42 @@
-use tauri::{AppHandle, Manager, Runtime};
-use std::process::Command;
+use tauri::{AppHandle, Manager, Runtime, State};
 use serde::Serialize;
 use std::fs;
-use std::io::Write;
-use futures_util::StreamExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::app_state::AppState;
+use crate::domain::ai::audio::{
+    audio_input::AudioBuffer,
+    transcribe_audio::{TranscriptionProvider, FallbackProvider},
+    transcript::{Transcript, TranscriptFormat},
+    whisper_model::WHISPER_MODELS,
+};
+use crate::infrastructure::ai::audio::{
+    whisper_client::WhisperCliClient,
+    whisper_local::{ProgressCallback, WhisperLocalClient},
+    whisper_models::WhisperModelStore,
+};
 
 #[derive(Serialize, Clone)]
 pub struct WhisperModelStatus {
     pub name: String,
     pub exists: bool,
+    /// Shipped with the app rather than downloaded; can't be deleted
+    pub bundled: bool,
     pub path: String,
     pub size_desc: String,
     pub ram_desc: String,
+    pub family: String,
+    pub english_only: bool,
+    pub quantization: Option<String>,
+    /// Bytes of an interrupted download the next one resumes from
+    pub partial_bytes: u64,
+    pub downloading: bool,
+}
+
+#[derive(Serialize, Clone)]
+pub struct TranscriptionProgress {
+    pub audio_path: String,
+    pub percentage: i32,
 }
 
 #[derive(Serialize, Clone)]
@@ -22,197 +47,283 @@ pub struct DownloadProgress {
     pub percentage: f64,
 }
 
+/// Downloads `name` into the app's data directory, resuming a download that was
+/// interrupted or cancelled, and installs it once its SHA-256 checks out.
+/// Emits `whisper-download-progress` as it goes.
 #[tauri::command]
 pub async fn download_whisper_model<R: Runtime>(
     app: AppHandle<R>,
     name: String,
+    state: State<'_, AppState>,
 ) -> Result<(), String> {
-    use tauri::path::BaseDirectory;
     use tauri::Emitter;
 
-    let file_name = format!("ggml-{}.bin", name);
-    let models_dir = app
-        .path()
-        .resolve("resources/whisper.cpp/models", BaseDirectory::Resource)
-        .map_err(|e| format!("Failed to resolve models path: {}", e))?;
-
-    if !models_dir.exists() {
-        fs::create_dir_all(&models_dir).map_err(|e| format!("Failed to create models directory: {}", e))?;
-    }
-
-    let file_path = models_dir.join(&file_name);
-    let url = format!(
-        "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/{}",
-        file_name
-    );
-
-    let client = reqwest::Client::new();
-    let response = client
-        .get(url)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to start download: {}", e))?;
-
-    let total_size = response
-        .content_length()
-        .ok_or("Failed to get content length")?;
-
-    let mut file = fs::File::create(&file_path).map_err(|e| format!("Failed to create file: {}", e))?;
-    let mut downloaded: u64 = 0;
-    let mut stream = response.bytes_stream();
-
-    while let Some(item) = stream.next().await {
-        let chunk = item.map_err(|e| format!("Error while downloading: {}", e))?;
-        file.write_all(&chunk)
-            .map_err(|e| format!("Error while writing to file: {}", e))?;
-        downloaded += chunk.len() as u64;
-
-        let percentage = (downloaded as f64 / total_size as f64) * 100.0;
-        let _ = app.emit(
+    let store = model_store(&app)?;
+    let progress_app = app.clone();
+    let progress_name = name.clone();
+    store.download(&name, &state.whisper_downloads, move |downloaded, total| {
+        let _ = progress_app.emit(
             "whisper-download-progress",
             DownloadProgress {
-                name: name.clone(),
+                name: progress_name.clone(),
                 downloaded,
-                total: total_size,
-                percentage,
+                total,
+                percentage: (downloaded as f64 / total.max(1) as f64) * 100.0,
             },
         );
-    }
+    })
+    .await
+    .map(|_| ())
+    .map_err(|e| e.to_string())
+}
 
-    Ok(())
+/// Stops the download of `name`, keeping what was downloaded for resuming.
+#[tauri::command]
+pub async fn cancel_whisper_download(name: String, state: State<'_, AppState>) -> Result<bool, String> {
+    Ok(state.whisper_downloads.cancel(&name))
 }
 
+/// Deletes a downloaded model, unloading it first if it's in use.
 #[tauri::command]
-pub async fn check_whisper_models<R: Runtime>(app: AppHandle<R>) -> Result<Vec<WhisperModelStatus>, String> {
-    use tauri::path::BaseDirectory;
-    
-    let models = vec![
-        ("tiny", "75 MB", "~390 MB"),
-        ("base", "142 MB", "~500 MB"),
-        ("small", "466 MB", "~1 GB"),
-        ("medium", "1.5 GB", "~2.6 GB"),
-        ("large", "2.9 GB", "~4.7 GB"),
-    ];
-
-    let mut statuses = Vec::new();
-
-    for (name, size, ram) in models {
-        let file_name = format!("ggml-{}.bin", name);
-        let model_path_result = app
-            .path()
-            .resolve(
-                format!("resources/whisper.cpp/models/{}", file_name),
-                BaseDirectory::Resource,
-            );
-
-        match model_path_result {
-            Ok(path) => {
-                statuses.push(WhisperModelStatus {
-                    name: name.to_string(),
-                    exists: path.exists(),
-                    path: path.to_string_lossy().to_string(),
-                    size_desc: size.to_string(),
-                    ram_desc: ram.to_string(),
-                });
-            }
-            Err(_) => {
-                 statuses.push(WhisperModelStatus {
-                    name: name.to_string(),
-                    exists: false,
-                    path: "".to_string(),
-                    size_desc: size.to_string(),
-                    ram_desc: ram.to_string(),
-                });
+pub async fn delete_whisper_model<R: Runtime>(
+    app: AppHandle<R>,
+    name: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    if state.whisper_downloads.is_downloading(&name) {
+        return Err(format!("Model {} is downloading; cancel it first", name));
+    }
+    let store = model_store(&app)?;
+    let engine = state.whisper_engine.clone();
+
+    // The engine stays locked for a whole transcription; wait for it off the async workers
+    tauri::async_runtime::spawn_blocking(move || {
+        if let (Some(path), Some(loaded)) = (store.path(&name), engine.loaded_model()) {
+            if path == loaded {
+                engine.unload();
             }
         }
-    }
+        store.delete(&name).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
 
-    Ok(statuses)
+/// Hashes an installed model and compares it with the manifest.
+#[tauri::command]
+pub async fn verify_whisper_model<R: Runtime>(app: AppHandle<R>, name: String) -> Result<bool, String> {
+    model_store(&app)?
+        .verify(&name)
+        .await
+        .map_err(|e| e.to_string())
 }
 
-#[cfg(target_os = "linux")]
 #[tauri::command]
-pub async fn transcribe_with_whisper<R: Runtime>(
+pub async fn check_whisper_models<R: Runtime>(
     app: AppHandle<R>,
-    audio_path: String,
-    model: String,
-    language: String,
-) -> Result<String, String> {
+    state: State<'_, AppState>,
+) -> Result<Vec<WhisperModelStatus>, String> {
+    let store = model_store(&app)?;
+
+    Ok(WHISPER_MODELS.iter()
+        .map(|model| {
+            let path = store.path(model.name);
+            WhisperModelStatus {
+                name: model.name.to_string(),
+                exists: path.is_some(),
+                bundled: path.as_deref().is_some_and(|path| store.is_bundled(path)),
+                path: path.map(|path| path.to_string_lossy().to_string()).unwrap_or_default(),
+                size_desc: model.size_desc.to_string(),
+                ram_desc: model.ram_desc.to_string(),
+                family: model.family.to_string(),
+                english_only: model.english_only,
+                quantization: model.quantization.map(str::to_string),
+                partial_bytes: store.partial_size(model.name),
+                downloading: state.whisper_downloads.is_downloading(model.name),
+            }
+        })
+        .collect())
+}
+
+/// Models download to `<app data>/whisper/models`; the ones bundled in the
+/// app's resources are still found.
+fn model_store<R: Runtime>(app: &AppHandle<R>) -> Result<WhisperModelStore, String> {
     use tauri::path::BaseDirectory;
+
+    let models_dir = app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve models path: {}", e))?
+        .join("whisper")
+        .join("models");
+    let bundled_dir = app.path()
+        .resolve("resources/whisper.cpp/models", BaseDirectory::Resource)
+        .ok();
+    Ok(WhisperModelStore::new(models_dir, bundled_dir))
+}
+
+/// Path of a downloaded model; fails when it isn't there.
+fn model_path<R: Runtime>(app: &AppHandle<R>, model: &str) -> Result<PathBuf, String> {
     use log::{info, error};
 
-    info!("[Whisper] Starting transcription for file: {} with model: {}", audio_path, model);
+    match model_store(app)?.path(model) {
+        Some(model_path) => {
+            info!("[Whisper] Model path: {:?}", model_path);
+            Ok(model_path)
+        }
+        None => {
+            error!("[Whisper] Model {} is not downloaded", model);
+            Err(format!("Model {} is not downloaded", model))
+        }
+    }
+}
+
+/// The bundled whisper.cpp binary, when there is one.
+#[cfg(target_os = "linux")]
+fn cli_client<R: Runtime>(app: &AppHandle<R>, model_path: &Path) -> Option<WhisperCliClient> {
+    use tauri::path::BaseDirectory;
 
     let resource_path = app
         .path()
         .resolve("resources/whisper.cpp/whisper", BaseDirectory::Resource)
-        .map_err(|e| format!("Failed to resolve whisper binary path: {}", e))?;
-
-    let model_path = app
-        .path()
-        .resolve(
-            format!("resources/whisper.cpp/models/ggml-{}.bin", model),
-            BaseDirectory::Resource,
-        )
-        .map_err(|e| format!("Failed to resolve model path: {}", e))?;
-
-    info!("[Whisper] Binary path: {:?}", resource_path);
-    info!("[Whisper] Model path: {:?}", model_path);
+        .ok()?;
 
     if !resource_path.exists() {
-         error!("[Whisper] Binary not found at {:?}", resource_path);
-         return Err(format!("Whisper binary not found at {:?}", resource_path));
-    }
-    if !model_path.exists() {
-         error!("[Whisper] Model not found at {:?}", model_path);
-         return Err(format!("Model not found at {:?}", model_path));
+        log::warn!("[Whisper] Binary not found at {:?}, no fallback available", resource_path);
+        return None;
     }
 
-    // -m model
-    // -f file
-    // -l lang
-    // -nt (No timestamps)
-    info!("[Whisper] Executing whisper command...");
-    
-    // Extract 2-letter code if possible (e.g., pt-BR -> pt)
-    let lang_code = if language.contains('-') {
-        language.split('-').next().unwrap_or(&language)
-    } else {
-        &language
-    };
-
-    let output = Command::new(resource_path)
-        .arg("-m")
-        .arg(model_path)
-        .arg("-f")
-        .arg(&audio_path)
-        .arg("-l")
-        .arg(lang_code) 
-        .arg("-nt") 
-        .output()
-        .map_err(|e| format!("Failed to execute whisper: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        error!("[Whisper] Command failed: {}", stderr);
-        return Err(format!("Whisper failed: {}", stderr));
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let trimmed = stdout.trim().to_string();
-    info!("[Whisper] Transcription success. Length: {}", trimmed.len());
-    info!("[Whisper] Transcription result: {}", trimmed);
-    
-    Ok(trimmed)
+    Some(WhisperCliClient::new(resource_path, model_path.to_path_buf()))
 }
 
 #[cfg(not(target_os = "linux"))]
+fn cli_client<R: Runtime>(_app: &AppHandle<R>, _model_path: &Path) -> Option<WhisperCliClient> {
+    None
+}
+
+/// Whisper with `model`: in process, falling back to the bundled binary where
+/// there is one.
+pub fn whisper_transcriber<R: Runtime>(
+    app: &AppHandle<R>,
+    model: &str,
+    progress: Option<ProgressCallback>,
+) -> Result<Arc<dyn TranscriptionProvider>, String> {
+    let model_path = model_path(app, model)?;
+    let engine = app.state::<AppState>().whisper_engine.clone();
+    let local: Arc<dyn TranscriptionProvider> = Arc::new(
+        WhisperLocalClient::new(engine, model_path.clone()).with_progress(progress),
+    );
+
+    Ok(match cli_client(app, &model_path) {
+        Some(cli) => Arc::new(FallbackProvider::new(local, Arc::new(cli))),
+        None => local,
+    })
+}
+
+/// Loads `model` now so the first transcription doesn't wait for it.
+#[tauri::command]
+pub async fn preload_whisper_model<R: Runtime>(
+    app: AppHandle<R>,
+    model: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let model_path = model_path(&app, &model)?;
+    let engine = state.whisper_engine.clone();
+
+    tauri::async_runtime::spawn_blocking(move || engine.load(&model_path))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// Frees the memory held by the loaded model.
+#[tauri::command]
+pub async fn unload_whisper_model(state: State<'_, AppState>) -> Result<(), String> {
+    let engine = state.whisper_engine.clone();
+    // Waits for a running transcription, which holds the engine
+    tauri::async_runtime::spawn_blocking(move || engine.unload())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Transcribes a WAV file of any rate and channel count; anything that isn't a
+/// readable WAV file is rejected. Emits `whisper-transcription-progress` while
+/// the model runs.
 #[tauri::command]
 pub async fn transcribe_with_whisper<R: Runtime>(
-    _app: AppHandle<R>,
-    _audio_path: String,
-    _model: String,
-    _language: String,
+    app: AppHandle<R>,
+    audio_path: String,
+    model: String,
+    language: String,
+) -> Result<String, String> {
+    let transcript = transcribe_wav(&app, &audio_path, &model, &language).await?;
+    let text = transcript.text();
+    log::info!("[Whisper] Transcription result: {}", text);
+    Ok(text)
+}
+
+/// Like `transcribe_with_whisper`, keeping the timing of each segment, its
+/// confidence and the detected language.
+#[tauri::command]
+pub async fn transcribe_segments_with_whisper<R: Runtime>(
+    app: AppHandle<R>,
+    audio_path: String,
+    model: String,
+    language: String,
+) -> Result<Transcript, String> {
+    transcribe_wav(&app, &audio_path, &model, &language).await
+}
+
+/// Renders a transcript as SubRip, WebVTT or JSON. With `path`, the result is
+/// also written there.
+#[tauri::command]
+pub async fn export_transcript(
+    transcript: Transcript,
+    format: TranscriptFormat,
+    path: Option<String>,
 ) -> Result<String, String> {
-    Err("Whisper is only supported on Linux".to_string())
+    let content = transcript.export(format).map_err(|e| e.to_string())?;
+    if let Some(path) = path {
+        fs::write(&path, &content)
+            .map_err(|e| format!("Failed to write transcript to {}: {}", path, e))?;
+        log::info!("[Whisper] Exported {} transcript to {}", format.extension(), path);
+    }
+    Ok(content)
+}
+
+async fn transcribe_wav<R: Runtime>(
+    app: &AppHandle<R>,
+    audio_path: &str,
+    model: &str,
+    language: &str,
+) -> Result<Transcript, String> {
+    use log::{info, error};
+    use tauri::Emitter;
+
+    info!("[Whisper] Starting transcription for file: {} with model: {}", audio_path, model);
+
+    let path = Path::new(audio_path);
+    if !path.is_file() {
+        return Err(format!("Audio file not found: {}", audio_path));
+    }
+    let audio = AudioBuffer::read_wav(path).map_err(|e| e.to_string())?;
+
+    let progress_app = app.clone();
+    let progress_path = audio_path.to_string();
+    let progress: ProgressCallback = Arc::new(move |percentage| {
+        let _ = progress_app.emit("whisper-transcription-progress", TranscriptionProgress {
+            audio_path: progress_path.clone(),
+            percentage,
+        });
+    });
+    let transcriber = whisper_transcriber(app, model, Some(progress))?;
+
+    let transcript = transcriber.transcribe_segments(&audio, language)
+        .await
+        .map_err(|e| {
+            error!("[Whisper] Transcription failed: {}", e);
+            e.to_string()
+        })?;
+
+    info!("[Whisper] Transcription success. Segments: {}, language: {}", transcript.segments.len(), transcript.language);
+    Ok(transcript)
 }
@@ -0,0 +1,136 @@
+use std::path::Path;
+use tauri::State;
+use crate::app_state::AppState;
+use crate::domain::workspace::{
+    entity::{IndexReport, Workspace, WorkspaceChunkMatch},
+    usecase::index_workspace::IndexWorkspaceUseCase,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+use chrono::Utc;
+
+#[derive(Debug, Deserialize)]
+pub struct AddWorkspaceDto {
+    pub user_id: String,
+    pub root_path: String,
+    pub name: Option<String>, // Defaults to the folder name
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchWorkspaceDto {
+    pub user_id: String,
+    pub workspace_id: String,
+    pub query: String,
+    pub limit: Option<i64>,
+}
+
+#[tauri::command]
+pub async fn add_workspace(dto: AddWorkspaceDto, state: State<'_, AppState>) -> Result<Workspace, String> {
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    let root = Path::new(&dto.root_path)
+        .canonicalize()
+        .map_err(|e| format!("Invalid workspace path: {}", e))?;
+    if !root.is_dir() {
+        return Err("Workspace path must be a directory".to_string());
+    }
+
+    let name = dto.name
+        .filter(|n| !n.trim().is_empty())
+        .or_else(|| root.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "Workspace".to_string());
+
+    let workspace = Workspace {
+        id: Uuid::new_v4(),
+        user_id,
+        name,
+        root_path: root.to_string_lossy().to_string(),
+        last_indexed_at: None,
+        created_at: Utc::now(),
+    };
+
+    let workspace = state.workspace_repo.create(&workspace)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // First index runs in the background, large repos can take a while
+    let index_workspace_usecase = IndexWorkspaceUseCase::new(state.workspace_repo.clone());
+    let workspace_id = workspace.id;
+    tokio::spawn(async move {
+        match index_workspace_usecase.execute(workspace_id).await {
+            Ok(report) => log::info!("[Workspace] Indexed {}: {:?}", workspace_id, report),
+            Err(e) => log::error!("[Workspace] Failed to index {}: {}", workspace_id, e),
+        }
+    });
+
+    Ok(workspace)
+}
+
+#[tauri::command]
+pub async fn get_workspaces(user_id: String, state: State<'_, AppState>) -> Result<Vec<Workspace>, String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+
+    state.workspace_repo.find_by_user_id(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Fails with "Workspace not found" unless the workspace belongs to `user_id`
+async fn ensure_owned(state: &AppState, user_id: Uuid, workspace_id: Uuid) -> Result<(), String> {
+    state.workspace_repo.find_by_id(workspace_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .filter(|workspace| workspace.user_id == user_id)
+        .ok_or_else(|| "Workspace not found".to_string())?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn reindex_workspace(user_id: String, workspace_id: String, state: State<'_, AppState>) -> Result<IndexReport, String> {
+    let index_workspace_usecase = IndexWorkspaceUseCase::new(
+        state.workspace_repo.clone(),
+    );
+
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let workspace_id = Uuid::parse_str(&workspace_id)
+        .map_err(|e| format!("Invalid workspace_id format: {}", e))?;
+    ensure_owned(&state, user_id, workspace_id).await?;
+
+    index_workspace_usecase.execute(workspace_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_workspace(user_id: String, workspace_id: String, state: State<'_, AppState>) -> Result<(), String> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let workspace_id = Uuid::parse_str(&workspace_id)
+        .map_err(|e| format!("Invalid workspace_id format: {}", e))?;
+
+    let deleted = state.workspace_repo.delete(user_id, workspace_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if !deleted {
+        return Err("Workspace not found".to_string());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn search_workspace(dto: SearchWorkspaceDto, state: State<'_, AppState>) -> Result<Vec<WorkspaceChunkMatch>, String> {
+    let user_id = Uuid::parse_str(&dto.user_id)
+        .map_err(|e| format!("Invalid user_id format: {}", e))?;
+    let workspace_id = Uuid::parse_str(&dto.workspace_id)
+        .map_err(|e| format!("Invalid workspace_id format: {}", e))?;
+    ensure_owned(&state, user_id, workspace_id).await?;
+
+    state.workspace_repo.search_chunks(workspace_id, &dto.query, dto.limit.unwrap_or(10))
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::domain::ai::chat::entity::message::Message;
+
+pub const CALENDAR_EVENT_TOOL: &str = "calendar_event";
+pub const NOTION_PAGE_TOOL: &str = "notion_page";
+pub const MCP_TOOL: &str = "mcp";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionStatus {
+    Pending,
+    /// Approved and running
+    Approved,
+    Executed,
+    Failed,
+    Rejected,
+    Undone,
+}
+
+impl ActionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActionStatus::Pending => "pending",
+            ActionStatus::Approved => "approved",
+            ActionStatus::Executed => "executed",
+            ActionStatus::Failed => "failed",
+            ActionStatus::Rejected => "rejected",
+            ActionStatus::Undone => "undone",
+        }
+    }
+}
+
+impl std::str::FromStr for ActionStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(ActionStatus::Pending),
+            "approved" => Ok(ActionStatus::Approved),
+            "executed" => Ok(ActionStatus::Executed),
+            "failed" => Ok(ActionStatus::Failed),
+            "rejected" => Ok(ActionStatus::Rejected),
+            "undone" => Ok(ActionStatus::Undone),
+            _ => Err(anyhow::anyhow!("Unknown action status: {}", s)),
+        }
+    }
+}
+
+/// A side effect the model asked for (calendar event, Notion page, MCP tool call).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposedAction {
+    pub id: Uuid,
+    pub message_id: Uuid,
+    pub chat_id: Uuid,
+    pub user_id: Uuid,
+    pub tool: String,
+    pub title: String,
+    pub arguments: serde_json::Value,
+    pub status: ActionStatus,
+    pub result: Option<String>,
+    pub provider_name: Option<String>,
+    pub model: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl ProposedAction {
+    /// Key matched against the auto-approve list. MCP tools are approved one by one.
+    pub fn approval_key(&self) -> String {
+        if self.tool == MCP_TOOL {
+            let server = self.arguments.get("server_name").and_then(|v| v.as_str()).unwrap_or_default();
+            let tool = self.arguments.get("tool").and_then(|v| v.as_str()).unwrap_or_default();
+            format!("{}:{}/{}", MCP_TOOL, server, tool)
+        } else {
+            self.tool.clone()
+        }
+    }
+}
+
+/// Result of approving an action. MCP tools also produce a follow-up reply
+/// where the model uses the tool output.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionOutcome {
+    pub action: ProposedAction,
+    pub follow_up: Option<Message>,
+}
+
+/// What to call to revert an executed action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UndoHandle {
+    /// Delete the created calendar event
+    CalendarEvent { event_id: Uuid },
+    /// Archive the created Notion page
+    NotionPage { page_id: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionLogEntry {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub chat_id: Uuid,
+    pub message_id: Uuid,
+    pub action_id: Option<Uuid>,
+    pub tool: String,
+    pub arguments: serde_json::Value,
+    pub result: Option<String>,
+    pub succeeded: bool,
+    pub undo_handle: Option<UndoHandle>,
+    pub undone_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
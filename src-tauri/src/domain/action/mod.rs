@@ -0,0 +1,4 @@
+pub mod entity;
+pub mod repository;
+pub mod notifier;
+pub mod usecase;
@@ -0,0 +1,27 @@
+use std::sync::Mutex;
+use super::entity::ProposedAction;
+
+type Callback = Box<dyn Fn(&ProposedAction) + Send + Sync>;
+
+/// Tells the UI about new and resolved actions. The app registers the callback
+/// at startup; until then notifications are dropped.
+#[derive(Default)]
+pub struct ActionNotifier {
+    callback: Mutex<Option<Callback>>,
+}
+
+impl ActionNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_callback(&self, callback: impl Fn(&ProposedAction) + Send + Sync + 'static) {
+        *self.callback.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    pub fn notify(&self, action: &ProposedAction) {
+        if let Some(callback) = self.callback.lock().unwrap().as_ref() {
+            callback(action);
+        }
+    }
+}
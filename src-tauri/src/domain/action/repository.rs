@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use super::entity::{ActionLogEntry, ActionStatus, ProposedAction};
+
+#[async_trait]
+pub trait ProposedActionRepository: Send + Sync {
+    async fn create(&self, action: &ProposedAction) -> Result<ProposedAction>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ProposedAction>>;
+    async fn find_by_chat_id(&self, chat_id: Uuid) -> Result<Vec<ProposedAction>>;
+    /// Persists status, result, arguments (after edits) and resolved_at.
+    async fn update(&self, action: &ProposedAction) -> Result<()>;
+    /// Moves a pending action to `status`. False when it was no longer pending,
+    /// e.g. because another approval or a rejection got there first.
+    async fn claim_pending(&self, id: Uuid, status: ActionStatus) -> Result<bool>;
+}
+
+#[async_trait]
+pub trait ActionLogRepository: Send + Sync {
+    async fn create(&self, entry: &ActionLogEntry) -> Result<ActionLogEntry>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ActionLogEntry>>;
+    /// Newest first, optionally limited to one chat.
+    async fn find_by_user_id(&self, user_id: Uuid, chat_id: Option<Uuid>, limit: i64, offset: i64) -> Result<Vec<ActionLogEntry>>;
+    /// Sets `undone_at` unless it is already set. False when another undo got there first.
+    async fn mark_undone(&self, id: Uuid, at: DateTime<Utc>) -> Result<bool>;
+    /// Clears `undone_at` again, for an undo that failed.
+    async fn clear_undone(&self, id: Uuid) -> Result<()>;
+}
@@ -0,0 +1,2 @@
+pub mod reject_action;
+pub mod undo_action;
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use uuid::Uuid;
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use crate::domain::action::{
+    entity::{ActionStatus, ProposedAction},
+    notifier::ActionNotifier,
+    repository::ProposedActionRepository,
+};
+
+pub struct RejectActionUseCase {
+    action_repo: Arc<dyn ProposedActionRepository>,
+    action_notifier: Arc<ActionNotifier>,
+}
+
+impl RejectActionUseCase {
+    pub fn new(action_repo: Arc<dyn ProposedActionRepository>, action_notifier: Arc<ActionNotifier>) -> Self {
+        Self { action_repo, action_notifier }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, action_id: Uuid) -> Result<ProposedAction> {
+        let mut action = self.action_repo.find_by_id(action_id).await?
+            .filter(|action| action.user_id == user_id)
+            .ok_or_else(|| anyhow!("Action not found"))?;
+
+        if action.status != ActionStatus::Pending {
+            return Err(anyhow!("Action was already {}", action.status.as_str()));
+        }
+        // An approval may have started since the check above
+        if !self.action_repo.claim_pending(action.id, ActionStatus::Rejected).await? {
+            return Err(anyhow!("Action was already resolved"));
+        }
+
+        action.status = ActionStatus::Rejected;
+        action.resolved_at = Some(Utc::now());
+        self.action_repo.update(&action).await?;
+        self.action_notifier.notify(&action);
+
+        Ok(action)
+    }
+}
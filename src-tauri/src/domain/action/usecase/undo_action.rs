@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use uuid::Uuid;
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use crate::domain::action::{
+    entity::{ActionLogEntry, ActionStatus, UndoHandle},
+    notifier::ActionNotifier,
+    repository::{ActionLogRepository, ProposedActionRepository},
+};
+use crate::domain::calendar::usecase::delete_event::DeleteEventUseCase;
+use crate::domain::notion::usecase::archive_page::ArchivePageUseCase;
+
+pub struct UndoActionUseCase {
+    action_log_repo: Arc<dyn ActionLogRepository>,
+    action_repo: Arc<dyn ProposedActionRepository>,
+    action_notifier: Arc<ActionNotifier>,
+    delete_event_usecase: DeleteEventUseCase,
+    archive_page_usecase: ArchivePageUseCase,
+}
+
+impl UndoActionUseCase {
+    pub fn new(
+        action_log_repo: Arc<dyn ActionLogRepository>,
+        action_repo: Arc<dyn ProposedActionRepository>,
+        action_notifier: Arc<ActionNotifier>,
+        delete_event_usecase: DeleteEventUseCase,
+        archive_page_usecase: ArchivePageUseCase,
+    ) -> Self {
+        Self {
+            action_log_repo,
+            action_repo,
+            action_notifier,
+            delete_event_usecase,
+            archive_page_usecase,
+        }
+    }
+
+    pub async fn execute(&self, log_id: Uuid) -> Result<ActionLogEntry> {
+        let mut entry = self.action_log_repo.find_by_id(log_id).await?
+            .ok_or_else(|| anyhow!("Action log entry not found"))?;
+
+        if entry.undone_at.is_some() {
+            return Err(anyhow!("Action was already undone"));
+        }
+        let handle = match (&entry.undo_handle, entry.succeeded) {
+            (Some(handle), true) => handle.clone(),
+            _ => return Err(anyhow!("This action cannot be undone")),
+        };
+
+        // Claim the entry first so that two undos racing each other run it once
+        let now = Utc::now();
+        if !self.action_log_repo.mark_undone(entry.id, now).await? {
+            return Err(anyhow!("Action was already undone"));
+        }
+
+        let undone = match handle {
+            UndoHandle::CalendarEvent { event_id } => {
+                self.delete_event_usecase.execute(entry.user_id, event_id).await
+            }
+            UndoHandle::NotionPage { page_id } => {
+                self.archive_page_usecase.execute(entry.user_id, &page_id).await
+            }
+        };
+        if let Err(e) = undone {
+            if let Err(clear_error) = self.action_log_repo.clear_undone(entry.id).await {
+                log::warn!("[Action] Failed to release undo of {}: {}", entry.id, clear_error);
+            }
+            return Err(e);
+        }
+        entry.undone_at = Some(now);
+
+        // Keep the proposal shown next to the message in sync
+        if let Some(action_id) = entry.action_id {
+            if let Some(mut action) = self.action_repo.find_by_id(action_id).await? {
+                action.status = ActionStatus::Undone;
+                self.action_repo.update(&action).await?;
+                self.action_notifier.notify(&action);
+            }
+        }
+
+        log::info!("Undid {} action {}", entry.tool, entry.id);
+        Ok(entry)
+    }
+}
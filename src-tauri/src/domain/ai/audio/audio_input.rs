@@ -0,0 +1,565 @@
+use std::path::Path;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+/// What whisper expects: 16 kHz mono 16-bit PCM.
+pub const WHISPER_SAMPLE_RATE: u32 = 16_000;
+/// Zero crossings of the resampling filter on each side of a sample.
+const RESAMPLER_ZERO_CROSSINGS: usize = 16;
+/// Precomputed filter points per input sample; in between is interpolated.
+const RESAMPLER_TABLE_RESOLUTION: usize = 256;
+
+/// What a recording listens to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureSource {
+    /// The input device (the user)
+    Microphone,
+    /// What the computer plays: meeting participants, videos
+    System,
+    /// Both, kept on separate channels: microphone left, system audio right
+    Mixed,
+}
+
+/// Who said a transcript line in a mixed recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Speaker {
+    /// The microphone
+    Me,
+    /// System audio
+    Them,
+}
+
+impl Speaker {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Speaker::Me => "Me",
+            Speaker::Them => "Them",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioDeviceKind {
+    /// A microphone or line in
+    Input,
+    /// Records what an output device plays (monitor, loopback)
+    Monitor,
+}
+
+/// A format a device can capture in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AudioDeviceConfig {
+    pub channels: u16,
+    pub min_sample_rate: u32,
+    pub max_sample_rate: u32,
+    pub sample_format: String,
+}
+
+/// A capture device as offered to the user. Devices are identified by name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AudioDevice {
+    pub name: String,
+    pub kind: AudioDeviceKind,
+    pub is_default: bool,
+    pub configs: Vec<AudioDeviceConfig>,
+}
+
+/// Mono PCM audio with samples in `[-1.0, 1.0]`.
+#[derive(Debug, Clone, Default)]
+pub struct AudioBuffer {
+    pub samples: Vec<f32>,
+    pub sample_rate: u32,
+}
+
+impl AudioBuffer {
+    pub fn new(sample_rate: u32) -> Self {
+        Self { samples: Vec::new(), sample_rate }
+    }
+
+    /// Appends interleaved frames, averaging the channels down to mono.
+    pub fn push_interleaved(&mut self, data: &[f32], channels: u16) {
+        let channels = channels.max(1) as usize;
+        if channels == 1 {
+            self.samples.extend_from_slice(data);
+            return;
+        }
+        self.samples.extend(
+            data.chunks_exact(channels)
+                .map(|frame| frame.iter().sum::<f32>() / channels as f32),
+        );
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        self.samples_to_ms(self.samples.len())
+    }
+
+    pub fn ms_to_samples(&self, ms: u64) -> usize {
+        (ms * self.sample_rate as u64 / 1000) as usize
+    }
+
+    pub fn samples_to_ms(&self, samples: usize) -> u64 {
+        if self.sample_rate == 0 {
+            return 0;
+        }
+        samples as u64 * 1000 / self.sample_rate as u64
+    }
+
+    /// Copy of `start..end`, clamped to the buffer.
+    pub fn slice(&self, start: usize, end: usize) -> AudioBuffer {
+        let end = end.min(self.samples.len());
+        let start = start.min(end);
+        AudioBuffer {
+            samples: self.samples[start..end].to_vec(),
+            sample_rate: self.sample_rate,
+        }
+    }
+
+    /// Root mean square of `start..end`.
+    pub fn rms(&self, start: usize, end: usize) -> f32 {
+        let end = end.min(self.samples.len());
+        let start = start.min(end);
+        if start == end {
+            return 0.0;
+        }
+        let sum_squares: f32 = self.samples[start..end].iter().map(|s| s * s).sum();
+        (sum_squares / (end - start) as f32).sqrt()
+    }
+
+    /// Splits the audio into pieces of at most `max_ms`, each ending at the
+    /// quietest moment of its last `search_ms` so words aren't cut in half.
+    /// Every piece comes with where it starts, in milliseconds.
+    pub fn split_at_pauses(&self, max_ms: u64, search_ms: u64) -> Vec<(u64, AudioBuffer)> {
+        let max_len = self.ms_to_samples(max_ms).max(1);
+        let search = self.ms_to_samples(search_ms).min(max_len / 2);
+        let frame = self.ms_to_samples(30).max(1);
+
+        let mut pieces = Vec::new();
+        let mut start = 0;
+        while start < self.samples.len() {
+            let mut end = (start + max_len).min(self.samples.len());
+            if end < self.samples.len() {
+                let mut cut = end;
+                let mut quietest = f32::MAX;
+                let mut position = end - search;
+                while position + frame <= end {
+                    let rms = self.rms(position, position + frame);
+                    if rms < quietest {
+                        quietest = rms;
+                        cut = position + frame / 2;
+                    }
+                    position += frame;
+                }
+                end = cut;
+            }
+            pieces.push((self.samples_to_ms(start), self.slice(start, end)));
+            start = end;
+        }
+        pieces
+    }
+
+    /// Reads a PCM or float WAV file of any channel count, downmixed to mono.
+    /// Fails on anything that isn't a readable, non-empty WAV file.
+    pub fn read_wav(path: &Path) -> Result<AudioBuffer> {
+        let (spec, samples) = Self::read_wav_samples(path)?;
+        let mut buffer = AudioBuffer::new(spec.sample_rate);
+        buffer.push_interleaved(&samples, spec.channels);
+        if buffer.is_empty() {
+            return Err(anyhow!("Audio file is empty"));
+        }
+        Ok(buffer)
+    }
+
+    /// Reads a WAV file with one buffer per channel, e.g. both speakers of a
+    /// mixed recording.
+    pub fn read_wav_channels(path: &Path) -> Result<Vec<AudioBuffer>> {
+        let (spec, samples) = Self::read_wav_samples(path)?;
+        let channels = spec.channels as usize;
+        if samples.len() < channels {
+            return Err(anyhow!("Audio file is empty"));
+        }
+        Ok((0..channels)
+            .map(|channel| AudioBuffer {
+                samples: samples.iter().skip(channel).step_by(channels).copied().collect(),
+                sample_rate: spec.sample_rate,
+            })
+            .collect())
+    }
+
+    /// Length of a WAV file, from its header.
+    pub fn wav_duration_ms(path: &Path) -> Result<u64> {
+        let reader = hound::WavReader::open(path)
+            .map_err(|e| anyhow!("Not a valid WAV file ({}): {}", path.display(), e))?;
+        let sample_rate = reader.spec().sample_rate;
+        if sample_rate == 0 {
+            return Err(anyhow!("Invalid WAV header: 0 Hz"));
+        }
+        Ok(reader.duration() as u64 * 1000 / sample_rate as u64)
+    }
+
+    /// Rewrites the sizes in the header of a WAV file that was never finalized
+    /// (the app quit mid-recording), so its data chunk covers the rest of the file.
+    pub fn repair_wav_header(path: &Path) -> Result<()> {
+        use std::io::{Read, Seek, SeekFrom, Write};
+
+        let mut file = std::fs::OpenOptions::new().read(true).write(true).open(path)
+            .map_err(|e| anyhow!("Failed to open {}: {}", path.display(), e))?;
+        let len = file.metadata()?.len();
+        let mut riff = [0u8; 12];
+        file.read_exact(&mut riff)
+            .map_err(|e| anyhow!("Not a valid WAV file ({}): {}", path.display(), e))?;
+        if &riff[0..4] != b"RIFF" || &riff[8..12] != b"WAVE" {
+            return Err(anyhow!("Not a valid WAV file ({})", path.display()));
+        }
+
+        // Walk the chunks up to `data`; everything after its header is samples,
+        // down to the last whole frame
+        let mut offset = 12u64;
+        let mut block_align = 1u64;
+        loop {
+            let mut header = [0u8; 8];
+            file.seek(SeekFrom::Start(offset))?;
+            file.read_exact(&mut header)
+                .map_err(|_| anyhow!("No data chunk in {}", path.display()))?;
+            let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as u64;
+            if &header[0..4] == b"fmt " {
+                let mut format = [0u8; 14];
+                file.read_exact(&mut format)?;
+                block_align = u16::from_le_bytes([format[12], format[13]]).max(1) as u64;
+            }
+            if &header[0..4] == b"data" {
+                let samples_len = len.saturating_sub(offset + 8);
+                let data_size = u32::try_from(samples_len - samples_len % block_align).unwrap_or(u32::MAX);
+                let riff_size = u32::try_from(len - 8).unwrap_or(u32::MAX);
+                file.seek(SeekFrom::Start(offset + 4))?;
+                file.write_all(&data_size.to_le_bytes())?;
+                file.seek(SeekFrom::Start(4))?;
+                file.write_all(&riff_size.to_le_bytes())?;
+                return Ok(());
+            }
+            offset += 8 + size + size % 2;
+        }
+    }
+
+    fn read_wav_samples(path: &Path) -> Result<(hound::WavSpec, Vec<f32>)> {
+        let mut reader = hound::WavReader::open(path)
+            .map_err(|e| anyhow!("Not a valid WAV file ({}): {}", path.display(), e))?;
+        let spec = reader.spec();
+        if spec.channels == 0 || spec.sample_rate == 0 {
+            return Err(anyhow!("Invalid WAV header: {} channels at {} Hz", spec.channels, spec.sample_rate));
+        }
+
+        let samples: Vec<f32> = match (spec.sample_format, spec.bits_per_sample) {
+            (hound::SampleFormat::Float, 32) => reader.samples::<f32>().collect::<Result<_, _>>(),
+            (hound::SampleFormat::Int, bits @ 8..=32) => {
+                let scale = (1i64 << (bits - 1)) as f32;
+                reader.samples::<i32>()
+                    .map(|s| s.map(|s| s as f32 / scale))
+                    .collect::<Result<_, _>>()
+            }
+            (format, bits) => return Err(anyhow!("Unsupported WAV format: {:?} {}-bit", format, bits)),
+        }
+        .map_err(|e| anyhow!("Failed to read WAV samples: {}", e))?;
+        Ok((spec, samples))
+    }
+
+    pub fn resampled(&self, sample_rate: u32) -> AudioBuffer {
+        if sample_rate == self.sample_rate {
+            return self.clone();
+        }
+        let mut resampler = Resampler::new(self.sample_rate, sample_rate);
+        let mut samples = resampler.process(&self.samples);
+        samples.extend(resampler.finish());
+        AudioBuffer { samples, sample_rate }
+    }
+
+    /// Writes the buffer as a 16-bit mono WAV file.
+    pub fn write_wav(&self, path: &Path) -> Result<()> {
+        let file = std::io::BufWriter::new(
+            std::fs::File::create(path).map_err(|e| anyhow!("Failed to create WAV file: {}", e))?,
+        );
+        self.write_wav_to(file)
+    }
+
+    /// The audio as a 16-bit mono WAV file, for uploading.
+    pub fn to_wav_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = std::io::Cursor::new(Vec::new());
+        self.write_wav_to(&mut bytes)?;
+        Ok(bytes.into_inner())
+    }
+
+    fn write_wav_to<W: std::io::Write + std::io::Seek>(&self, out: W) -> Result<()> {
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: self.sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+
+        let mut writer = hound::WavWriter::new(out, spec)
+            .map_err(|e| anyhow!("Failed to create WAV file: {}", e))?;
+        for &sample in &self.samples {
+            writer.write_sample(f32_to_i16(sample))
+                .map_err(|e| anyhow!("Failed to write WAV sample: {}", e))?;
+        }
+        writer.finalize()
+            .map_err(|e| anyhow!("Failed to finalize WAV file: {}", e))
+    }
+}
+
+/// Band-limited (windowed sinc) sample rate conversion over a stream of chunks.
+/// Output lags the input by the filter's half-width until `finish`.
+pub struct Resampler {
+    from_rate: u64,
+    to_rate: u64,
+    half_width: usize,
+    /// The filter at distances `0..=half_width` input samples
+    kernel: Vec<f32>,
+    input: Vec<f32>,
+    /// Position of the next output sample in `input`, in `1/to_rate` input
+    /// samples so that it never drifts however the input is chunked
+    position: u64,
+}
+
+impl Resampler {
+    pub fn new(from_rate: u32, to_rate: u32) -> Self {
+        let step = from_rate.max(1) as f64 / to_rate.max(1) as f64;
+        // Downsampling must remove what the new rate can't represent
+        let cutoff = (1.0 / step).min(1.0) * 0.95;
+        let half_width = (RESAMPLER_ZERO_CROSSINGS as f64 / cutoff).ceil() as usize;
+        let kernel = (0..=half_width * RESAMPLER_TABLE_RESOLUTION + 1)
+            .map(|i| {
+                let x = i as f64 / RESAMPLER_TABLE_RESOLUTION as f64;
+                let window = 0.5 + 0.5 * (std::f64::consts::PI * x / (half_width as f64 + 1.0)).cos();
+                (cutoff * sinc(cutoff * x) * window) as f32
+            })
+            .collect();
+        Self {
+            from_rate: from_rate.max(1) as u64,
+            to_rate: to_rate.max(1) as u64,
+            half_width,
+            kernel,
+            input: Vec::new(),
+            position: 0,
+        }
+    }
+
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        if self.from_rate == self.to_rate {
+            return samples.to_vec();
+        }
+        self.input.extend_from_slice(samples);
+
+        let mut output = Vec::with_capacity((samples.len() as u64 * self.to_rate / self.from_rate) as usize + 1);
+        while self.position + (self.half_width as u64) * self.to_rate < self.input.len() as u64 * self.to_rate {
+            output.push(self.sample_at(self.position));
+            self.position += self.from_rate;
+        }
+
+        // Keep the samples the next outputs still reach back to
+        let consumed = ((self.position / self.to_rate) as usize)
+            .saturating_sub(self.half_width)
+            .min(self.input.len());
+        self.input.drain(..consumed);
+        self.position -= consumed as u64 * self.to_rate;
+        output
+    }
+
+    /// Emits the samples still held back, as if the input were followed by silence.
+    pub fn finish(&mut self) -> Vec<f32> {
+        let end = self.input.len() as u64 * self.to_rate;
+        let mut output = Vec::new();
+        while self.position < end {
+            output.push(self.sample_at(self.position));
+            self.position += self.from_rate;
+        }
+        self.input.clear();
+        self.position = 0;
+        output
+    }
+
+    fn sample_at(&self, position: u64) -> f32 {
+        let center = (position / self.to_rate) as isize;
+        let position = position as f64 / self.to_rate as f64;
+        let half_width = self.half_width as isize;
+        let mut sum = 0.0f32;
+        for index in (center - half_width + 1).max(0)..=(center + half_width) {
+            let Some(&sample) = self.input.get(index as usize) else { break };
+            let x = (position - index as f64).abs() * RESAMPLER_TABLE_RESOLUTION as f64;
+            let i = x as usize;
+            let fraction = (x - i as f64) as f32;
+            let weight = self.kernel[i] + (self.kernel[i + 1] - self.kernel[i]) * fraction;
+            sum += sample * weight;
+        }
+        sum
+    }
+}
+
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1e-9 {
+        1.0
+    } else {
+        let x = std::f64::consts::PI * x;
+        x.sin() / x
+    }
+}
+
+/// Writes audio the way whisper wants it (16 kHz mono 16-bit PCM) while it is
+/// being captured at the device's rate.
+pub struct NormalizedWavWriter {
+    writer: hound::WavWriter<std::io::BufWriter<std::fs::File>>,
+    resampler: Resampler,
+}
+
+impl NormalizedWavWriter {
+    pub fn create(path: &Path, input_rate: u32) -> Result<Self> {
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: WHISPER_SAMPLE_RATE,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let writer = hound::WavWriter::create(path, spec)
+            .map_err(|e| anyhow!("Failed to create WAV file: {}", e))?;
+        Ok(Self { writer, resampler: Resampler::new(input_rate, WHISPER_SAMPLE_RATE) })
+    }
+
+    /// Resamples mono `samples`, writes them and returns what was written.
+    pub fn write(&mut self, samples: &[f32]) -> Result<Vec<f32>> {
+        let normalized = self.resampler.process(samples);
+        self.write_normalized(&normalized)?;
+        Ok(normalized)
+    }
+
+    pub fn finalize(mut self) -> Result<()> {
+        let rest = self.resampler.finish();
+        self.write_normalized(&rest)?;
+        self.writer.finalize()
+            .map_err(|e| anyhow!("Failed to finalize WAV file: {}", e))
+    }
+
+    fn write_normalized(&mut self, samples: &[f32]) -> Result<()> {
+        for &sample in samples {
+            self.writer.write_sample(f32_to_i16(sample))
+                .map_err(|e| anyhow!("Failed to write WAV sample: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+pub fn f32_to_i16(sample: f32) -> i16 {
+    (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16
+}
+
+pub fn i16_to_f32(sample: i16) -> f32 {
+    sample as f32 / i16::MAX as f32
+}
+
+/// Unsigned samples are centered on 32768.
+pub fn u16_to_f32(sample: u16) -> f32 {
+    (sample as f32 - 32768.0) / 32768.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(hz: f32, amplitude: f32, ms: u64, sample_rate: u32) -> Vec<f32> {
+        (0..(sample_rate as u64 * ms / 1000) as usize)
+            .map(|i| amplitude * (2.0 * std::f32::consts::PI * hz * i as f32 / sample_rate as f32).sin())
+            .collect()
+    }
+
+    fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+        let mut resampler = Resampler::new(from_rate, to_rate);
+        let mut output = resampler.process(samples);
+        output.extend(resampler.finish());
+        output
+    }
+
+    /// RMS away from the edges, where the filter sees the silence around the input.
+    fn steady_rms(samples: &[f32]) -> f32 {
+        let edge = samples.len() / 10;
+        let middle = &samples[edge..samples.len() - edge];
+        (middle.iter().map(|s| s * s).sum::<f32>() / middle.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn resampling_to_16k_keeps_the_amplitude() {
+        let expected = 0.5 / std::f32::consts::SQRT_2;
+        for from_rate in [48_000, 44_100, 8_000] {
+            let output = resample(&sine(1000.0, 0.5, 1000, from_rate), from_rate, WHISPER_SAMPLE_RATE);
+            let rms = steady_rms(&output);
+            assert!((rms - expected).abs() < 0.01, "{} Hz: rms {}", from_rate, rms);
+        }
+    }
+
+    #[test]
+    fn resampling_keeps_the_duration() {
+        for from_rate in [48_000, 44_100, 8_000] {
+            let output = resample(&sine(440.0, 0.5, 1000, from_rate), from_rate, WHISPER_SAMPLE_RATE);
+            assert!(output.len().abs_diff(WHISPER_SAMPLE_RATE as usize) <= 1, "{} Hz: {} samples", from_rate, output.len());
+        }
+    }
+
+    #[test]
+    fn downsampling_removes_what_16k_cannot_represent() {
+        // Without filtering, these would alias down to 4 kHz and 2.05 kHz
+        for (from_rate, hz) in [(48_000, 12_000.0), (44_100, 13_950.0)] {
+            let output = resample(&sine(hz, 0.5, 1000, from_rate), from_rate, WHISPER_SAMPLE_RATE);
+            let rms = steady_rms(&output);
+            assert!(rms < 0.01, "{} Hz tone at {} Hz: rms {}", hz, from_rate, rms);
+        }
+    }
+
+    #[test]
+    fn chunked_resampling_matches_one_shot() {
+        for from_rate in [48_000, 44_100, 8_000] {
+            let input = sine(1000.0, 0.5, 500, from_rate);
+            let one_shot = resample(&input, from_rate, WHISPER_SAMPLE_RATE);
+
+            let mut resampler = Resampler::new(from_rate, WHISPER_SAMPLE_RATE);
+            let mut chunked = Vec::new();
+            let mut rest = &input[..];
+            for size in [1, 7, 480, 1, 1024, 333].iter().cycle() {
+                if rest.is_empty() {
+                    break;
+                }
+                let (chunk, tail) = rest.split_at((*size).min(rest.len()));
+                chunked.extend(resampler.process(chunk));
+                rest = tail;
+            }
+            chunked.extend(resampler.finish());
+
+            assert_eq!(chunked.len(), one_shot.len(), "{} Hz", from_rate);
+            for (i, (a, b)) in chunked.iter().zip(&one_shot).enumerate() {
+                assert!((a - b).abs() < 1e-5, "{} Hz, sample {}: {} != {}", from_rate, i, a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn same_rate_passes_through() {
+        let input = sine(1000.0, 0.5, 100, WHISPER_SAMPLE_RATE);
+        assert_eq!(resample(&input, WHISPER_SAMPLE_RATE, WHISPER_SAMPLE_RATE), input);
+    }
+
+    #[test]
+    fn unsigned_samples_are_centered() {
+        assert_eq!(u16_to_f32(0), -1.0);
+        assert_eq!(u16_to_f32(32768), 0.0);
+        assert!((u16_to_f32(u16::MAX) - 1.0).abs() < 1e-4);
+        assert_eq!(i16_to_f32(0), 0.0);
+        assert_eq!(i16_to_f32(i16::MAX), 1.0);
+    }
+}
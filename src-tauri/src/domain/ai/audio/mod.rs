@@ -0,0 +1,7 @@
+pub mod audio_input;
+pub mod transcribe_audio;
+pub mod transcript;
+pub mod streaming;
+pub mod vad;
+pub mod whisper_model;
+pub mod repository;
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use super::{transcribe_audio::TranscriptionSettings, vad::VadConfig};
+
+#[async_trait]
+pub trait VadSettingsRepository: Send + Sync {
+    /// `None` when the user never changed the defaults.
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<VadConfig>>;
+    async fn save(&self, user_id: Uuid, config: &VadConfig) -> Result<()>;
+}
+
+#[async_trait]
+pub trait TranscriptionSettingsRepository: Send + Sync {
+    /// `None` when the user never picked a provider.
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<TranscriptionSettings>>;
+    async fn save(&self, user_id: Uuid, settings: &TranscriptionSettings) -> Result<()>;
+}
@@ -0,0 +1,395 @@
+use anyhow::Result;
+use serde::Serialize;
+use super::{
+    audio_input::AudioBuffer,
+    transcribe_audio::{TranscriptionProvider, clean_transcript},
+    transcript::TranscriptSegment,
+};
+
+/// Length of the frames used to look for pauses.
+const FRAME_MS: u64 = 30;
+/// Most words compared when removing text repeated by the overlap.
+const MAX_OVERLAP_WORDS: usize = 8;
+
+#[derive(Debug, Clone)]
+pub struct StreamingConfig {
+    /// New audio needed before the open segment is transcribed again
+    pub hop_ms: u64,
+    /// Audio before a segment's start sent along with it, so words cut at the
+    /// boundary are heard whole
+    pub overlap_ms: u64,
+    /// A segment is only closed at a pause once it is this long
+    pub min_segment_ms: u64,
+    /// A segment is closed here even without a pause
+    pub max_segment_ms: u64,
+    /// Silence that counts as a pause
+    pub pause_ms: u64,
+    /// RMS below which a frame is silence
+    pub silence_threshold: f32,
+}
+
+impl Default for StreamingConfig {
+    fn default() -> Self {
+        Self {
+            hop_ms: 1500,
+            overlap_ms: 800,
+            min_segment_ms: 3000,
+            max_segment_ms: 15000,
+            pause_ms: 500,
+            silence_threshold: 0.015,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TranscriptEvent {
+    /// Best guess so far: `committed` won't change, `tentative` may
+    Partial { committed: String, tentative: String },
+    /// A closed segment; its text is final
+    Segment { index: usize, text: String, start_ms: u64, end_ms: u64, confidence: Option<f32> },
+}
+
+/// Incremental transcription of audio that is still being captured.
+///
+/// Audio is split into segments at pauses (or at `max_segment_ms`). The open
+/// segment is re-transcribed every `hop_ms` and reported as tentative text; once
+/// it is closed, its text is final and the next segment starts. Each window
+/// includes `overlap_ms` of the previous segment, and words repeated because of
+/// that overlap are dropped.
+pub struct StreamingTranscription {
+    config: StreamingConfig,
+    audio: AudioBuffer,
+    segment_start: usize,
+    transcribed_until: usize,
+    /// Samples of closed segments already dropped from `audio`
+    dropped: usize,
+    segments: Vec<TranscriptSegment>,
+}
+
+impl StreamingTranscription {
+    pub fn new(sample_rate: u32, config: StreamingConfig) -> Self {
+        Self {
+            config,
+            audio: AudioBuffer::new(sample_rate),
+            segment_start: 0,
+            transcribed_until: 0,
+            dropped: 0,
+            segments: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, samples: &[f32]) {
+        self.audio.samples.extend_from_slice(samples);
+    }
+
+    /// Final text of the closed segments.
+    pub fn text(&self) -> String {
+        self.segments.iter()
+            .map(|s| s.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn segments(&self) -> &[TranscriptSegment] {
+        &self.segments
+    }
+
+    /// Transcribes what was pushed since the last step. With `finish`, the open
+    /// segment is closed whatever its length; call it once capture has stopped.
+    pub async fn step(&mut self, transcriber: &dyn TranscriptionProvider, language: &str, finish: bool) -> Result<Vec<TranscriptEvent>> {
+        // Only the open segment and its overlap are needed from here on
+        let keep_from = self.segment_start.saturating_sub(self.audio.ms_to_samples(self.config.overlap_ms));
+        if keep_from > 0 {
+            self.audio.samples.drain(..keep_from);
+            self.segment_start -= keep_from;
+            self.transcribed_until = self.transcribed_until.saturating_sub(keep_from);
+            self.dropped += keep_from;
+        }
+
+        let end = self.audio.len();
+        let hop = self.audio.ms_to_samples(self.config.hop_ms);
+        if !finish && end < self.transcribed_until + hop {
+            return Ok(Vec::new());
+        }
+        self.transcribed_until = end;
+
+        let mut events = Vec::new();
+        let cut = if finish { Some(end) } else { self.find_cut(end) };
+
+        if let Some(cut) = cut {
+            if cut > self.segment_start {
+                let (text, confidence) = self.transcribe_window(transcriber, language, cut).await?;
+                // Segments of pure silence are dropped
+                if !text.is_empty() {
+                    let segment = TranscriptSegment {
+                        text,
+                        start_ms: self.audio.samples_to_ms(self.dropped + self.segment_start),
+                        end_ms: self.audio.samples_to_ms(self.dropped + cut),
+                        confidence,
+                        speaker: None,
+                    };
+                    events.push(TranscriptEvent::Segment {
+                        index: self.segments.len(),
+                        text: segment.text.clone(),
+                        start_ms: segment.start_ms,
+                        end_ms: segment.end_ms,
+                        confidence: segment.confidence,
+                    });
+                    self.segments.push(segment);
+                }
+                self.segment_start = cut;
+            }
+            if finish || end - self.segment_start < hop {
+                events.push(TranscriptEvent::Partial { committed: self.text(), tentative: String::new() });
+                return Ok(events);
+            }
+        }
+
+        let (tentative, _) = self.transcribe_window(transcriber, language, end).await?;
+        events.push(TranscriptEvent::Partial { committed: self.text(), tentative });
+        Ok(events)
+    }
+
+    /// Where to close the open segment: the middle of the first pause after
+    /// `min_segment_ms`, or `end` once the segment is too long.
+    fn find_cut(&self, end: usize) -> Option<usize> {
+        let frame = self.audio.ms_to_samples(FRAME_MS).max(1);
+        let pause_frames = (self.config.pause_ms / FRAME_MS).max(1) as usize;
+        let search_from = self.segment_start + self.audio.ms_to_samples(self.config.min_segment_ms);
+
+        let mut silent_run = 0;
+        let mut position = search_from;
+        while position + frame <= end {
+            if self.audio.rms(position, position + frame) < self.config.silence_threshold {
+                silent_run += 1;
+                if silent_run >= pause_frames {
+                    return Some(position + frame - silent_run * frame / 2);
+                }
+            } else {
+                silent_run = 0;
+            }
+            position += frame;
+        }
+
+        if end - self.segment_start >= self.audio.ms_to_samples(self.config.max_segment_ms) {
+            return Some(end);
+        }
+        None
+    }
+
+    /// Transcribes the open segment up to `end`, with the overlap, and strips the
+    /// words the previous segment already ended with. Returns the text and the
+    /// model's confidence in it.
+    async fn transcribe_window(&self, transcriber: &dyn TranscriptionProvider, language: &str, end: usize) -> Result<(String, Option<f32>)> {
+        let start = self.segment_start.saturating_sub(self.audio.ms_to_samples(self.config.overlap_ms));
+        // Whisper makes up text for silence; don't send it any
+        if self.audio.rms(self.segment_start, end) < self.config.silence_threshold {
+            return Ok((String::new(), None));
+        }
+
+        let window = self.audio.slice(start, end);
+        let transcript = transcriber.transcribe_segments(&window, language).await?;
+        let text = clean_transcript(&transcript.text());
+        let text = match self.segments.last() {
+            Some(previous) => strip_repeated_prefix(&previous.text, &text),
+            None => text,
+        };
+        Ok((text, transcript.confidence()))
+    }
+}
+
+/// Drops the longest run of words at the start of `text` that `previous` ends with.
+fn strip_repeated_prefix(previous: &str, text: &str) -> String {
+    let normalize = |w: &str| {
+        w.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase()
+    };
+    let previous: Vec<String> = previous.split_whitespace().map(normalize).collect();
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    let max = MAX_OVERLAP_WORDS.min(previous.len()).min(words.len());
+    let repeated = (1..=max).rev()
+        .find(|&k| {
+            previous[previous.len() - k..].iter()
+                .zip(&words[..k])
+                .all(|(a, b)| !a.is_empty() && *a == normalize(b))
+        })
+        .unwrap_or(0);
+
+    words[repeated..].join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use async_trait::async_trait;
+
+    const RATE: u32 = 16_000;
+
+    /// Answers each window with the next of `replies`, and remembers how long
+    /// every window it was sent was.
+    struct StubTranscriber {
+        replies: Mutex<Vec<&'static str>>,
+        windows_ms: Mutex<Vec<u64>>,
+    }
+
+    impl StubTranscriber {
+        fn new(replies: &[&'static str]) -> Self {
+            Self {
+                replies: Mutex::new(replies.iter().rev().copied().collect()),
+                windows_ms: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn windows_ms(&self) -> Vec<u64> {
+            self.windows_ms.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl TranscriptionProvider for StubTranscriber {
+        async fn transcribe(&self, audio: &AudioBuffer, _language: &str) -> Result<String> {
+            self.windows_ms.lock().unwrap().push(audio.duration_ms());
+            Ok(self.replies.lock().unwrap().pop().unwrap_or_default().to_string())
+        }
+    }
+
+    fn ms(ms: u64) -> usize {
+        (RATE as u64 * ms / 1000) as usize
+    }
+
+    fn silence(duration_ms: u64) -> Vec<f32> {
+        vec![0.0; ms(duration_ms)]
+    }
+
+    fn tone(duration_ms: u64) -> Vec<f32> {
+        (0..ms(duration_ms))
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / RATE as f32).sin())
+            .collect()
+    }
+
+    fn stream_of(parts: &[Vec<f32>]) -> StreamingTranscription {
+        let mut stream = StreamingTranscription::new(RATE, StreamingConfig::default());
+        for part in parts {
+            stream.push(part);
+        }
+        stream
+    }
+
+    fn segments(events: &[TranscriptEvent]) -> Vec<(String, u64, u64)> {
+        events.iter()
+            .filter_map(|e| match e {
+                TranscriptEvent::Segment { text, start_ms, end_ms, .. } => Some((text.clone(), *start_ms, *end_ms)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cuts_in_the_middle_of_a_pause() {
+        let stream = stream_of(&[tone(4000), silence(600), tone(1000)]);
+        let cut = stream.find_cut(stream.audio.len()).expect("the pause should close the segment");
+        assert!((ms(4000)..ms(4600)).contains(&cut), "cut at {} ms", cut * 1000 / RATE as usize);
+        assert!(cut.abs_diff(ms(4300)) <= ms(2 * FRAME_MS), "cut at {} ms", cut * 1000 / RATE as usize);
+    }
+
+    #[test]
+    fn pauses_before_the_minimum_length_are_skipped() {
+        let stream = stream_of(&[tone(1000), silence(600), tone(3000), silence(600), tone(500)]);
+        let cut = stream.find_cut(stream.audio.len()).unwrap();
+        assert!((ms(4600)..ms(5200)).contains(&cut), "cut at {} ms", cut * 1000 / RATE as usize);
+    }
+
+    #[test]
+    fn pauses_shorter_than_pause_ms_are_not_cuts() {
+        let stream = stream_of(&[tone(4000), silence(300), tone(2000)]);
+        assert_eq!(stream.find_cut(stream.audio.len()), None);
+    }
+
+    #[test]
+    fn long_segments_are_cut_at_the_end() {
+        let stream = stream_of(&[tone(14_000)]);
+        assert_eq!(stream.find_cut(stream.audio.len()), None);
+
+        let stream = stream_of(&[tone(15_000)]);
+        assert_eq!(stream.find_cut(stream.audio.len()), Some(ms(15_000)));
+    }
+
+    #[tokio::test]
+    async fn waits_for_a_hop_of_new_audio() {
+        let transcriber = StubTranscriber::new(&["hello"]);
+        let mut stream = stream_of(&[tone(1000)]);
+        assert!(stream.step(&transcriber, "en", false).await.unwrap().is_empty());
+        assert!(transcriber.windows_ms().is_empty());
+
+        stream.push(&tone(500));
+        let events = stream.step(&transcriber, "en", false).await.unwrap();
+        assert!(matches!(&events[..], [TranscriptEvent::Partial { tentative, .. }] if tentative == "hello"));
+        assert_eq!(transcriber.windows_ms(), vec![1500]);
+    }
+
+    #[tokio::test]
+    async fn segments_stay_timed_after_audio_is_dropped() {
+        let transcriber = StubTranscriber::new(&["one two", "three", "three four"]);
+        let mut stream = stream_of(&[tone(4000), silence(600), tone(2000)]);
+
+        let events = stream.step(&transcriber, "en", false).await.unwrap();
+        let first = segments(&events);
+        assert_eq!(first.len(), 1, "events: {:?}", events);
+        let (_, start_ms, cut_ms) = first[0].clone();
+        assert_eq!(start_ms, 0);
+        assert!((4000..4600).contains(&cut_ms), "cut at {}", cut_ms);
+        assert!(matches!(events.last(), Some(TranscriptEvent::Partial { tentative, .. }) if tentative == "three"));
+
+        stream.push(&tone(2000));
+        let events = stream.step(&transcriber, "en", true).await.unwrap();
+
+        // Everything before the overlap of the open segment is gone
+        let overlap = ms(StreamingConfig::default().overlap_ms);
+        assert_eq!(stream.dropped + stream.segment_start, ms(8600));
+        assert_eq!(stream.dropped, ms(cut_ms) - overlap);
+        assert_eq!(stream.audio.len(), ms(8600) - stream.dropped);
+
+        assert_eq!(segments(&events), vec![("three four".to_string(), cut_ms, 8600)]);
+        assert_eq!(stream.text(), "one two three four");
+
+        // The last window starts `overlap_ms` before the segment it closes
+        let windows = transcriber.windows_ms();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[2], 8600 - cut_ms + StreamingConfig::default().overlap_ms);
+    }
+
+    #[tokio::test]
+    async fn silent_segments_are_not_transcribed() {
+        let transcriber = StubTranscriber::new(&["made up"]);
+        let mut stream = stream_of(&[silence(5000)]);
+        let events = stream.step(&transcriber, "en", true).await.unwrap();
+        assert!(segments(&events).is_empty());
+        assert!(transcriber.windows_ms().is_empty());
+        assert_eq!(stream.text(), "");
+    }
+
+    #[test]
+    fn repeated_words_are_stripped() {
+        assert_eq!(strip_repeated_prefix("And then we went home.", "we went home, and slept"), "and slept");
+        assert_eq!(strip_repeated_prefix("Good morning", "Morning! How are you?"), "How are you?");
+    }
+
+    #[test]
+    fn unrelated_text_is_kept() {
+        assert_eq!(strip_repeated_prefix("We went home", "and slept"), "and slept");
+        assert_eq!(strip_repeated_prefix("", "and slept"), "and slept");
+        assert_eq!(strip_repeated_prefix("We went home", ""), "");
+        // Punctuation alone doesn't count as a repeated word
+        assert_eq!(strip_repeated_prefix("wait —", "— yes"), "— yes");
+    }
+
+    #[test]
+    fn only_the_last_words_are_compared() {
+        let previous = "one two three four five six seven eight nine ten";
+        assert_eq!(strip_repeated_prefix(previous, "three four five six seven eight nine ten eleven"), "eleven");
+        assert_eq!(strip_repeated_prefix(previous, "two three four five six seven eight nine ten eleven"), "two three four five six seven eight nine ten eleven");
+        assert_eq!(strip_repeated_prefix(previous, previous), previous);
+    }
+}
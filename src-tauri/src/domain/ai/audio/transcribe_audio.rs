@@ -0,0 +1,179 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use async_trait::async_trait;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use super::{
+    audio_input::AudioBuffer,
+    transcript::{Transcript, TranscriptSegment},
+};
+
+/// Where speech is sent to be turned into text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TranscriptionProviderType {
+    /// whisper.cpp on this computer
+    #[default]
+    #[serde(rename = "local")]
+    Local,
+    /// OpenAI's transcription API
+    #[serde(rename = "openai")]
+    OpenAI,
+    /// Gemini, given the audio as input
+    #[serde(rename = "gemini")]
+    Gemini,
+    /// A server exposing OpenAI's `/audio/transcriptions`, such as a local
+    /// faster-whisper or whisper.cpp server
+    #[serde(rename = "openai_compatible")]
+    OpenAICompatible,
+}
+
+impl FromStr for TranscriptionProviderType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "local" => Ok(TranscriptionProviderType::Local),
+            "openai" => Ok(TranscriptionProviderType::OpenAI),
+            "gemini" => Ok(TranscriptionProviderType::Gemini),
+            "openai_compatible" => Ok(TranscriptionProviderType::OpenAICompatible),
+            _ => Err(anyhow::anyhow!("Unknown transcription provider: {}", s)),
+        }
+    }
+}
+
+impl TranscriptionProviderType {
+    pub fn to_string_key(&self) -> String {
+        match self {
+            TranscriptionProviderType::Local => "local".to_string(),
+            TranscriptionProviderType::OpenAI => "openai".to_string(),
+            TranscriptionProviderType::Gemini => "gemini".to_string(),
+            TranscriptionProviderType::OpenAICompatible => "openai_compatible".to_string(),
+        }
+    }
+
+    /// The `user_api_keys` provider whose key is sent, if any.
+    pub fn api_key_provider(&self) -> Option<&'static str> {
+        match self {
+            TranscriptionProviderType::Local => None,
+            TranscriptionProviderType::OpenAI => Some("openai"),
+            TranscriptionProviderType::Gemini => Some("gemini"),
+            TranscriptionProviderType::OpenAICompatible => Some("openai_compatible"),
+        }
+    }
+
+    /// Model used when the settings don't name one.
+    pub fn default_model(&self) -> &'static str {
+        match self {
+            TranscriptionProviderType::Local => "base",
+            TranscriptionProviderType::OpenAI => "whisper-1",
+            TranscriptionProviderType::Gemini => "gemini-2.5-flash",
+            TranscriptionProviderType::OpenAICompatible => "whisper-1",
+        }
+    }
+}
+
+/// Which provider a user transcribes with.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionSettings {
+    pub provider: TranscriptionProviderType,
+    /// Whisper model name for `local`, API model otherwise
+    pub model: Option<String>,
+    /// Server root for `openai_compatible`, e.g. `http://localhost:8000/v1`
+    pub base_url: Option<String>,
+}
+
+impl TranscriptionSettings {
+    pub fn model(&self) -> &str {
+        self.model.as_deref()
+            .filter(|model| !model.is_empty())
+            .unwrap_or_else(|| self.provider.default_model())
+    }
+}
+
+/// Turns speech into text.
+#[async_trait]
+pub trait TranscriptionProvider: Send + Sync {
+    /// Transcribes `audio`. `language` is a BCP-47 tag (`pt-BR`) or a bare code (`pt`).
+    async fn transcribe(&self, audio: &AudioBuffer, language: &str) -> Result<String>;
+
+    /// Transcribes `audio` into timed segments. Transcribers that can't time
+    /// their output return one segment spanning the whole audio.
+    async fn transcribe_segments(&self, audio: &AudioBuffer, language: &str) -> Result<Transcript> {
+        let text = self.transcribe(audio, language).await?;
+        let segments = if text.is_empty() {
+            Vec::new()
+        } else {
+            vec![TranscriptSegment {
+                text,
+                start_ms: 0,
+                end_ms: audio.duration_ms(),
+                confidence: None,
+                speaker: None,
+            }]
+        };
+        Ok(Transcript { language: language_code(language).to_string(), segments })
+    }
+
+    /// Starts loading what the first transcription needs, in the background.
+    fn warm_up(&self) {}
+}
+
+/// Uses `fallback` for calls `primary` fails.
+pub struct FallbackProvider {
+    primary: Arc<dyn TranscriptionProvider>,
+    fallback: Arc<dyn TranscriptionProvider>,
+}
+
+impl FallbackProvider {
+    pub fn new(primary: Arc<dyn TranscriptionProvider>, fallback: Arc<dyn TranscriptionProvider>) -> Self {
+        Self { primary, fallback }
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for FallbackProvider {
+    async fn transcribe(&self, audio: &AudioBuffer, language: &str) -> Result<String> {
+        match self.primary.transcribe(audio, language).await {
+            Ok(text) => Ok(text),
+            Err(e) => {
+                log::warn!("[Audio] Transcription failed, trying fallback: {}", e);
+                self.fallback.transcribe(audio, language).await
+            }
+        }
+    }
+
+    async fn transcribe_segments(&self, audio: &AudioBuffer, language: &str) -> Result<Transcript> {
+        match self.primary.transcribe_segments(audio, language).await {
+            Ok(transcript) => Ok(transcript),
+            Err(e) => {
+                log::warn!("[Audio] Transcription failed, trying fallback: {}", e);
+                self.fallback.transcribe_segments(audio, language).await
+            }
+        }
+    }
+
+    fn warm_up(&self) {
+        self.primary.warm_up();
+    }
+}
+
+/// Two-letter code understood by speech models (`pt-BR` -> `pt`).
+pub fn language_code(language: &str) -> &str {
+    language.split('-').next().unwrap_or(language)
+}
+
+/// Removes the non-speech markers models emit for silence and noise
+/// (`[BLANK_AUDIO]`, `(music)`, `[ Silence ]`) and collapses whitespace.
+pub fn clean_transcript(text: &str) -> String {
+    let mut cleaned = String::with_capacity(text.len());
+    let mut depth = 0usize;
+    for c in text.chars() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' if depth > 0 => depth -= 1,
+            _ if depth == 0 => cleaned.push(c),
+            _ => {}
+        }
+    }
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,231 @@
+use std::fmt::Write;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use super::audio_input::Speaker;
+
+/// A piece of transcribed speech, timed from the start of the audio.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+    pub text: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    /// Mean token probability, 0.0 to 1.0, when the model reports it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    /// Who spoke, in recordings that keep speakers apart
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<Speaker>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Transcript {
+    /// Two-letter code of the spoken language, detected or as requested
+    pub language: String,
+    pub segments: Vec<TranscriptSegment>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptFormat {
+    Srt,
+    Vtt,
+    Json,
+}
+
+impl TranscriptFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TranscriptFormat::Srt => "srt",
+            TranscriptFormat::Vtt => "vtt",
+            TranscriptFormat::Json => "json",
+        }
+    }
+}
+
+impl Transcript {
+    /// All segments as plain text, labelled by speaker when there are speakers.
+    pub fn text(&self) -> String {
+        let labelled = self.segments.iter().any(|s| s.speaker.is_some());
+        self.segments.iter()
+            .map(|s| (s.speaker, s.text.trim()))
+            .filter(|(_, text)| !text.is_empty())
+            .map(|(speaker, text)| match speaker {
+                Some(speaker) => format!("{}: {}", speaker.label(), text),
+                None => text.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(if labelled { "\n" } else { " " })
+    }
+
+    /// Duration-weighted mean of the segment confidences.
+    pub fn confidence(&self) -> Option<f32> {
+        let (weighted, total) = self.segments.iter()
+            .filter_map(|s| s.confidence.map(|c| (c, s.end_ms.saturating_sub(s.start_ms).max(1) as f32)))
+            .fold((0.0, 0.0), |(weighted, total), (confidence, duration)| {
+                (weighted + confidence * duration, total + duration)
+            });
+        (total > 0.0).then(|| weighted / total)
+    }
+
+    /// Moves every segment `offset_ms` later, for audio cut from a longer recording.
+    pub fn offset(mut self, offset_ms: u64) -> Self {
+        for segment in &mut self.segments {
+            segment.start_ms += offset_ms;
+            segment.end_ms += offset_ms;
+        }
+        self
+    }
+
+    pub fn export(&self, format: TranscriptFormat) -> Result<String> {
+        Ok(match format {
+            TranscriptFormat::Srt => self.to_srt(),
+            TranscriptFormat::Vtt => self.to_vtt(),
+            TranscriptFormat::Json => serde_json::to_string_pretty(self)?,
+        })
+    }
+
+    /// SubRip: numbered cues, `00:00:01,500` timestamps.
+    pub fn to_srt(&self) -> String {
+        let mut out = String::new();
+        for (index, segment) in self.cues().enumerate() {
+            let _ = writeln!(out, "{}", index + 1);
+            let _ = writeln!(out, "{} --> {}", timestamp(segment.start_ms, ','), timestamp(segment.end_ms, ','));
+            match segment.speaker {
+                Some(speaker) => { let _ = writeln!(out, "{}: {}", speaker.label(), segment.text); }
+                None => { let _ = writeln!(out, "{}", segment.text); }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// WebVTT: `00:00:01.500` timestamps, speakers as voice spans.
+    pub fn to_vtt(&self) -> String {
+        let mut out = String::from("WEBVTT\n\n");
+        for segment in self.cues() {
+            let _ = writeln!(out, "{} --> {}", timestamp(segment.start_ms, '.'), timestamp(segment.end_ms, '.'));
+            match segment.speaker {
+                Some(speaker) => { let _ = writeln!(out, "<v {}>{}", speaker.label(), escape_vtt(&segment.text)); }
+                None => { let _ = writeln!(out, "{}", escape_vtt(&segment.text)); }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Segments with text, in time order, each on one line: a blank line ends a
+    /// cue, and players skip cues that end before they start.
+    fn cues(&self) -> impl Iterator<Item = TranscriptSegment> + '_ {
+        let mut segments: Vec<&TranscriptSegment> = self.segments.iter()
+            .filter(|s| !s.text.trim().is_empty())
+            .collect();
+        segments.sort_by_key(|s| s.start_ms);
+        segments.into_iter().map(|s| TranscriptSegment {
+            text: s.text.split_whitespace().collect::<Vec<_>>().join(" "),
+            end_ms: s.end_ms.max(s.start_ms + 1),
+            ..s.clone()
+        })
+    }
+}
+
+/// `HH:MM:SS<separator>mmm`
+fn timestamp(ms: u64, separator: char) -> String {
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        separator,
+        ms % 1000,
+    )
+}
+
+/// Cue text can't contain `-->`, and `<` and `&` start markup.
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, start_ms: u64, end_ms: u64, speaker: Option<Speaker>) -> TranscriptSegment {
+        TranscriptSegment { text: text.to_string(), start_ms, end_ms, confidence: None, speaker }
+    }
+
+    fn transcript(segments: Vec<TranscriptSegment>) -> Transcript {
+        Transcript { language: "en".to_string(), segments }
+    }
+
+    #[test]
+    fn timestamps_roll_over_into_hours() {
+        assert_eq!(timestamp(0, ','), "00:00:00,000");
+        assert_eq!(timestamp(59_999, '.'), "00:00:59.999");
+        assert_eq!(timestamp(3_599_999, ','), "00:59:59,999");
+        assert_eq!(timestamp(3_600_000, ','), "01:00:00,000");
+        assert_eq!(timestamp(3_723_456, '.'), "01:02:03.456");
+        assert_eq!(timestamp(100 * 3_600_000, ','), "100:00:00,000");
+    }
+
+    #[test]
+    fn srt_cues_are_numbered_in_time_order() {
+        let transcript = transcript(vec![
+            segment("Second", 2000, 3000, None),
+            segment("   ", 500, 900, None),
+            segment("First", 0, 1500, None),
+        ]);
+        assert_eq!(
+            transcript.to_srt(),
+            "1\n00:00:00,000 --> 00:00:01,500\nFirst\n\n2\n00:00:02,000 --> 00:00:03,000\nSecond\n\n",
+        );
+    }
+
+    #[test]
+    fn zero_length_cues_get_a_millisecond() {
+        let transcript = transcript(vec![
+            segment("Instant", 1000, 1000, None),
+            segment("Backwards", 2000, 1500, None),
+        ]);
+        let srt = transcript.to_srt();
+        assert!(srt.contains("00:00:01,000 --> 00:00:01,001"), "{}", srt);
+        assert!(srt.contains("00:00:02,000 --> 00:00:02,001"), "{}", srt);
+    }
+
+    #[test]
+    fn multi_line_text_stays_in_one_cue() {
+        let transcript = transcript(vec![
+            segment("Hello\n\nthere\r\n  you", 0, 1000, None),
+            segment("Next", 1000, 2000, None),
+        ]);
+        assert_eq!(
+            transcript.to_srt(),
+            "1\n00:00:00,000 --> 00:00:01,000\nHello there you\n\n2\n00:00:01,000 --> 00:00:02,000\nNext\n\n",
+        );
+        assert_eq!(
+            transcript.to_vtt(),
+            "WEBVTT\n\n00:00:00.000 --> 00:00:01.000\nHello there you\n\n00:00:01.000 --> 00:00:02.000\nNext\n\n",
+        );
+    }
+
+    #[test]
+    fn vtt_escapes_markup() {
+        assert_eq!(escape_vtt("a --> b"), "a --&gt; b");
+        assert_eq!(escape_vtt("<b>Tom & Jerry</b>"), "&lt;b&gt;Tom &amp; Jerry&lt;/b&gt;");
+        assert_eq!(escape_vtt("&lt;"), "&amp;lt;");
+    }
+
+    #[test]
+    fn speakers_are_labelled() {
+        let transcript = transcript(vec![
+            segment("Hi", 0, 1000, Some(Speaker::Me)),
+            segment("Hello <you>", 1000, 2000, Some(Speaker::Them)),
+        ]);
+        let vtt = transcript.to_vtt();
+        assert!(vtt.contains(&format!("<v {}>Hi\n", Speaker::Me.label())), "{}", vtt);
+        assert!(vtt.contains(&format!("<v {}>Hello &lt;you&gt;\n", Speaker::Them.label())), "{}", vtt);
+        let srt = transcript.to_srt();
+        assert!(srt.contains(&format!("{}: Hello <you>\n", Speaker::Them.label())), "{}", srt);
+    }
+}
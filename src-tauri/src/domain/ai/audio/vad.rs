@@ -0,0 +1,473 @@
+use serde::{Deserialize, Serialize};
+
+/// Length of the frames the detectors classify.
+const FRAME_MS: u64 = 30;
+/// Lowest noise floor, so digital silence doesn't make every sound speech.
+const MIN_NOISE_FLOOR: f32 = 1e-4;
+/// Frames at the start that only measure the room (300 ms); people rarely talk
+/// the instant recording starts.
+const WARMUP_FRAMES: usize = 10;
+/// Voice energy sits between these frequencies (telephone band).
+const SPEECH_BAND_LOW_HZ: f32 = 300.0;
+const SPEECH_BAND_HIGH_HZ: f32 = 3400.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VadAlgorithm {
+    /// Loudness above an adaptive noise floor
+    Energy,
+    /// WebRTC-style: loudness of the voice band above its noise floor, and most
+    /// of the frame's energy in that band. Ignores hum, rumble and hiss.
+    Spectral,
+}
+
+/// Voice activity detection settings, saved per user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VadConfig {
+    pub algorithm: VadAlgorithm,
+    /// RMS a frame needs to be speech, however quiet the room is
+    pub min_rms: f32,
+    /// How far above the noise floor speech must be, in dB
+    pub snr_db: f32,
+    /// Speech needed before it counts, so clicks and coughs don't
+    pub min_speech_ms: u64,
+    /// Silence tolerated inside speech before it ends
+    pub hangover_ms: u64,
+    /// Silence after speech that stops the recording; 0 never stops it
+    pub silence_timeout_ms: u64,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self {
+            algorithm: VadAlgorithm::Energy,
+            min_rms: 0.015,
+            snr_db: 10.0,
+            min_speech_ms: 90,
+            hangover_ms: 300,
+            silence_timeout_ms: 1500,
+        }
+    }
+}
+
+impl VadConfig {
+    fn snr_gain(&self) -> f32 {
+        10f32.powf(self.snr_db / 20.0)
+    }
+}
+
+/// Times are from the first sample pushed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum VadEvent {
+    SpeechStart { at_ms: u64 },
+    SpeechEnd { at_ms: u64 },
+    /// `silence_timeout_ms` of silence after speech; sent once
+    SilenceTimeout { at_ms: u64 },
+}
+
+/// Classifies one frame of mono audio as speech or not.
+pub trait VoiceDetector: Send {
+    fn is_speech(&mut self, frame: &[f32]) -> bool;
+}
+
+/// Background level estimate: the quietest frame of the warm-up, then follows
+/// quieter sound quickly and louder sound slowly, and barely moves during speech
+/// so a long sentence isn't absorbed.
+#[derive(Debug, Clone)]
+struct NoiseFloor {
+    level: f32,
+    warmup_left: usize,
+}
+
+impl Default for NoiseFloor {
+    fn default() -> Self {
+        Self { level: f32::MAX, warmup_left: WARMUP_FRAMES }
+    }
+}
+
+impl NoiseFloor {
+    fn warming_up(&self) -> bool {
+        self.warmup_left > 0
+    }
+
+    /// Whether a frame at `rms` is loud enough to be speech.
+    fn is_above(&self, rms: f32, min_rms: f32, snr_gain: f32) -> bool {
+        !self.warming_up() && rms > min_rms.max(self.level * snr_gain)
+    }
+
+    fn update(&mut self, rms: f32, is_speech: bool) {
+        let level = if self.warming_up() {
+            self.warmup_left -= 1;
+            self.level.min(rms)
+        } else if rms < self.level {
+            self.level + (rms - self.level) * 0.2
+        } else if !is_speech {
+            self.level + (rms - self.level) * 0.05
+        } else {
+            self.level + (rms - self.level) * 0.0005
+        };
+        self.level = level.max(MIN_NOISE_FLOOR);
+    }
+}
+
+pub struct EnergyDetector {
+    min_rms: f32,
+    snr_gain: f32,
+    floor: NoiseFloor,
+}
+
+impl EnergyDetector {
+    pub fn new(config: &VadConfig) -> Self {
+        Self { min_rms: config.min_rms, snr_gain: config.snr_gain(), floor: NoiseFloor::default() }
+    }
+}
+
+impl VoiceDetector for EnergyDetector {
+    fn is_speech(&mut self, frame: &[f32]) -> bool {
+        let rms = rms(frame);
+        let is_speech = self.floor.is_above(rms, self.min_rms, self.snr_gain);
+        self.floor.update(rms, is_speech);
+        is_speech
+    }
+}
+
+pub struct SpectralDetector {
+    min_rms: f32,
+    snr_gain: f32,
+    floor: NoiseFloor,
+    /// One-pole low-pass coefficients at the band edges
+    low_alpha: f32,
+    high_alpha: f32,
+    below_band: f32,
+    below_high: f32,
+}
+
+impl SpectralDetector {
+    /// Share of a frame's energy that must be in the voice band.
+    const MIN_BAND_RATIO: f32 = 0.5;
+
+    pub fn new(config: &VadConfig, sample_rate: u32) -> Self {
+        let alpha = |cutoff: f32| {
+            1.0 - (-2.0 * std::f32::consts::PI * cutoff / sample_rate.max(1) as f32).exp()
+        };
+        Self {
+            min_rms: config.min_rms,
+            snr_gain: config.snr_gain(),
+            floor: NoiseFloor::default(),
+            low_alpha: alpha(SPEECH_BAND_LOW_HZ),
+            high_alpha: alpha(SPEECH_BAND_HIGH_HZ.min(sample_rate as f32 * 0.45)),
+            below_band: 0.0,
+            below_high: 0.0,
+        }
+    }
+}
+
+impl VoiceDetector for SpectralDetector {
+    fn is_speech(&mut self, frame: &[f32]) -> bool {
+        let mut band_energy = 0.0;
+        let mut total_energy = 0.0;
+        for &sample in frame {
+            self.below_band += self.low_alpha * (sample - self.below_band);
+            self.below_high += self.high_alpha * (sample - self.below_high);
+            let band = self.below_high - self.below_band;
+            band_energy += band * band;
+            total_energy += sample * sample;
+        }
+
+        let band_rms = (band_energy / frame.len().max(1) as f32).sqrt();
+        let ratio = if total_energy > 0.0 { band_energy / total_energy } else { 0.0 };
+        let is_speech = ratio >= Self::MIN_BAND_RATIO
+            && self.floor.is_above(band_rms, self.min_rms, self.snr_gain);
+        self.floor.update(band_rms, is_speech);
+        is_speech
+    }
+}
+
+/// Turns a stream of samples into speech start/end events and stops recordings
+/// that have gone quiet.
+pub struct VoiceActivityDetector {
+    config: VadConfig,
+    detector: Box<dyn VoiceDetector>,
+    frame_len: usize,
+    pending: Vec<f32>,
+    elapsed_ms: u64,
+    speaking: bool,
+    speech_run_ms: u64,
+    silence_run_ms: u64,
+    heard_speech: bool,
+    timed_out: bool,
+}
+
+impl VoiceActivityDetector {
+    pub fn new(sample_rate: u32, config: VadConfig) -> Self {
+        let detector: Box<dyn VoiceDetector> = match config.algorithm {
+            VadAlgorithm::Energy => Box::new(EnergyDetector::new(&config)),
+            VadAlgorithm::Spectral => Box::new(SpectralDetector::new(&config, sample_rate)),
+        };
+        Self {
+            config,
+            detector,
+            frame_len: ((sample_rate as u64 * FRAME_MS / 1000) as usize).max(1),
+            pending: Vec::new(),
+            elapsed_ms: 0,
+            speaking: false,
+            speech_run_ms: 0,
+            silence_run_ms: 0,
+            heard_speech: false,
+            timed_out: false,
+        }
+    }
+
+    pub fn is_speaking(&self) -> bool {
+        self.speaking
+    }
+
+    /// Whether speech has been heard at all.
+    pub fn heard_speech(&self) -> bool {
+        self.heard_speech
+    }
+
+    /// Feeds mono samples; partial frames wait for the next push.
+    pub fn push(&mut self, samples: &[f32]) -> Vec<VadEvent> {
+        self.pending.extend_from_slice(samples);
+        let mut events = Vec::new();
+        let mut offset = 0;
+        while offset + self.frame_len <= self.pending.len() {
+            let is_speech = self.detector.is_speech(&self.pending[offset..offset + self.frame_len]);
+            self.elapsed_ms += FRAME_MS;
+            self.advance(is_speech, &mut events);
+            offset += self.frame_len;
+        }
+        self.pending.drain(..offset);
+        events
+    }
+
+    fn advance(&mut self, is_speech: bool, events: &mut Vec<VadEvent>) {
+        if is_speech {
+            self.speech_run_ms += FRAME_MS;
+            self.silence_run_ms = 0;
+            if !self.speaking && self.speech_run_ms >= self.config.min_speech_ms {
+                self.speaking = true;
+                self.heard_speech = true;
+                self.timed_out = false;
+                events.push(VadEvent::SpeechStart { at_ms: self.elapsed_ms - self.speech_run_ms });
+            }
+            return;
+        }
+
+        self.speech_run_ms = 0;
+        self.silence_run_ms += FRAME_MS;
+        if self.speaking && self.silence_run_ms > self.config.hangover_ms {
+            self.speaking = false;
+            events.push(VadEvent::SpeechEnd { at_ms: self.elapsed_ms - self.silence_run_ms });
+        }
+        if self.heard_speech
+            && !self.timed_out
+            && self.config.silence_timeout_ms > 0
+            && self.silence_run_ms >= self.config.silence_timeout_ms
+        {
+            self.timed_out = true;
+            events.push(VadEvent::SilenceTimeout { at_ms: self.elapsed_ms });
+        }
+    }
+}
+
+fn rms(frame: &[f32]) -> f32 {
+    if frame.is_empty() {
+        return 0.0;
+    }
+    (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const RATE: u32 = 16_000;
+
+    /// `test.wav` at the repository root, downmixed to mono.
+    fn fixture() -> (Vec<f32>, u32) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../test.wav");
+        read_wav(&path)
+    }
+
+    fn read_wav(path: &Path) -> (Vec<f32>, u32) {
+        let mut reader = hound::WavReader::open(path).expect("fixture should open");
+        let spec = reader.spec();
+        let channels = spec.channels as usize;
+        let samples: Vec<f32> = match spec.sample_format {
+            hound::SampleFormat::Int => reader.samples::<i16>()
+                .map(|s| s.unwrap() as f32 / i16::MAX as f32)
+                .collect(),
+            hound::SampleFormat::Float => reader.samples::<f32>().map(|s| s.unwrap()).collect(),
+        };
+        let mono = samples.chunks_exact(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect();
+        (mono, spec.sample_rate)
+    }
+
+    fn silence(ms: u64, sample_rate: u32) -> Vec<f32> {
+        vec![0.0; (sample_rate as u64 * ms / 1000) as usize]
+    }
+
+    fn sine(hz: f32, amplitude: f32, ms: u64, sample_rate: u32) -> Vec<f32> {
+        (0..(sample_rate as u64 * ms / 1000) as usize)
+            .map(|i| amplitude * (2.0 * std::f32::consts::PI * hz * i as f32 / sample_rate as f32).sin())
+            .collect()
+    }
+
+    /// Deterministic white noise.
+    fn noise(amplitude: f32, ms: u64, sample_rate: u32) -> Vec<f32> {
+        let mut state = 0x2545_f491_u32;
+        (0..(sample_rate as u64 * ms / 1000) as usize)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                amplitude * (state as f32 / u32::MAX as f32 * 2.0 - 1.0)
+            })
+            .collect()
+    }
+
+    /// Pushes the audio in 10 ms chunks, like a capture callback.
+    fn run(samples: &[f32], sample_rate: u32, config: VadConfig) -> Vec<VadEvent> {
+        let mut vad = VoiceActivityDetector::new(sample_rate, config);
+        samples.chunks((sample_rate / 100) as usize)
+            .flat_map(|chunk| vad.push(chunk))
+            .collect()
+    }
+
+    fn starts(events: &[VadEvent]) -> Vec<u64> {
+        events.iter()
+            .filter_map(|e| match e {
+                VadEvent::SpeechStart { at_ms } => Some(*at_ms),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Event times are frame-aligned, so they can be a frame or two off.
+    fn near(actual: u64, expected: u64) -> bool {
+        actual.abs_diff(expected) <= 2 * FRAME_MS
+    }
+
+    fn timeout(events: &[VadEvent]) -> Option<u64> {
+        events.iter().find_map(|e| match e {
+            VadEvent::SilenceTimeout { at_ms } => Some(*at_ms),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn silence_produces_no_events() {
+        assert!(run(&silence(3000, RATE), RATE, VadConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn fixture_between_pauses_starts_and_times_out() {
+        let (audio, rate) = fixture();
+        let audio_ms = audio.len() as u64 * 1000 / rate as u64;
+        let mut samples = silence(1000, rate);
+        samples.extend(&audio);
+        samples.extend(silence(2500, rate));
+
+        let events = run(&samples, rate, VadConfig::default());
+        let starts = starts(&events);
+        assert_eq!(starts.len(), 1, "events: {:?}", events);
+        assert!(near(starts[0], 1000), "started at {}", starts[0]);
+
+        let end_of_audio = 1000 + audio_ms;
+        let stop = timeout(&events).expect("silence after the fixture should time out");
+        assert!(
+            near(stop, end_of_audio + 1500),
+            "timed out at {}, audio ended at {}", stop, end_of_audio,
+        );
+    }
+
+    #[test]
+    fn no_timeout_before_speech() {
+        let config = VadConfig { silence_timeout_ms: 500, ..VadConfig::default() };
+        assert_eq!(timeout(&run(&silence(3000, RATE), RATE, config)), None);
+    }
+
+    #[test]
+    fn zero_timeout_never_stops() {
+        let (audio, rate) = fixture();
+        let mut samples = audio;
+        samples.extend(silence(5000, rate));
+        let config = VadConfig { silence_timeout_ms: 0, ..VadConfig::default() };
+        assert_eq!(timeout(&run(&samples, rate, config)), None);
+    }
+
+    #[test]
+    fn short_pauses_are_bridged_by_hangover() {
+        let mut samples = silence(500, RATE);
+        samples.extend(sine(440.0, 0.3, 1000, RATE));
+        samples.extend(silence(150, RATE));
+        samples.extend(sine(440.0, 0.3, 1000, RATE));
+        let events = run(&samples, RATE, VadConfig::default());
+        assert_eq!(starts(&events).len(), 1, "events: {:?}", events);
+        assert!(!events.iter().any(|e| matches!(e, VadEvent::SpeechEnd { .. })), "events: {:?}", events);
+    }
+
+    #[test]
+    fn clicks_shorter_than_min_speech_are_ignored() {
+        let mut samples = silence(500, RATE);
+        samples.extend(sine(440.0, 0.5, 30, RATE));
+        samples.extend(silence(2000, RATE));
+        assert!(run(&samples, RATE, VadConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn steady_noise_becomes_the_floor() {
+        // Louder than the old fixed 0.015 threshold, which would never have stopped
+        let mut samples = noise(0.05, 3000, RATE);
+        let mut loud = noise(0.05, 1000, RATE);
+        for (sample, tone) in loud.iter_mut().zip(sine(440.0, 0.4, 1000, RATE)) {
+            *sample += tone;
+        }
+        samples.extend(loud);
+        samples.extend(noise(0.05, 2500, RATE));
+
+        let events = run(&samples, RATE, VadConfig::default());
+        let starts = starts(&events);
+        assert_eq!(starts.len(), 1, "events: {:?}", events);
+        assert!(near(starts[0], 3000), "started at {}", starts[0]);
+        assert!(timeout(&events).is_some(), "noise after speech should time out: {:?}", events);
+    }
+
+    #[test]
+    fn spectral_ignores_hum_that_energy_takes_for_speech() {
+        let mut samples = silence(500, RATE);
+        samples.extend(sine(50.0, 0.3, 2000, RATE));
+        let energy = VadConfig { algorithm: VadAlgorithm::Energy, ..VadConfig::default() };
+        let spectral = VadConfig { algorithm: VadAlgorithm::Spectral, ..VadConfig::default() };
+
+        assert_eq!(starts(&run(&samples, RATE, energy)).len(), 1);
+        assert!(starts(&run(&samples, RATE, spectral)).is_empty());
+    }
+
+    #[test]
+    fn spectral_detects_voice_band_tone() {
+        let mut samples = silence(500, RATE);
+        samples.extend(sine(1000.0, 0.3, 2000, RATE));
+        samples.extend(silence(2000, RATE));
+        let config = VadConfig { algorithm: VadAlgorithm::Spectral, ..VadConfig::default() };
+
+        let events = run(&samples, RATE, config);
+        assert_eq!(starts(&events).len(), 1, "events: {:?}", events);
+        assert!(timeout(&events).is_some());
+    }
+
+    #[test]
+    fn config_defaults_fill_missing_fields() {
+        let config: VadConfig = serde_json::from_str(r#"{"algorithm":"spectral","silence_timeout_ms":3000}"#).unwrap();
+        assert_eq!(config.algorithm, VadAlgorithm::Spectral);
+        assert_eq!(config.silence_timeout_ms, 3000);
+        assert_eq!(config.min_rms, VadConfig::default().min_rms);
+    }
+}
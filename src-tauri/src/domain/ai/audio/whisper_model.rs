@@ -0,0 +1,73 @@
+use serde::Serialize;
+
+/// A whisper.cpp model published as `ggml-<name>.bin`.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct WhisperModelInfo {
+    pub name: &'static str,
+    /// Model size: `tiny`, `base`, `small`, `medium`, `large-v3`...
+    pub family: &'static str,
+    /// `.en` models only understand English, and do it better than the
+    /// multilingual one of the same size
+    pub english_only: bool,
+    /// `q5_0`, `q5_1` or `q8_0`: smaller and faster, slightly less accurate
+    pub quantization: Option<&'static str>,
+    pub size_desc: &'static str,
+    pub ram_desc: &'static str,
+}
+
+impl WhisperModelInfo {
+    pub fn file_name(&self) -> String {
+        model_file_name(self.name)
+    }
+}
+
+pub fn model_file_name(name: &str) -> String {
+    format!("ggml-{}.bin", name)
+}
+
+const fn model(
+    name: &'static str,
+    family: &'static str,
+    english_only: bool,
+    quantization: Option<&'static str>,
+    size_desc: &'static str,
+    ram_desc: &'static str,
+) -> WhisperModelInfo {
+    WhisperModelInfo { name, family, english_only, quantization, size_desc, ram_desc }
+}
+
+/// Every model that can be downloaded, smallest first.
+pub const WHISPER_MODELS: &[WhisperModelInfo] = &[
+    model("tiny", "tiny", false, None, "75 MB", "~390 MB"),
+    model("tiny.en", "tiny", true, None, "75 MB", "~390 MB"),
+    model("tiny-q5_1", "tiny", false, Some("q5_1"), "31 MB", "~200 MB"),
+    model("tiny.en-q5_1", "tiny", true, Some("q5_1"), "31 MB", "~200 MB"),
+    model("tiny-q8_0", "tiny", false, Some("q8_0"), "42 MB", "~250 MB"),
+    model("base", "base", false, None, "142 MB", "~500 MB"),
+    model("base.en", "base", true, None, "142 MB", "~500 MB"),
+    model("base-q5_1", "base", false, Some("q5_1"), "57 MB", "~300 MB"),
+    model("base.en-q5_1", "base", true, Some("q5_1"), "57 MB", "~300 MB"),
+    model("base-q8_0", "base", false, Some("q8_0"), "78 MB", "~350 MB"),
+    model("small", "small", false, None, "466 MB", "~1 GB"),
+    model("small.en", "small", true, None, "466 MB", "~1 GB"),
+    model("small-q5_1", "small", false, Some("q5_1"), "181 MB", "~500 MB"),
+    model("small.en-q5_1", "small", true, Some("q5_1"), "181 MB", "~500 MB"),
+    model("small-q8_0", "small", false, Some("q8_0"), "252 MB", "~600 MB"),
+    model("medium", "medium", false, None, "1.5 GB", "~2.6 GB"),
+    model("medium.en", "medium", true, None, "1.5 GB", "~2.6 GB"),
+    model("medium-q5_0", "medium", false, Some("q5_0"), "514 MB", "~1.1 GB"),
+    model("medium.en-q5_0", "medium", true, Some("q5_0"), "514 MB", "~1.1 GB"),
+    model("medium-q8_0", "medium", false, Some("q8_0"), "785 MB", "~1.4 GB"),
+    model("large-v2", "large-v2", false, None, "2.9 GB", "~4.7 GB"),
+    model("large-v2-q5_0", "large-v2", false, Some("q5_0"), "1.1 GB", "~2 GB"),
+    model("large-v2-q8_0", "large-v2", false, Some("q8_0"), "1.5 GB", "~2.6 GB"),
+    model("large-v3", "large-v3", false, None, "2.9 GB", "~4.7 GB"),
+    model("large-v3-q5_0", "large-v3", false, Some("q5_0"), "1.1 GB", "~2 GB"),
+    model("large-v3-turbo", "large-v3-turbo", false, None, "1.5 GB", "~2.6 GB"),
+    model("large-v3-turbo-q5_0", "large-v3-turbo", false, Some("q5_0"), "547 MB", "~1.1 GB"),
+    model("large-v3-turbo-q8_0", "large-v3-turbo", false, Some("q8_0"), "834 MB", "~1.5 GB"),
+];
+
+pub fn find_model(name: &str) -> Option<&'static WhisperModelInfo> {
+    WHISPER_MODELS.iter().find(|model| model.name == name)
+}
@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use chrono::{DateTime, Utc};
+use crate::domain::document::entity::DocumentCitation;
+use crate::domain::ai::chat::entity::generation::MessageGeneration;
+use crate::domain::ai::chat::service::chat_service::CompareTarget;
+use crate::domain::mcp::entity::McpResourceRef;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CreateChatDto {
@@ -7,6 +12,7 @@ pub struct CreateChatDto {
     pub title: Option<String>,
     pub prompt_preset_id: Option<String>,
     pub model: Option<String>,
+    pub is_incognito: Option<bool>, // Memory-only chat, wiped on close
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -25,6 +31,9 @@ pub struct SendMessageDto {
     pub max_tokens: Option<u32>,
     pub image: Option<String>,
     pub output_language: Option<String>,
+    pub workspace_id: Option<String>, // Local code workspace used as extra context
+    pub template_values: Option<HashMap<String, String>>, // Preset template variables
+    pub mcp_resources: Option<Vec<McpResourceRef>>, // MCP resources attached to the message
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -37,6 +46,7 @@ pub struct MessageDto {
     pub created_at: DateTime<Utc>,
     pub follow_ups: Option<Vec<String>>,
     pub tip: Option<String>,
+    pub citations: Option<Vec<DocumentCitation>>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -45,9 +55,42 @@ pub struct SendMessageResponse {
     pub follow_ups: Vec<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct CompareModelsDto {
+    pub user_id: String,
+    pub chat_id: String,
+    pub content: String,
+    pub targets: Vec<CompareTarget>, // At least two provider/model pairs
+    pub temperature: Option<f32>,
+    pub max_tokens: Option<u32>,
+    pub image: Option<String>,
+    pub output_language: Option<String>,
+    pub workspace_id: Option<String>,
+    pub template_values: Option<HashMap<String, String>>,
+    pub mcp_resources: Option<Vec<McpResourceRef>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AlternativeDto {
+    pub provider_name: String,
+    pub model: String,
+    pub message: Option<MessageDto>,
+    pub generation: Option<MessageGeneration>,
+    pub is_canonical: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CompareModelsResponse {
+    pub user_message: MessageDto,
+    pub alternatives: Vec<AlternativeDto>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GetChatsDto {
     pub user_id: String,
+    pub before: Option<String>, // next_cursor of the previous page
+    pub limit: Option<i64>,     // None = all chats
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -56,6 +99,10 @@ pub struct ChatDto {
     pub user_id: String,
     pub title: String,
     pub model: Option<String>,
+    pub is_incognito: bool,
+    pub is_pinned: bool,
+    pub tags: Vec<String>,
+    pub archived_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -63,16 +110,28 @@ pub struct ChatDto {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GetChatsResponse {
     pub chats: Vec<ChatDto>,
+    pub next_cursor: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GetMessagesDto {
     pub chat_id: String,
+    pub before: Option<String>, // next_cursor of the previous page
+    pub limit: Option<i64>,     // None = whole chat
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GetMessagesResponse {
     pub messages: Vec<MessageDto>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpdateChatOrganizationDto {
+    pub chat_id: String,
+    pub pinned: Option<bool>,
+    pub tags: Option<Vec<String>>,
+    pub archived: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -11,6 +11,21 @@ pub struct Chat {
     pub prompt_preset_id: Option<String>,
     #[sqlx(default)]
     pub model: Option<String>,
+    /// Kept in memory only: never written to SQLite, analyzed or exported
+    #[serde(default)]
+    #[sqlx(default)]
+    pub is_incognito: bool,
+    /// Pinned chats are skipped by retention rules that keep pinned chats
+    #[serde(default)]
+    #[sqlx(default)]
+    pub is_pinned: bool,
+    #[serde(default)]
+    #[sqlx(skip)]
+    pub tags: Vec<String>,
+    /// Set when a retention rule archived the chat
+    #[serde(default)]
+    #[sqlx(default)]
+    pub archived_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+/// Provider, model, latency and token usage behind an assistant message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageGeneration {
+    pub message_id: Uuid,
+    pub provider: String,
+    pub model: String,
+    pub latency_ms: i64,
+    pub prompt_tokens: i64,
+    pub completion_tokens: i64,
+    pub total_tokens: i64,
+    pub created_at: DateTime<Utc>,
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
+use crate::domain::document::entity::DocumentCitation;
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Message {
@@ -12,10 +13,18 @@ pub struct Message {
     pub summary: Option<String>,
     pub message_type: String,
     pub importance: i32,
+    /// User message this reply answers; set for compare-mode replies
+    #[sqlx(default)]
+    pub reply_to_id: Option<Uuid>,
+    /// Compare-mode reply that has not been picked as the canonical answer
+    #[sqlx(default)]
+    pub is_alternative: bool,
     #[sqlx(skip)]
     pub follow_ups: Option<Vec<String>>,
     #[sqlx(skip)]
     pub tip: Option<String>,
+    #[sqlx(skip)]
+    pub citations: Option<Vec<DocumentCitation>>,
 }
 
 
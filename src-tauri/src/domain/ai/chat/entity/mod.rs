@@ -1,2 +1,4 @@
 pub mod chat;
 pub mod message;
+pub mod generation;
+pub mod page;
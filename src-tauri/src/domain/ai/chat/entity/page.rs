@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Keyset position in a list ordered by `(created_at, id)`. The frontend gets it
+/// as an opaque string and sends it back to load the next page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl PageCursor {
+    pub fn new(created_at: DateTime<Utc>, id: Uuid) -> Self {
+        Self { created_at, id }
+    }
+
+    /// Whether an item at `(created_at, id)` is older than this cursor, i.e. on a later page.
+    pub fn precedes(&self, created_at: DateTime<Utc>, id: Uuid) -> bool {
+        (created_at, id) < (self.created_at, self.id)
+    }
+}
+
+impl std::fmt::Display for PageCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.created_at.to_rfc3339(), self.id)
+    }
+}
+
+impl std::str::FromStr for PageCursor {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (created_at, id) = s.split_once('|')
+            .ok_or_else(|| anyhow::anyhow!("Invalid page cursor: {}", s))?;
+        Ok(Self {
+            created_at: DateTime::parse_from_rfc3339(created_at)?.with_timezone(&Utc),
+            id: Uuid::parse_str(id)?,
+        })
+    }
+}
+
+/// One page of results; `next_cursor` is `None` on the last page.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<PageCursor>,
+}
@@ -1,14 +1,20 @@
 use uuid::Uuid;
+use chrono::{DateTime, Utc};
 use anyhow::Result;
 use async_trait::async_trait;
-use crate::domain::ai::chat::entity::chat::Chat;
+use crate::domain::ai::chat::entity::{chat::Chat, page::PageCursor};
 
 #[async_trait]
 pub trait ChatRepository: Send + Sync {
     async fn create(&self, chat: Chat) -> Result<Chat>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Chat>>;
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Chat>>;
+    /// Up to `limit` chats older than `before` (newest first).
+    async fn find_page_by_user_id(&self, user_id: Uuid, before: Option<PageCursor>, limit: i64) -> Result<Vec<Chat>>;
     async fn update(&self, chat: Chat) -> Result<Chat>;
+    async fn set_pinned(&self, id: Uuid, pinned: bool) -> Result<()>;
+    async fn set_tags(&self, id: Uuid, tags: Vec<String>) -> Result<()>;
+    async fn set_archived(&self, id: Uuid, archived_at: Option<DateTime<Utc>>) -> Result<()>;
     async fn delete(&self, id: Uuid) -> Result<()>;
     async fn delete_all_by_user_id(&self, user_id: Uuid) -> Result<()>;
 }
@@ -1,12 +1,28 @@
 use uuid::Uuid;
 use anyhow::Result;
 use async_trait::async_trait;
-use crate::domain::ai::chat::entity::message::Message;
+use crate::domain::ai::chat::entity::{generation::MessageGeneration, message::Message, page::PageCursor};
 
 #[async_trait]
 pub trait MessageRepository: Send + Sync {
     async fn create(&self, message: Message) -> Result<Message>;
+    /// Canonical thread of a chat; compare-mode alternatives are excluded.
     async fn find_by_chat_id(&self, chat_id: Uuid) -> Result<Vec<Message>>;
+    /// Up to `limit` canonical messages older than `before`, in chronological order.
+    async fn find_page_by_chat_id(&self, chat_id: Uuid, before: Option<PageCursor>, limit: i64) -> Result<Vec<Message>>;
+    /// The last `limit` canonical messages, in chronological order.
+    async fn find_recent_by_chat_id(&self, chat_id: Uuid, limit: i64) -> Result<Vec<Message>>;
+    /// Summarized messages above `min_importance`, most important first, ignoring
+    /// the `skip_recent` newest messages.
+    async fn find_highlights_by_chat_id(&self, chat_id: Uuid, min_importance: i32, skip_recent: i64, limit: i64) -> Result<Vec<Message>>;
+    async fn count_by_chat_id(&self, chat_id: Uuid) -> Result<i64>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Message>>;
+    /// All replies (picked or not) to a user message.
+    async fn find_alternatives(&self, reply_to_id: Uuid) -> Result<Vec<Message>>;
+    /// Makes `message_id` the canonical reply to `reply_to_id` and demotes its siblings.
+    async fn set_canonical_reply(&self, reply_to_id: Uuid, message_id: Uuid) -> Result<()>;
+    async fn save_generation(&self, generation: &MessageGeneration) -> Result<()>;
+    async fn find_generation(&self, message_id: Uuid) -> Result<Option<MessageGeneration>>;
     async fn update(&self, message: Message) -> Result<Message>;
     async fn delete(&self, id: Uuid) -> Result<()>;
     
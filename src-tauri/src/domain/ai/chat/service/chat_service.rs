@@ -1,9 +1,14 @@
 use async_trait::async_trait;
 use anyhow::Result;
 use uuid::Uuid;
-use crate::domain::ai::chat::entity::message::Message;
+use serde::{Deserialize, Serialize};
+use crate::domain::ai::chat::entity::{generation::MessageGeneration, message::Message};
+use crate::domain::mcp::entity::McpResourceRef;
+use crate::domain::action::entity::ActionOutcome;
+use crate::domain::task::entity::Task;
 
 use std::str::FromStr; // Add this import
+use std::collections::HashMap;
 
 // Enum to represent available AI providers
 pub enum AIProviderType {
@@ -33,6 +38,15 @@ impl AIProviderType {
             AIProviderType::OpenRouter => "openrouter".to_string(),
         }
     }
+
+    /// Model used when the caller didn't pick one (e.g. quick actions).
+    pub fn default_model(&self) -> &'static str {
+        match self {
+            AIProviderType::Gemini => "gemini-2.5-flash",
+            AIProviderType::OpenAI => "gpt-4o-mini",
+            AIProviderType::OpenRouter => "openai/gpt-4o-mini",
+        }
+    }
 }
 
 pub struct ChatServiceRequest {
@@ -45,10 +59,45 @@ pub struct ChatServiceRequest {
     pub max_tokens: Option<u32>,
     pub image: Option<String>,
     pub output_language: Option<String>,
+    pub workspace_id: Option<Uuid>,
+    /// Preset template values (`clipboard`, user parameters)
+    pub template_values: Option<HashMap<String, String>>,
+    /// MCP resources whose contents are added to the context
+    pub mcp_resources: Vec<McpResourceRef>,
+}
+
+/// A provider/model pair to run in compare mode.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompareTarget {
+    pub provider_name: String,
+    pub model: String,
+}
+
+/// One compare-mode reply. `error` is set instead of `message` when the model failed.
+#[derive(Debug, Clone, Serialize)]
+pub struct CompareCandidate {
+    pub provider_name: String,
+    pub model: String,
+    pub message: Option<Message>,
+    pub generation: Option<MessageGeneration>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CompareResult {
+    pub user_message: Message,
+    pub candidates: Vec<CompareCandidate>,
 }
 
 #[async_trait]
 pub trait ChatService: Send + Sync {
     async fn send_message_to_ai(&self, request: ChatServiceRequest) -> Result<(Message, Vec<String>)>;
+    /// Sends one prompt to several models with the same context. Replies are stored
+    /// as alternatives and stay out of the thread until one is picked.
+    async fn compare_models(&self, request: ChatServiceRequest, targets: Vec<CompareTarget>) -> Result<CompareResult>;
+    /// Runs a pending proposed action of the user, optionally with arguments edited by them.
+    async fn approve_action(&self, user_id: Uuid, action_id: Uuid, arguments: Option<serde_json::Value>) -> Result<ActionOutcome>;
+    /// Asks the model for the todos in the chat's recent messages and stores the new ones.
+    async fn extract_tasks(&self, user_id: Uuid, chat_id: Uuid, provider_name: &str, model: &str) -> Result<Vec<Task>>;
 }
 
@@ -16,7 +16,7 @@ impl CreateChatUseCase {
         Self { chat_repo }
     }
 
-            pub async fn execute(&self, user_id: Uuid, title: Option<String>, prompt_preset_id: Option<String>, model: Option<String>) -> Result<Chat> {
+            pub async fn execute(&self, user_id: Uuid, title: Option<String>, prompt_preset_id: Option<String>, model: Option<String>, is_incognito: bool) -> Result<Chat> {
                 // Sanitize prompt_preset_id: treat empty or whitespace strings as None
                 let prompt_preset_id = prompt_preset_id.filter(|s| !s.trim().is_empty());
 
@@ -26,6 +26,10 @@ impl CreateChatUseCase {
                     title,
                     prompt_preset_id,
                     model,
+                    is_incognito,
+                    is_pinned: false,
+                    tags: Vec::new(),
+                    archived_at: None,
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 };
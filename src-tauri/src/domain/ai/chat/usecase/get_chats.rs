@@ -2,7 +2,10 @@ use std::sync::Arc;
 use uuid::Uuid;
 use anyhow::Result;
 use crate::domain::ai::chat::{
-    entity::chat::Chat,
+    entity::{
+        chat::Chat,
+        page::{Page, PageCursor},
+    },
     repository::chat_repository::ChatRepository,
 };
 
@@ -15,7 +18,27 @@ impl GetChatsUseCase {
         Self { chat_repo }
     }
 
-    pub async fn execute(&self, user_id: Uuid) -> Result<Vec<Chat>> {
-        self.chat_repo.find_by_user_id(user_id).await
+    /// Without `limit` every chat is returned. Otherwise returns `limit` chats
+    /// older than `before`, newest first.
+    pub async fn execute(&self, user_id: Uuid, before: Option<PageCursor>, limit: Option<i64>) -> Result<Page<Chat>> {
+        let limit = match limit {
+            Some(limit) => limit.max(1),
+            None => {
+                let items = self.chat_repo.find_by_user_id(user_id).await?;
+                return Ok(Page { items, next_cursor: None });
+            }
+        };
+
+        // One extra row tells whether another page exists
+        let mut items = self.chat_repo.find_page_by_user_id(user_id, before, limit + 1).await?;
+        let has_more = items.len() as i64 > limit;
+        items.truncate(limit as usize);
+        let next_cursor = if has_more {
+            items.last().map(|c| PageCursor::new(c.created_at, c.id))
+        } else {
+            None
+        };
+
+        Ok(Page { items, next_cursor })
     }
 }
@@ -2,7 +2,10 @@ use std::sync::Arc;
 use uuid::Uuid;
 use anyhow::Result;
 use crate::domain::ai::chat::{
-    entity::message::Message,
+    entity::{
+        message::Message,
+        page::{Page, PageCursor},
+    },
     repository::message_repository::MessageRepository,
 };
 
@@ -15,7 +18,30 @@ impl GetMessagesUseCase {
         Self { message_repo }
     }
 
-    pub async fn execute(&self, chat_id: Uuid) -> Result<Vec<Message>> {
-        self.message_repo.find_by_chat_id(chat_id).await
+    /// Without `limit` the whole chat is returned. Otherwise returns the `limit`
+    /// messages before `before` (the newest ones when `None`), oldest first;
+    /// `next_cursor` points at older messages when there are any.
+    pub async fn execute(&self, chat_id: Uuid, before: Option<PageCursor>, limit: Option<i64>) -> Result<Page<Message>> {
+        let limit = match limit {
+            Some(limit) => limit.max(1),
+            None => {
+                let items = self.message_repo.find_by_chat_id(chat_id).await?;
+                return Ok(Page { items, next_cursor: None });
+            }
+        };
+
+        // One extra row tells whether an older page exists
+        let mut items = self.message_repo.find_page_by_chat_id(chat_id, before, limit + 1).await?;
+        let has_more = items.len() as i64 > limit;
+        if has_more {
+            items.remove(0);
+        }
+        let next_cursor = if has_more {
+            items.first().map(|m| PageCursor::new(m.created_at, m.id))
+        } else {
+            None
+        };
+
+        Ok(Page { items, next_cursor })
     }
 }
@@ -2,4 +2,5 @@ pub mod create_chat;
 pub mod send_message;
 pub mod get_chats;
 pub mod get_messages;
-pub mod delete_chat;
\ No newline at end of file
+pub mod delete_chat;
+pub mod pick_reply;
\ No newline at end of file
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use uuid::Uuid;
+use anyhow::{Result, anyhow};
+use crate::domain::ai::chat::{
+    entity::{generation::MessageGeneration, message::Message},
+    repository::message_repository::MessageRepository,
+};
+
+pub struct PickReplyUseCase {
+    message_repo: Arc<dyn MessageRepository>,
+}
+
+impl PickReplyUseCase {
+    pub fn new(message_repo: Arc<dyn MessageRepository>) -> Self {
+        Self { message_repo }
+    }
+
+    /// Every reply to a user message (canonical and alternatives) with its generation stats.
+    pub async fn alternatives(&self, user_message_id: Uuid) -> Result<Vec<(Message, Option<MessageGeneration>)>> {
+        let mut replies = Vec::new();
+        for message in self.message_repo.find_alternatives(user_message_id).await? {
+            let generation = self.message_repo.find_generation(message.id).await?;
+            replies.push((message, generation));
+        }
+        Ok(replies)
+    }
+
+    /// Promotes a reply into the thread; its siblings become alternatives again.
+    pub async fn execute(&self, message_id: Uuid) -> Result<Message> {
+        let message = self.message_repo.find_by_id(message_id).await?
+            .ok_or_else(|| anyhow!("Message not found"))?;
+
+        let reply_to_id = message.reply_to_id
+            .ok_or_else(|| anyhow!("Message is not a reply to a user message"))?;
+
+        self.message_repo.set_canonical_reply(reply_to_id, message_id).await?;
+
+        Ok(Message { is_alternative: false, ..message })
+    }
+}
@@ -4,6 +4,12 @@ use serde::{Deserialize, Serialize};
 pub struct AppConfig {
     pub language: String,
     pub enable_smart_rag: bool,
+    /// Tools whose proposed actions run without asking (see `ProposedAction::approval_key`)
+    #[serde(default)]
+    pub auto_approve_tools: Vec<String>,
+    /// Microphone to record from; the system default when unset or unplugged
+    #[serde(default)]
+    pub input_device: Option<String>,
 }
 
 impl Default for AppConfig {
@@ -11,6 +17,8 @@ impl Default for AppConfig {
         Self {
             language: "en-US".to_string(),
             enable_smart_rag: false,
+            auto_approve_tools: Vec::new(),
+            input_device: None,
         }
     }
 }
@@ -7,4 +7,6 @@ pub trait ConfigRepository: Send + Sync {
     async fn get(&self) -> Result<AppConfig>;
     async fn set_language(&self, language: &str) -> Result<()>;
     async fn set_enable_smart_rag(&self, enabled: bool) -> Result<()>;
+    async fn set_tool_auto_approve(&self, tool: &str, enabled: bool) -> Result<()>;
+    async fn set_input_device(&self, device: Option<&str>) -> Result<()>;
 }
@@ -0,0 +1,62 @@
+/// A slice of a document with its 1-based, inclusive line range.
+#[derive(Debug, Clone)]
+pub struct TextChunk {
+    pub content: String,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+const MAX_CHUNK_CHARS: usize = 1500;
+const MAX_CHUNK_LINES: usize = 60;
+// Don't break on a heading if the chunk so far is tiny (e.g. a title line)
+const MIN_CHUNK_CHARS: usize = 200;
+
+/// Splits text into line-aligned chunks. Markdown headings start a new chunk so
+/// sections stay together; otherwise chunks are bounded by size.
+pub fn chunk_text(text: &str) -> Vec<TextChunk> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut start_line = 1;
+    let mut line_count = 0;
+
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let is_heading = line.trim_start().starts_with('#') && line.trim_start().trim_start_matches('#').starts_with(' ');
+
+        let too_big = current.len() + line.len() > MAX_CHUNK_CHARS || line_count >= MAX_CHUNK_LINES;
+        let new_section = is_heading && current.trim().len() >= MIN_CHUNK_CHARS;
+
+        if line_count > 0 && (too_big || new_section) {
+            push_chunk(&mut chunks, &current, start_line, line_no - 1);
+            current.clear();
+            line_count = 0;
+        }
+
+        if line_count == 0 {
+            start_line = line_no;
+        }
+
+        current.push_str(line);
+        current.push('\n');
+        line_count += 1;
+    }
+
+    if line_count > 0 {
+        push_chunk(&mut chunks, &current, start_line, start_line + line_count - 1);
+    }
+
+    chunks
+}
+
+fn push_chunk(chunks: &mut Vec<TextChunk>, content: &str, start_line: usize, end_line: usize) {
+    // Skip chunks that are only whitespace (blank runs between sections)
+    if content.trim().is_empty() {
+        return;
+    }
+
+    chunks.push(TextChunk {
+        content: content.trim_end().to_string(),
+        start_line,
+        end_line,
+    });
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Document {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub chat_id: Option<Uuid>, // None = global library
+    pub file_name: String,
+    pub file_path: String,
+    pub file_type: String,
+    pub content_hash: String,
+    pub chunk_count: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentChunk {
+    pub id: Uuid,
+    pub document_id: Uuid,
+    pub chunk_index: i64,
+    pub content: String,
+    pub start_line: i64,
+    pub end_line: i64,
+}
+
+/// A chunk returned by retrieval, with enough of its document to cite it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentChunkMatch {
+    pub chunk: DocumentChunk,
+    pub file_name: String,
+    pub file_path: String,
+}
+
+/// Where a piece of injected context came from, so answers can point back to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentCitation {
+    pub source: String, // "document" | "workspace"
+    pub source_id: String,
+    pub file_name: String,
+    pub file_path: String,
+    pub start_line: i64,
+    pub end_line: i64,
+}
+
+impl DocumentChunkMatch {
+    pub fn citation(&self) -> DocumentCitation {
+        DocumentCitation {
+            source: "document".to_string(),
+            source_id: self.chunk.document_id.to_string(),
+            file_name: self.file_name.clone(),
+            file_path: self.file_path.clone(),
+            start_line: self.chunk.start_line,
+            end_line: self.chunk.end_line,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use anyhow::Result;
+use super::entity::{Document, DocumentChunk, DocumentChunkMatch};
+
+#[async_trait]
+pub trait DocumentRepository: Send + Sync {
+    async fn save(&self, document: &Document, chunks: &[DocumentChunk]) -> Result<Document>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Document>>;
+    /// Documents visible from a chat: its own attachments plus the global library.
+    /// With `chat_id = None` only the global library is returned.
+    async fn find_by_user_id(&self, user_id: Uuid, chat_id: Option<Uuid>) -> Result<Vec<Document>>;
+    async fn find_by_hash(&self, user_id: Uuid, chat_id: Option<Uuid>, content_hash: &str) -> Result<Option<Document>>;
+    async fn find_chunks(&self, document_id: Uuid) -> Result<Vec<DocumentChunk>>;
+    async fn set_chat(&self, user_id: Uuid, id: Uuid, chat_id: Option<Uuid>) -> Result<bool>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool>;
+    async fn search_chunks(&self, user_id: Uuid, chat_id: Uuid, query: &str, limit: i64) -> Result<Vec<DocumentChunkMatch>>;
+}
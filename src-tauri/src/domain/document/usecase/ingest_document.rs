@@ -0,0 +1,69 @@
+use std::path::Path;
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use crate::domain::document::{
+    chunker::chunk_text,
+    entity::{Document, DocumentChunk},
+    repository::DocumentRepository,
+};
+use crate::infrastructure::document::extractor::extract_text;
+
+pub struct IngestDocumentUseCase {
+    document_repo: Arc<dyn DocumentRepository>,
+}
+
+impl IngestDocumentUseCase {
+    pub fn new(document_repo: Arc<dyn DocumentRepository>) -> Self {
+        Self { document_repo }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, chat_id: Option<Uuid>, file_path: String) -> Result<Document> {
+        let path = Path::new(&file_path).to_path_buf();
+        let file_name = path.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .ok_or_else(|| anyhow!("Invalid file path: {}", file_path))?;
+
+        // PDF parsing can be slow, keep it off the async runtime
+        let extracted = tokio::task::spawn_blocking(move || extract_text(&path)).await??;
+
+        let content_hash = format!("{:x}", Sha256::digest(extracted.text.as_bytes()));
+
+        // Re-ingesting the same content into the same scope is a no-op
+        if let Some(existing) = self.document_repo.find_by_hash(user_id, chat_id, &content_hash).await? {
+            log::info!("Document {} already ingested as {}", file_name, existing.id);
+            return Ok(existing);
+        }
+
+        let document_id = Uuid::new_v4();
+        let chunks: Vec<DocumentChunk> = chunk_text(&extracted.text)
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| DocumentChunk {
+                id: Uuid::new_v4(),
+                document_id,
+                chunk_index: i as i64,
+                content: c.content,
+                start_line: c.start_line as i64,
+                end_line: c.end_line as i64,
+            })
+            .collect();
+
+        let document = Document {
+            id: document_id,
+            user_id,
+            chat_id,
+            file_name,
+            file_path,
+            file_type: extracted.file_type,
+            content_hash,
+            chunk_count: chunks.len() as i64,
+            created_at: Utc::now(),
+        };
+
+        log::info!("Ingesting document {} ({} chunks)", document.file_name, chunks.len());
+        self.document_repo.save(&document, &chunks).await
+    }
+}
@@ -0,0 +1 @@
+pub mod ingest_document;
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageFeedback {
+    pub id: Uuid,
+    pub message_id: Uuid,
+    pub chat_id: Uuid,
+    pub user_id: Uuid,
+    pub rating: i32, // 1 = positive, -1 = negative
+    pub reason: Option<String>,
+    pub comment: Option<String>,
+    pub prompt_preset_id: Option<String>,
+    pub model: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Feedback totals for one preset/model combination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedbackStats {
+    pub prompt_preset_id: Option<String>,
+    pub preset_name: Option<String>,
+    pub model: Option<String>,
+    pub positive: i64,
+    pub negative: i64,
+    pub total: i64,
+    /// Share of positive ratings, 0.0 - 1.0
+    pub approval_rate: f64,
+}
+
+/// `message_type` given to assistant messages marked as wrong. The background
+/// analyzer skips them so they never become a memory.
+pub const REJECTED_MESSAGE_TYPE: &str = "rejected";
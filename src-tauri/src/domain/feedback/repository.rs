@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use anyhow::Result;
+use super::entity::{FeedbackStats, MessageFeedback};
+
+#[async_trait]
+pub trait FeedbackRepository: Send + Sync {
+    /// Inserts feedback, replacing any previous rating for the same message.
+    async fn upsert(&self, feedback: &MessageFeedback) -> Result<MessageFeedback>;
+    async fn find_by_message_id(&self, message_id: Uuid) -> Result<Option<MessageFeedback>>;
+    async fn find_by_chat_id(&self, user_id: Uuid, chat_id: Uuid) -> Result<Vec<MessageFeedback>>;
+    async fn delete_by_message_id(&self, user_id: Uuid, message_id: Uuid) -> Result<bool>;
+    async fn get_stats(&self, user_id: Uuid) -> Result<Vec<FeedbackStats>>;
+}
@@ -0,0 +1 @@
+pub mod submit_feedback;
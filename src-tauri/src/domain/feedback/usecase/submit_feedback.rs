@@ -0,0 +1,117 @@
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use chrono::Utc;
+use crate::domain::ai::chat::{
+    entity::message::Message,
+    repository::{chat_repository::ChatRepository, message_repository::MessageRepository},
+};
+use crate::domain::feedback::{
+    entity::{MessageFeedback, REJECTED_MESSAGE_TYPE},
+    repository::FeedbackRepository,
+};
+
+// Added to a message's importance on a thumbs up (importance is 0-100)
+const POSITIVE_BOOST: i32 = 20;
+
+pub struct SubmitFeedbackUseCase {
+    feedback_repo: Arc<dyn FeedbackRepository>,
+    message_repo: Arc<dyn MessageRepository>,
+    chat_repo: Arc<dyn ChatRepository>,
+}
+
+impl SubmitFeedbackUseCase {
+    pub fn new(
+        feedback_repo: Arc<dyn FeedbackRepository>,
+        message_repo: Arc<dyn MessageRepository>,
+        chat_repo: Arc<dyn ChatRepository>,
+    ) -> Self {
+        Self { feedback_repo, message_repo, chat_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        message_id: Uuid,
+        rating: i32,
+        reason: Option<String>,
+        comment: Option<String>,
+        model: Option<String>,
+    ) -> Result<MessageFeedback> {
+        if rating != 1 && rating != -1 {
+            return Err(anyhow!("Rating must be 1 (positive) or -1 (negative)"));
+        }
+
+        let message = self.message_repo.find_by_id(message_id).await?
+            .ok_or_else(|| anyhow!("Message not found"))?;
+        if message.role != "assistant" {
+            return Err(anyhow!("Feedback can only be given on assistant messages"));
+        }
+
+        let chat = self.chat_repo.find_by_id(message.chat_id).await?
+            .filter(|chat| chat.user_id == user_id)
+            .ok_or_else(|| anyhow!("Chat not found"))?;
+        if chat.is_incognito {
+            return Err(anyhow!("Feedback is not recorded for incognito chats"));
+        }
+
+        let previous = self.feedback_repo.find_by_message_id(message_id).await?;
+
+        let feedback = self.feedback_repo.upsert(&MessageFeedback {
+            id: Uuid::new_v4(),
+            message_id,
+            chat_id: chat.id,
+            user_id,
+            rating,
+            reason,
+            comment,
+            prompt_preset_id: chat.prompt_preset_id,
+            // The frontend knows which model actually answered; the chat default is a fallback
+            model: model.filter(|m| !m.is_empty()).or(chat.model),
+            created_at: Utc::now(),
+        }).await?;
+
+        // Re-submitting the same rating (e.g. to add a comment) must not boost twice
+        if previous.map(|p| p.rating) != Some(rating) {
+            self.apply_to_memory(message, rating).await?;
+        }
+
+        Ok(feedback)
+    }
+
+    /// Removes feedback. A rejected message goes back to being a plain chat
+    /// message, but keeps its lowered importance until it is analyzed again.
+    pub async fn clear(&self, user_id: Uuid, message_id: Uuid) -> Result<()> {
+        if !self.feedback_repo.delete_by_message_id(user_id, message_id).await? {
+            return Err(anyhow!("Feedback not found"));
+        }
+
+        if let Some(mut message) = self.message_repo.find_by_id(message_id).await? {
+            if message.message_type == REJECTED_MESSAGE_TYPE {
+                message.message_type = "chat".to_string();
+                self.message_repo.update(message).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn apply_to_memory(&self, mut message: Message, rating: i32) -> Result<()> {
+        if rating < 0 {
+            // Summaries with importance > 0 feed the cross-chat memory; drop both
+            message.importance = 0;
+            message.summary = None;
+            message.message_type = REJECTED_MESSAGE_TYPE.to_string();
+        } else {
+            if message.message_type == REJECTED_MESSAGE_TYPE {
+                message.message_type = "chat".to_string();
+            }
+            message.importance = (message.importance + POSITIVE_BOOST).min(100);
+        }
+
+        log::info!("Feedback {} applied to message {}: importance {}", rating, message.id, message.importance);
+        self.message_repo.update(message).await?;
+
+        Ok(())
+    }
+}
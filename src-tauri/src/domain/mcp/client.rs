@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use anyhow::Result;
+use std::collections::HashMap;
+use super::entity::{McpPrompt, McpResource, McpTool, McpToolResult};
+
+/// An initialized session with one MCP server.
+#[async_trait]
+pub trait McpClient: Send + Sync {
+    fn server_version(&self) -> Option<String>;
+    /// Whether the server announced new tools since the last call, so a cached
+    /// tool list should be fetched again.
+    fn take_tools_changed(&self) -> bool;
+    async fn list_tools(&self) -> Result<Vec<McpTool>>;
+    async fn list_resources(&self) -> Result<Vec<McpResource>>;
+    async fn list_prompts(&self) -> Result<Vec<McpPrompt>>;
+    async fn call_tool(&self, name: &str, arguments: serde_json::Value) -> Result<McpToolResult>;
+    /// Text contents of a resource; binary contents are summarized
+    async fn read_resource(&self, uri: &str) -> Result<String>;
+    /// Prompt messages rendered as `role: text` lines
+    async fn get_prompt(&self, name: &str, arguments: HashMap<String, String>) -> Result<String>;
+    async fn close(&self);
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum McpTransport {
+    /// Launches `command` and speaks JSON-RPC over its stdin/stdout
+    Stdio,
+    /// Connects to a local Streamable HTTP endpoint (JSON or SSE responses)
+    Http,
+}
+
+impl McpTransport {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            McpTransport::Stdio => "stdio",
+            McpTransport::Http => "http",
+        }
+    }
+}
+
+impl std::str::FromStr for McpTransport {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "stdio" => Ok(McpTransport::Stdio),
+            "http" | "sse" => Ok(McpTransport::Http),
+            _ => Err(anyhow::anyhow!("Unknown MCP transport: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpServer {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub transport: McpTransport,
+    pub command: Option<String>,
+    pub args: Vec<String>,
+    pub env: HashMap<String, String>,
+    pub url: Option<String>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpTool {
+    pub server_id: Uuid,
+    pub server_name: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// JSON Schema of the tool arguments, as advertised by the server
+    pub input_schema: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResource {
+    pub server_id: Uuid,
+    pub uri: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpPromptArgument {
+    pub name: String,
+    pub description: Option<String>,
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpPrompt {
+    pub server_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub arguments: Vec<McpPromptArgument>,
+}
+
+/// Everything a connected server offers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpCapabilities {
+    pub server_id: Uuid,
+    pub server_name: String,
+    pub server_version: Option<String>,
+    pub tools: Vec<McpTool>,
+    pub resources: Vec<McpResource>,
+    pub prompts: Vec<McpPrompt>,
+}
+
+/// A resource the user attached to a message; its contents are added to the context.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpResourceRef {
+    pub server_id: Uuid,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpToolResult {
+    /// Text content of the result; non-text parts are summarized
+    pub content: String,
+    pub is_error: bool,
+}
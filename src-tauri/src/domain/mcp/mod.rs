@@ -0,0 +1,4 @@
+pub mod entity;
+pub mod repository;
+pub mod client;
+pub mod usecase;
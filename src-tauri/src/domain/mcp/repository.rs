@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use anyhow::Result;
+use super::entity::McpServer;
+
+#[async_trait]
+pub trait McpServerRepository: Send + Sync {
+    async fn create(&self, server: &McpServer) -> Result<McpServer>;
+    async fn update(&self, server: &McpServer) -> Result<McpServer>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<McpServer>>;
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<McpServer>>;
+    async fn delete(&self, id: Uuid) -> Result<()>;
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use uuid::Uuid;
+use crate::domain::mcp::{
+    entity::{McpServer, McpTransport},
+    repository::McpServerRepository,
+};
+
+pub struct McpServerInput {
+    pub name: String,
+    pub transport: McpTransport,
+    pub command: Option<String>,
+    pub args: Vec<String>,
+    pub env: HashMap<String, String>,
+    pub url: Option<String>,
+    pub enabled: bool,
+}
+
+pub struct ManageMcpServerUseCase {
+    server_repo: Arc<dyn McpServerRepository>,
+}
+
+impl ManageMcpServerUseCase {
+    pub fn new(server_repo: Arc<dyn McpServerRepository>) -> Self {
+        Self { server_repo }
+    }
+
+    pub async fn add(&self, user_id: Uuid, input: McpServerInput) -> Result<McpServer> {
+        let input = Self::validate(input)?;
+
+        let server = McpServer {
+            id: Uuid::new_v4(),
+            user_id,
+            name: input.name,
+            transport: input.transport,
+            command: input.command,
+            args: input.args,
+            env: input.env,
+            url: input.url,
+            enabled: input.enabled,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        self.server_repo.create(&server).await
+    }
+
+    pub async fn update(&self, server_id: Uuid, input: McpServerInput) -> Result<McpServer> {
+        let mut server = self.server_repo.find_by_id(server_id).await?
+            .ok_or_else(|| anyhow!("MCP server not found"))?;
+        let input = Self::validate(input)?;
+
+        server.name = input.name;
+        server.transport = input.transport;
+        server.command = input.command;
+        server.args = input.args;
+        server.env = input.env;
+        server.url = input.url;
+        server.enabled = input.enabled;
+        server.updated_at = Utc::now();
+
+        self.server_repo.update(&server).await
+    }
+
+    /// Stdio servers need a command; HTTP servers must listen on this machine,
+    /// since tool calls can carry chat content.
+    fn validate(mut input: McpServerInput) -> Result<McpServerInput> {
+        input.name = input.name.trim().to_string();
+        if input.name.is_empty() {
+            return Err(anyhow!("MCP server name is required"));
+        }
+
+        match input.transport {
+            McpTransport::Stdio => {
+                let command = input.command.as_deref().map(str::trim).unwrap_or_default();
+                if command.is_empty() {
+                    return Err(anyhow!("A command is required for stdio MCP servers"));
+                }
+                input.command = Some(command.to_string());
+                input.url = None;
+            }
+            McpTransport::Http => {
+                let url = input.url.as_deref().map(str::trim).unwrap_or_default();
+                let parsed = reqwest::Url::parse(url)
+                    .map_err(|e| anyhow!("Invalid MCP server URL: {}", e))?;
+                if !matches!(parsed.scheme(), "http" | "https") {
+                    return Err(anyhow!("MCP server URL must use http or https"));
+                }
+                let host = parsed.host_str().unwrap_or_default()
+                    .trim_start_matches('[')
+                    .trim_end_matches(']');
+                let is_local = host.eq_ignore_ascii_case("localhost")
+                    || host.parse::<IpAddr>().map(|ip| ip.is_loopback()).unwrap_or(false);
+                if !is_local {
+                    return Err(anyhow!("Only local MCP servers (localhost, 127.0.0.1, ::1) are supported"));
+                }
+                input.url = Some(url.to_string());
+                input.command = None;
+                input.args.clear();
+                input.env.clear();
+            }
+        }
+
+        Ok(input)
+    }
+}
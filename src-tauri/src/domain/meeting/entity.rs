@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::domain::ai::{
+    audio::transcript::{Transcript, TranscriptSegment},
+    chat::entity::message::Message,
+};
+
+/// One recording session: its audio, kept in the app's data directory, and
+/// what was said in it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Meeting {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    /// WAV file of the recording; stereo (me left, them right) for mixed recordings
+    pub audio_path: String,
+    pub duration_ms: i64,
+    /// Language of the transcript, once there is one
+    pub language: Option<String>,
+    pub segments: Vec<TranscriptSegment>,
+    pub calendar_event_id: Option<Uuid>,
+    /// Chat opened from the meeting, with its summary
+    pub chat_id: Option<Uuid>,
+    pub started_at: DateTime<Utc>,
+    /// `None` while still recording
+    pub ended_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Meeting {
+    pub fn transcript(&self) -> Transcript {
+        Transcript {
+            language: self.language.clone().unwrap_or_default(),
+            segments: self.segments.clone(),
+        }
+    }
+}
+
+/// A meeting without its segments, for lists.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingSummary {
+    pub id: Uuid,
+    pub title: String,
+    pub duration_ms: i64,
+    pub segment_count: i64,
+    pub calendar_event_id: Option<Uuid>,
+    pub chat_id: Option<Uuid>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+/// The chat opened from a meeting, with the AI's summary as its first answer.
+#[derive(Debug, Clone, Serialize)]
+pub struct MeetingChatResult {
+    pub meeting_id: Uuid,
+    pub chat_id: Uuid,
+    pub message: Message,
+    pub follow_ups: Vec<String>,
+}
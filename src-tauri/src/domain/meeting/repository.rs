@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use anyhow::Result;
+use crate::domain::ai::audio::transcript::Transcript;
+use super::entity::{Meeting, MeetingSummary};
+
+#[async_trait]
+pub trait MeetingRepository: Send + Sync {
+    async fn create(&self, meeting: &Meeting) -> Result<Meeting>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Meeting>>;
+    /// Meetings of the user, most recent first.
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<MeetingSummary>>;
+    /// Meetings started before `started_before` whose recording never finished.
+    async fn find_unfinished(&self, started_before: DateTime<Utc>) -> Result<Vec<Meeting>>;
+    async fn update_details(&self, id: Uuid, title: &str, calendar_event_id: Option<Uuid>) -> Result<()>;
+    /// Records that the recording stopped. Kept apart from the transcript,
+    /// which is saved by whoever finishes transcribing.
+    async fn finish_recording(&self, id: Uuid, duration_ms: i64, ended_at: DateTime<Utc>) -> Result<()>;
+    async fn save_transcript(&self, id: Uuid, transcript: &Transcript) -> Result<()>;
+    async fn set_chat(&self, id: Uuid, chat_id: Uuid) -> Result<()>;
+    async fn delete(&self, id: Uuid) -> Result<()>;
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Duration, Local, Utc};
+use log::warn;
+use uuid::Uuid;
+use crate::domain::{
+    ai::audio::audio_input::AudioBuffer,
+    calendar::repository::CalendarRepository,
+    meeting::{
+        entity::Meeting,
+        repository::MeetingRepository,
+    },
+};
+
+pub struct ManageMeetingUseCase {
+    meeting_repo: Arc<dyn MeetingRepository>,
+    calendar_repo: Arc<dyn CalendarRepository>,
+}
+
+impl ManageMeetingUseCase {
+    pub fn new(meeting_repo: Arc<dyn MeetingRepository>, calendar_repo: Arc<dyn CalendarRepository>) -> Self {
+        Self { meeting_repo, calendar_repo }
+    }
+
+    /// Records that a recording into `audio_path` started. Without a title the
+    /// meeting is named after its calendar event, or after when it started.
+    pub async fn start(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+        audio_path: String,
+        title: Option<String>,
+        calendar_event_id: Option<Uuid>,
+    ) -> Result<Meeting> {
+        let title = match Self::clean_title(title) {
+            Some(title) => title,
+            None => self.event_title(calendar_event_id).await
+                .unwrap_or_else(|| format!("Meeting {}", Local::now().format("%Y-%m-%d %H:%M"))),
+        };
+        let now = Utc::now();
+
+        let meeting = Meeting {
+            id,
+            user_id,
+            title,
+            audio_path,
+            duration_ms: 0,
+            language: None,
+            segments: Vec::new(),
+            calendar_event_id,
+            chat_id: None,
+            started_at: now,
+            ended_at: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        self.meeting_repo.create(&meeting).await
+    }
+
+    pub async fn update(&self, meeting_id: Uuid, title: Option<String>, calendar_event_id: Option<Uuid>) -> Result<Meeting> {
+        let mut meeting = self.meeting_repo.find_by_id(meeting_id).await?
+            .ok_or_else(|| anyhow!("Meeting not found"))?;
+
+        if let Some(title) = Self::clean_title(title) {
+            meeting.title = title;
+        } else if calendar_event_id.is_some() && calendar_event_id != meeting.calendar_event_id {
+            if let Some(title) = self.event_title(calendar_event_id).await {
+                meeting.title = title;
+            }
+        }
+        meeting.calendar_event_id = calendar_event_id;
+
+        self.meeting_repo.update_details(meeting.id, &meeting.title, meeting.calendar_event_id).await?;
+        Ok(meeting)
+    }
+
+    /// Deletes the meeting and its recording once it's no longer being recorded.
+    /// The chat made from it is kept.
+    pub async fn delete(&self, meeting_id: Uuid) -> Result<()> {
+        let meeting = self.meeting_repo.find_by_id(meeting_id).await?
+            .ok_or_else(|| anyhow!("Meeting not found"))?;
+        // The recorder still has the file open and would finish it afterwards
+        if meeting.ended_at.is_none() {
+            return Err(anyhow!("The meeting is still being recorded"));
+        }
+
+        if let Err(e) = std::fs::remove_file(&meeting.audio_path) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(anyhow!("Failed to delete the recording: {}", e));
+            }
+        }
+
+        self.meeting_repo.delete(meeting.id).await
+    }
+
+    /// Finishes the meetings whose recording was cut off by a crash or a forced
+    /// quit, taking their length from what made it into the WAV file. Run it at
+    /// startup with the time the app started, before anything is recorded.
+    pub async fn finish_interrupted(&self, started_before: DateTime<Utc>) -> Result<usize> {
+        let meetings = self.meeting_repo.find_unfinished(started_before).await?;
+        for meeting in &meetings {
+            let path = std::path::Path::new(&meeting.audio_path);
+            if let Err(e) = AudioBuffer::repair_wav_header(path) {
+                warn!("[Meeting] Failed to repair the recording of interrupted meeting {}: {}", meeting.id, e);
+            }
+            let duration_ms = AudioBuffer::wav_duration_ms(path)
+                .unwrap_or_else(|e| {
+                    warn!("[Meeting] Recording of interrupted meeting {} is unreadable: {}", meeting.id, e);
+                    0
+                });
+            let ended_at = meeting.started_at
+                .checked_add_signed(Duration::milliseconds(duration_ms as i64))
+                .unwrap_or(meeting.started_at);
+            self.meeting_repo.finish_recording(meeting.id, duration_ms as i64, ended_at).await?;
+        }
+        Ok(meetings.len())
+    }
+
+    fn clean_title(title: Option<String>) -> Option<String> {
+        title.map(|title| title.trim().to_string()).filter(|title| !title.is_empty())
+    }
+
+    async fn event_title(&self, calendar_event_id: Option<Uuid>) -> Option<String> {
+        let event_id = calendar_event_id?;
+        match self.calendar_repo.find_by_id(event_id).await {
+            Ok(event) => event.map(|event| event.title),
+            Err(e) => {
+                warn!("[Meeting] Failed to look up calendar event {}: {}", event_id, e);
+                None
+            }
+        }
+    }
+}
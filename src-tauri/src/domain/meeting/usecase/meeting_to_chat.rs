@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::Local;
+use uuid::Uuid;
+use crate::domain::{
+    ai::chat::{
+        repository::chat_repository::ChatRepository,
+        service::chat_service::{AIProviderType, ChatService, ChatServiceRequest},
+        usecase::create_chat::CreateChatUseCase,
+    },
+    meeting::{
+        entity::MeetingChatResult,
+        repository::MeetingRepository,
+    },
+    prompt_preset::repository::PromptPresetRepository,
+    user::repository::user_api_key_repository::UserApiKeyRepository,
+};
+
+/// Longest transcript sent to the model; longer ones are cut at this many characters.
+const MAX_TRANSCRIPT_CHARS: usize = 100_000;
+
+pub struct MeetingToChatUseCase {
+    meeting_repo: Arc<dyn MeetingRepository>,
+    chat_service: Arc<dyn ChatService>,
+    chat_repo: Arc<dyn ChatRepository>,
+    prompt_preset_repo: Arc<dyn PromptPresetRepository>,
+    user_api_key_repo: Arc<dyn UserApiKeyRepository>,
+}
+
+impl MeetingToChatUseCase {
+    pub fn new(
+        meeting_repo: Arc<dyn MeetingRepository>,
+        chat_service: Arc<dyn ChatService>,
+        chat_repo: Arc<dyn ChatRepository>,
+        prompt_preset_repo: Arc<dyn PromptPresetRepository>,
+        user_api_key_repo: Arc<dyn UserApiKeyRepository>,
+    ) -> Self {
+        Self { meeting_repo, chat_service, chat_repo, prompt_preset_repo, user_api_key_repo }
+    }
+
+    /// Opens a chat named after the meeting with the summary preset as its
+    /// system prompt, and sends the transcript so the first answer is the summary.
+    /// Without a provider, the first one the user has a key for is used.
+    pub async fn execute(
+        &self,
+        meeting_id: Uuid,
+        summary_preset_id: &str,
+        provider_name: Option<String>,
+        model: Option<String>,
+    ) -> Result<MeetingChatResult> {
+        let meeting = self.meeting_repo.find_by_id(meeting_id).await?
+            .ok_or_else(|| anyhow!("Meeting not found"))?;
+        let transcript = meeting.transcript().text();
+        if transcript.trim().is_empty() {
+            return Err(anyhow!("The meeting has no transcript yet"));
+        }
+        let transcript: String = transcript.chars().take(MAX_TRANSCRIPT_CHARS).collect();
+
+        let preset = self.prompt_preset_repo.find_by_id(summary_preset_id).await?
+            .ok_or_else(|| anyhow!("Summary preset not found"))?;
+        if preset.preset_type.as_deref() != Some("summary") {
+            return Err(anyhow!("Preset '{}' is not a summary preset", preset.name));
+        }
+
+        let (provider_name, model) = match (provider_name, model) {
+            (Some(provider), Some(model)) => (provider, model),
+            (provider, model) => {
+                let keys = self.user_api_key_repo.find_by_user_id(meeting.user_id).await?;
+                let key = keys.into_iter()
+                    .filter(|k| k.provider.parse::<AIProviderType>().is_ok())
+                    .find(|k| provider.as_ref().map_or(true, |provider| &k.provider == provider))
+                    .ok_or_else(|| anyhow!("No API key configured. Add one in settings to summarize meetings."))?;
+                let model = match model.or(key.selected_model).filter(|m| !m.trim().is_empty()) {
+                    Some(model) => model,
+                    None => key.provider.parse::<AIProviderType>()?.default_model().to_string(),
+                };
+                (key.provider, model)
+            }
+        };
+
+        let chat = CreateChatUseCase::new(self.chat_repo.clone())
+            .execute(meeting.user_id, Some(meeting.title.clone()), Some(preset.id.clone()), Some(model.clone()), false)
+            .await?;
+
+        let started_at = meeting.started_at.with_timezone(&Local);
+        let template_values = HashMap::from([
+            ("meeting_title".to_string(), meeting.title.clone()),
+            ("meeting_date".to_string(), started_at.format("%Y-%m-%d %H:%M").to_string()),
+        ]);
+
+        let request = ChatServiceRequest {
+            user_id: meeting.user_id,
+            chat_id: chat.id,
+            provider_name,
+            prompt: format!(
+                "Meeting: {} ({})\n\nTranscript:\n\n{}",
+                meeting.title,
+                started_at.format("%Y-%m-%d %H:%M"),
+                transcript,
+            ),
+            model,
+            temperature: None,
+            max_tokens: None,
+            image: None,
+            output_language: None,
+            workspace_id: None,
+            template_values: Some(template_values),
+            mcp_resources: Vec::new(),
+        };
+
+        let (message, follow_ups) = self.chat_service.send_message_to_ai(request).await?;
+        self.meeting_repo.set_chat(meeting.id, chat.id).await?;
+
+        Ok(MeetingChatResult {
+            meeting_id: meeting.id,
+            chat_id: chat.id,
+            message,
+            follow_ups,
+        })
+    }
+}
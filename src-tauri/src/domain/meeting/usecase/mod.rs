@@ -0,0 +1,2 @@
+pub mod manage_meeting;
+pub mod meeting_to_chat;
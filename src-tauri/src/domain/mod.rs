@@ -7,3 +7,13 @@ pub mod maintenance;
 pub mod changelog;
 pub mod calendar;
 pub mod notion;
+pub mod document;
+pub mod workspace;
+pub mod feedback;
+pub mod mcp;
+pub mod action;
+pub mod quick_action;
+pub mod retention;
+pub mod schedule;
+pub mod task;
+pub mod meeting;
@@ -10,7 +10,8 @@ use crate::domain::ai::chat::{
 };
 use crate::domain::user::repository::user_repository::UserRepository;
 use crate::domain::user::repository::user_api_key_repository::UserApiKeyRepository;
-use crate::domain::prompt_preset::repository::PromptPresetRepository;
+use crate::domain::prompt_preset::{repository::PromptPresetRepository, template};
+use crate::domain::config::repository::ConfigRepository;
 
 pub struct SendChatSummaryEmailUseCase {
     email_service: Arc<EmailService>,
@@ -19,6 +20,7 @@ pub struct SendChatSummaryEmailUseCase {
     user_repo: Arc<dyn UserRepository>,
     prompt_preset_repo: Arc<dyn PromptPresetRepository>,
     user_api_key_repo: Arc<dyn UserApiKeyRepository>,
+    config_repo: Arc<dyn ConfigRepository>,
 }
 
 impl SendChatSummaryEmailUseCase {
@@ -29,6 +31,7 @@ impl SendChatSummaryEmailUseCase {
         user_repo: Arc<dyn UserRepository>,
         prompt_preset_repo: Arc<dyn PromptPresetRepository>,
         user_api_key_repo: Arc<dyn UserApiKeyRepository>,
+        config_repo: Arc<dyn ConfigRepository>,
     ) -> Self {
         Self {
             email_service,
@@ -37,6 +40,7 @@ impl SendChatSummaryEmailUseCase {
             user_repo,
             prompt_preset_repo,
             user_api_key_repo,
+            config_repo,
         }
     }
 
@@ -48,6 +52,9 @@ impl SendChatSummaryEmailUseCase {
         if chat.user_id != user_id {
             return Err(anyhow!("Chat does not belong to user"));
         }
+        if chat.is_incognito {
+            return Err(anyhow!("Incognito chats can't be exported"));
+        }
 
         // 2. Get user email
         let user = self.user_repo.find_by_id(user_id).await?
@@ -109,9 +116,13 @@ impl SendChatSummaryEmailUseCase {
             .collect::<Vec<_>>()
             .join("\n\n");
 
+        let app_config = self.config_repo.get().await.unwrap_or_default();
+        let values = template::built_in_values(&app_config.language, chat.title.as_deref());
+        let summary_prompt = template::render(&preset.prompt, &preset.parameters, &values);
+
         let full_prompt = format!(
             "{}\n\n---\n\nChat to summarize:\n\n{}",
-            preset.prompt,
+            summary_prompt,
             chat_content
         );
 
@@ -414,7 +425,7 @@ impl SendChatSummaryEmailUseCase {
     }
 }
 
-fn html_escape(text: &str) -> String {
+pub(crate) fn html_escape(text: &str) -> String {
     text.replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+use uuid::Uuid;
+use anyhow::{Result, anyhow};
+use crate::infrastructure::notion::client::NotionClient;
+use crate::domain::notion::repository::NotionRepository;
+
+pub struct ArchivePageUseCase {
+    notion_repo: Arc<dyn NotionRepository>,
+    notion_client: Arc<NotionClient>,
+}
+
+impl ArchivePageUseCase {
+    pub fn new(
+        notion_repo: Arc<dyn NotionRepository>,
+        notion_client: Arc<NotionClient>,
+    ) -> Self {
+        Self {
+            notion_repo,
+            notion_client,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, page_id: &str) -> Result<()> {
+        let integration = self.notion_repo.find_by_user_id(user_id).await?
+            .ok_or_else(|| anyhow!("Notion not connected"))?;
+
+        self.notion_client.archive_page(&integration.access_token, page_id).await
+    }
+}
@@ -1 +1,2 @@
 pub mod create_page;
+pub mod archive_page;
@@ -11,10 +11,104 @@ pub struct PromptPreset {
     #[serde(default = "default_preset_type")]
     #[sqlx(default)]
     pub preset_type: Option<String>,
+    /// User-defined template parameters, see `template.rs`
+    #[serde(default)]
+    #[sqlx(json)]
+    pub parameters: Vec<PresetParameter>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// A `{{name}}` placeholder declared by the preset author, with the value used
+/// when the caller doesn't provide one.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PresetParameter {
+    pub name: String,
+    #[serde(default)]
+    pub default_value: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
 fn default_preset_type() -> Option<String> {
     Some("assistant".to_string())
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PromptPresetVersion {
+    pub id: String,
+    pub preset_id: String,
+    pub version: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub prompt: String,
+    #[sqlx(json)]
+    pub parameters: Vec<PresetParameter>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Shareable file format for presets (JSON or YAML).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresetBundle {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub presets: Vec<BundledPreset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledPreset {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub prompt: String,
+    #[serde(default = "default_preset_type")]
+    pub preset_type: Option<String>,
+    #[serde(default)]
+    pub parameters: Vec<PresetParameter>,
+}
+
+/// What to do when an imported preset id already exists locally.
+/// Built-in presets are never overwritten, whatever the strategy.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportConflictStrategy {
+    Skip,
+    Overwrite,
+    /// Import under a new id, keeping the existing preset
+    Duplicate,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub imported: Vec<String>,
+    pub overwritten: Vec<String>,
+    pub duplicated: Vec<String>,
+    pub skipped: Vec<String>,
+    /// Presets rejected as invalid, with the reason
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffLine {
+    pub tag: String, // "equal" | "insert" | "delete"
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresetDiff {
+    pub preset_id: String,
+    pub from_version: i64,
+    pub to_version: i64,
+    pub name_changed: bool,
+    pub description_changed: bool,
+    pub parameters_changed: bool,
+    pub prompt: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleFormat {
+    Json,
+    Yaml,
+}
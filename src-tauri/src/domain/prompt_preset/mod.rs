@@ -1,2 +1,4 @@
 pub mod entity;
 pub mod repository;
+pub mod template;
+pub mod usecase;
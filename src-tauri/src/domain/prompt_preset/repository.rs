@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use super::entity::PromptPreset;
+use super::entity::{PromptPreset, PromptPresetVersion};
 use anyhow::Result;
 
 #[async_trait]
@@ -11,5 +11,9 @@ pub trait PromptPresetRepository: Send + Sync {
     async fn update(&self, preset: &PromptPreset) -> Result<PromptPreset>;
     async fn delete(&self, id: &str) -> Result<()>;
     async fn delete_all_custom(&self) -> Result<()>;
-}
 
+    /// Stores the preset's current content as its next version.
+    async fn save_version(&self, preset: &PromptPreset) -> Result<PromptPresetVersion>;
+    async fn find_versions(&self, preset_id: &str) -> Result<Vec<PromptPresetVersion>>;
+    async fn find_version(&self, preset_id: &str, version: i64) -> Result<Option<PromptPresetVersion>>;
+}
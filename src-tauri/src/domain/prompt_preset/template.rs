@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet};
+use anyhow::{Result, anyhow};
+use chrono::Local;
+use super::entity::PresetParameter;
+
+/// Variables every preset can use without declaring them. `clipboard` is
+/// supplied by the caller and renders empty when unavailable.
+pub const BUILT_IN_VARIABLES: &[&str] = &["date", "language", "clipboard", "chat_title"];
+
+enum Segment<'a> {
+    Text(&'a str),
+    Variable(&'a str),
+}
+
+/// Splits a template into literal text and `{{ name }}` placeholders.
+fn parse(template: &str) -> Result<Vec<Segment<'_>>> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+    let mut offset = 0;
+
+    while let Some(open) = rest.find("{{") {
+        if open > 0 {
+            segments.push(Segment::Text(&rest[..open]));
+        }
+
+        let after_open = &rest[open + 2..];
+        let close = after_open.find("}}")
+            .ok_or_else(|| anyhow!("Unclosed '{{{{' at position {}", offset + open))?;
+        let inner = &after_open[..close];
+        if inner.contains("{{") {
+            return Err(anyhow!("Nested '{{{{' at position {}", offset + open));
+        }
+
+        let name = inner.trim();
+        if name.is_empty() {
+            return Err(anyhow!("Empty variable at position {}", offset + open));
+        }
+        if !is_valid_name(name) {
+            return Err(anyhow!("Invalid variable name '{}': use letters, digits and underscores", name));
+        }
+        segments.push(Segment::Variable(name));
+
+        let consumed = open + 2 + close + 2;
+        offset += consumed;
+        rest = &rest[consumed..];
+    }
+
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest));
+    }
+
+    Ok(segments)
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Rejects templates that would not render: bad syntax, badly declared
+/// parameters, or variables that are neither built-in nor declared.
+pub fn validate(template: &str, parameters: &[PresetParameter]) -> Result<()> {
+    let mut declared = HashSet::new();
+    for parameter in parameters {
+        if !is_valid_name(&parameter.name) {
+            return Err(anyhow!("Invalid parameter name '{}': use letters, digits and underscores", parameter.name));
+        }
+        if BUILT_IN_VARIABLES.contains(&parameter.name.as_str()) {
+            return Err(anyhow!("Parameter '{}' clashes with a built-in variable", parameter.name));
+        }
+        if !declared.insert(parameter.name.as_str()) {
+            return Err(anyhow!("Parameter '{}' is declared twice", parameter.name));
+        }
+    }
+
+    for segment in parse(template)? {
+        if let Segment::Variable(name) = segment {
+            if !BUILT_IN_VARIABLES.contains(&name) && !declared.contains(name) {
+                return Err(anyhow!(
+                    "Unknown variable '{{{{{}}}}}'. Declare it as a parameter or use one of: {}",
+                    name,
+                    BUILT_IN_VARIABLES.join(", ")
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether rendering the template would read the variable `name`. Templates
+/// that fail to parse are sent as-is, so they use none.
+pub fn uses_variable(template: &str, name: &str) -> bool {
+    parse(template)
+        .map(|segments| segments.iter().any(|segment| matches!(segment, Segment::Variable(v) if *v == name)))
+        .unwrap_or(false)
+}
+
+/// Values for the built-in variables the backend can resolve by itself.
+pub fn built_in_values(language: &str, chat_title: Option<&str>) -> HashMap<String, String> {
+    HashMap::from([
+        ("date".to_string(), Local::now().format("%Y-%m-%d").to_string()),
+        ("language".to_string(), language.to_string()),
+        ("chat_title".to_string(), chat_title.unwrap_or_default().to_string()),
+    ])
+}
+
+/// Renders a template. A variable takes its value from `values`, then from the
+/// parameter default, and is otherwise left empty. Templates that fail to parse
+/// (saved before validation existed) are returned unchanged.
+pub fn render(template: &str, parameters: &[PresetParameter], values: &HashMap<String, String>) -> String {
+    let segments = match parse(template) {
+        Ok(segments) => segments,
+        Err(e) => {
+            log::warn!("Prompt template is invalid, sending it as-is: {}", e);
+            return template.to_string();
+        }
+    };
+
+    let mut rendered = String::with_capacity(template.len());
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => rendered.push_str(text),
+            Segment::Variable(name) => {
+                let value = values.get(name)
+                    .map(String::as_str)
+                    .or_else(|| parameters.iter().find(|p| p.name == name).map(|p| p.default_value.as_str()))
+                    .unwrap_or_default();
+                rendered.push_str(value);
+            }
+        }
+    }
+
+    rendered
+}
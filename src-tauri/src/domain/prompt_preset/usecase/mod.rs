@@ -0,0 +1,2 @@
+pub mod preset_history;
+pub mod preset_bundle;
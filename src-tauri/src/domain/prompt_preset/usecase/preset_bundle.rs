@@ -0,0 +1,164 @@
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use uuid::Uuid;
+use crate::domain::prompt_preset::{
+    entity::{BundleFormat, BundledPreset, ImportConflictStrategy, ImportReport, PresetBundle, PromptPreset},
+    repository::PromptPresetRepository,
+    template,
+    usecase::preset_history::PresetHistoryUseCase,
+};
+
+const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+pub struct PresetBundleUseCase {
+    prompt_preset_repo: Arc<dyn PromptPresetRepository>,
+}
+
+impl PresetBundleUseCase {
+    pub fn new(prompt_preset_repo: Arc<dyn PromptPresetRepository>) -> Self {
+        Self { prompt_preset_repo }
+    }
+
+    /// Serializes presets into a bundle. Without `ids`, every custom preset is
+    /// exported (built-ins ship with the app, so they are only exported on request).
+    pub async fn export(&self, ids: Option<Vec<String>>, format: BundleFormat) -> Result<String> {
+        let presets: Vec<PromptPreset> = self.prompt_preset_repo.find_all().await?
+            .into_iter()
+            .filter(|p| match &ids {
+                Some(ids) => ids.contains(&p.id),
+                None => !p.is_built_in,
+            })
+            .collect();
+
+        let bundle = PresetBundle {
+            format_version: BUNDLE_FORMAT_VERSION,
+            exported_at: Utc::now(),
+            presets: presets.into_iter()
+                .map(|p| BundledPreset {
+                    id: p.id,
+                    name: p.name,
+                    description: p.description,
+                    prompt: p.prompt,
+                    preset_type: p.preset_type,
+                    parameters: p.parameters,
+                })
+                .collect(),
+        };
+
+        match format {
+            BundleFormat::Json => Ok(serde_json::to_string_pretty(&bundle)?),
+            BundleFormat::Yaml => Ok(serde_yaml::to_string(&bundle)?),
+        }
+    }
+
+    pub async fn import(&self, content: &str, format: BundleFormat, strategy: ImportConflictStrategy) -> Result<ImportReport> {
+        let bundle: PresetBundle = match format {
+            BundleFormat::Json => serde_json::from_str(content)
+                .map_err(|e| anyhow!("Invalid JSON preset bundle: {}", e))?,
+            BundleFormat::Yaml => serde_yaml::from_str(content)
+                .map_err(|e| anyhow!("Invalid YAML preset bundle: {}", e))?,
+        };
+
+        if bundle.format_version > BUNDLE_FORMAT_VERSION {
+            return Err(anyhow!(
+                "Preset bundle version {} is newer than supported version {}",
+                bundle.format_version,
+                BUNDLE_FORMAT_VERSION
+            ));
+        }
+
+        let history = PresetHistoryUseCase::new(self.prompt_preset_repo.clone());
+        let mut report = ImportReport::default();
+
+        for incoming in bundle.presets {
+            if incoming.name.trim().is_empty() || incoming.prompt.trim().is_empty() {
+                report.errors.push(format!("{}: name and prompt are required", incoming.id));
+                continue;
+            }
+            if let Err(e) = template::validate(&incoming.prompt, &incoming.parameters) {
+                report.errors.push(format!("{}: invalid prompt template: {}", incoming.name, e));
+                continue;
+            }
+
+            let existing = if incoming.id.trim().is_empty() {
+                None
+            } else {
+                self.prompt_preset_repo.find_by_id(&incoming.id).await?
+            };
+
+            let Some(mut existing) = existing else {
+                let id = if incoming.id.trim().is_empty() { Uuid::new_v4().to_string() } else { incoming.id.clone() };
+                let preset = Self::new_preset(id, incoming);
+                self.prompt_preset_repo.save(&preset).await?;
+                history.record(None, &preset).await?;
+                report.imported.push(preset.name);
+                continue;
+            };
+
+            let unchanged = existing.name == incoming.name
+                && existing.description == incoming.description
+                && existing.prompt == incoming.prompt
+                && existing.parameters == incoming.parameters;
+
+            // Built-ins are never overwritten; a modified copy is imported alongside instead
+            let strategy = if existing.is_built_in && strategy == ImportConflictStrategy::Overwrite {
+                ImportConflictStrategy::Duplicate
+            } else {
+                strategy
+            };
+
+            if unchanged || strategy == ImportConflictStrategy::Skip {
+                report.skipped.push(incoming.name);
+                continue;
+            }
+
+            match strategy {
+                ImportConflictStrategy::Overwrite => {
+                    let previous = existing.clone();
+                    existing.name = incoming.name;
+                    existing.description = incoming.description;
+                    existing.prompt = incoming.prompt;
+                    existing.parameters = incoming.parameters;
+                    existing.updated_at = Utc::now();
+
+                    self.prompt_preset_repo.update(&existing).await?;
+                    history.record(Some(&previous), &existing).await?;
+                    report.overwritten.push(existing.name);
+                }
+                _ => {
+                    let mut preset = Self::new_preset(Uuid::new_v4().to_string(), incoming);
+                    preset.name = format!("{} (imported)", preset.name);
+                    self.prompt_preset_repo.save(&preset).await?;
+                    history.record(None, &preset).await?;
+                    report.duplicated.push(preset.name);
+                }
+            }
+        }
+
+        log::info!(
+            "Imported presets: {} new, {} overwritten, {} duplicated, {} skipped, {} rejected",
+            report.imported.len(),
+            report.overwritten.len(),
+            report.duplicated.len(),
+            report.skipped.len(),
+            report.errors.len()
+        );
+
+        Ok(report)
+    }
+
+    fn new_preset(id: String, incoming: BundledPreset) -> PromptPreset {
+        PromptPreset {
+            id,
+            name: incoming.name,
+            description: incoming.description,
+            prompt: incoming.prompt,
+            is_built_in: false,
+            preset_type: incoming.preset_type,
+            parameters: incoming.parameters,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use similar::{ChangeTag, TextDiff};
+use crate::domain::prompt_preset::{
+    entity::{DiffLine, PresetDiff, PromptPreset, PromptPresetVersion},
+    repository::PromptPresetRepository,
+    template,
+};
+
+pub struct PresetHistoryUseCase {
+    prompt_preset_repo: Arc<dyn PromptPresetRepository>,
+}
+
+impl PresetHistoryUseCase {
+    pub fn new(prompt_preset_repo: Arc<dyn PromptPresetRepository>) -> Self {
+        Self { prompt_preset_repo }
+    }
+
+    /// Records `current` as a new version. `previous` is the content before the
+    /// change; it becomes version 1 for presets created before history existed.
+    pub async fn record(&self, previous: Option<&PromptPreset>, current: &PromptPreset) -> Result<PromptPresetVersion> {
+        if let Some(previous) = previous {
+            if self.prompt_preset_repo.find_versions(&previous.id).await?.is_empty() {
+                self.prompt_preset_repo.save_version(previous).await?;
+            }
+        }
+
+        self.prompt_preset_repo.save_version(current).await
+    }
+
+    pub async fn history(&self, preset_id: &str) -> Result<Vec<PromptPresetVersion>> {
+        self.prompt_preset_repo.find_versions(preset_id).await
+    }
+
+    pub async fn diff(&self, preset_id: &str, from_version: i64, to_version: i64) -> Result<PresetDiff> {
+        let from = self.find_version(preset_id, from_version).await?;
+        let to = self.find_version(preset_id, to_version).await?;
+
+        let prompt = TextDiff::from_lines(&from.prompt, &to.prompt)
+            .iter_all_changes()
+            .map(|change| DiffLine {
+                tag: match change.tag() {
+                    ChangeTag::Equal => "equal",
+                    ChangeTag::Insert => "insert",
+                    ChangeTag::Delete => "delete",
+                }.to_string(),
+                text: change.value().trim_end_matches('\n').to_string(),
+            })
+            .collect();
+
+        Ok(PresetDiff {
+            preset_id: preset_id.to_string(),
+            from_version,
+            to_version,
+            name_changed: from.name != to.name,
+            description_changed: from.description != to.description,
+            parameters_changed: from.parameters != to.parameters,
+            prompt,
+        })
+    }
+
+    /// Restores a preset to an earlier version. The rollback itself is recorded as
+    /// a new version, so history stays linear and can be rolled forward again.
+    pub async fn rollback(&self, preset_id: &str, version: i64) -> Result<PromptPreset> {
+        let mut preset = self.prompt_preset_repo.find_by_id(preset_id).await?
+            .ok_or_else(|| anyhow!("Prompt preset not found"))?;
+
+        if preset.is_built_in {
+            return Err(anyhow!("Cannot roll back built-in preset"));
+        }
+
+        let target = self.find_version(preset_id, version).await?;
+
+        // The variable set may have changed since this version was saved
+        template::validate(&target.prompt, &target.parameters)
+            .map_err(|e| anyhow!("Version {} has an invalid template: {}", version, e))?;
+
+        let previous = preset.clone();
+        preset.name = target.name;
+        preset.description = target.description;
+        preset.prompt = target.prompt;
+        preset.parameters = target.parameters;
+        preset.updated_at = Utc::now();
+
+        self.prompt_preset_repo.update(&preset).await?;
+        self.record(Some(&previous), &preset).await?;
+
+        Ok(preset)
+    }
+
+    async fn find_version(&self, preset_id: &str, version: i64) -> Result<PromptPresetVersion> {
+        self.prompt_preset_repo.find_version(preset_id, version).await?
+            .ok_or_else(|| anyhow!("Version {} not found for preset {}", version, preset_id))
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use crate::domain::ai::chat::entity::message::Message;
+
+/// Where a quick action reads its input from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuickActionSource {
+    /// The system clipboard
+    Clipboard,
+    /// The currently selected text: the PRIMARY selection on Linux (X11/Wayland),
+    /// the clipboard on other platforms
+    Selection,
+}
+
+impl QuickActionSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuickActionSource::Clipboard => "clipboard",
+            QuickActionSource::Selection => "selection",
+        }
+    }
+}
+
+impl std::str::FromStr for QuickActionSource {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "clipboard" => Ok(QuickActionSource::Clipboard),
+            "selection" | "primary" => Ok(QuickActionSource::Selection),
+            _ => Err(anyhow::anyhow!("Unknown quick action source: {}", s)),
+        }
+    }
+}
+
+/// A global shortcut that runs a preset on the clipboard or the selected text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuickAction {
+    pub id: Uuid,
+    pub name: String,
+    /// Accelerator string, e.g. `CommandOrControl+Alt+E`
+    pub shortcut: String,
+    pub prompt_preset_id: String,
+    pub source: QuickActionSource,
+    /// Values for the preset parameters (e.g. `target_language`)
+    pub template_values: HashMap<String, String>,
+    /// Provider/model to use; `None` falls back to the first configured API key
+    pub provider_name: Option<String>,
+    pub model: Option<String>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The chat a quick action opened and the assistant reply in it.
+#[derive(Debug, Clone, Serialize)]
+pub struct QuickActionResult {
+    pub action_id: Uuid,
+    pub chat_id: Uuid,
+    pub input: String,
+    pub message: Message,
+    pub follow_ups: Vec<String>,
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use anyhow::Result;
+use super::entity::QuickAction;
+
+#[async_trait]
+pub trait QuickActionRepository: Send + Sync {
+    async fn create(&self, action: &QuickAction) -> Result<QuickAction>;
+    async fn update(&self, action: &QuickAction) -> Result<QuickAction>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<QuickAction>>;
+    async fn find_all(&self) -> Result<Vec<QuickAction>>;
+    async fn delete(&self, id: Uuid) -> Result<()>;
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use uuid::Uuid;
+use crate::domain::{
+    prompt_preset::repository::PromptPresetRepository,
+    quick_action::{
+        entity::{QuickAction, QuickActionSource},
+        repository::QuickActionRepository,
+    },
+};
+
+pub struct QuickActionInput {
+    pub name: String,
+    pub shortcut: String,
+    pub prompt_preset_id: String,
+    pub source: QuickActionSource,
+    pub template_values: HashMap<String, String>,
+    pub provider_name: Option<String>,
+    pub model: Option<String>,
+    pub enabled: bool,
+}
+
+pub struct ManageQuickActionUseCase {
+    action_repo: Arc<dyn QuickActionRepository>,
+    prompt_preset_repo: Arc<dyn PromptPresetRepository>,
+}
+
+impl ManageQuickActionUseCase {
+    pub fn new(
+        action_repo: Arc<dyn QuickActionRepository>,
+        prompt_preset_repo: Arc<dyn PromptPresetRepository>,
+    ) -> Self {
+        Self { action_repo, prompt_preset_repo }
+    }
+
+    pub async fn add(&self, input: QuickActionInput) -> Result<QuickAction> {
+        let input = self.validate(input, None).await?;
+
+        let action = QuickAction {
+            id: Uuid::new_v4(),
+            name: input.name,
+            shortcut: input.shortcut,
+            prompt_preset_id: input.prompt_preset_id,
+            source: input.source,
+            template_values: input.template_values,
+            provider_name: input.provider_name,
+            model: input.model,
+            enabled: input.enabled,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        self.action_repo.create(&action).await
+    }
+
+    pub async fn update(&self, action_id: Uuid, input: QuickActionInput) -> Result<QuickAction> {
+        let mut action = self.action_repo.find_by_id(action_id).await?
+            .ok_or_else(|| anyhow!("Quick action not found"))?;
+        let input = self.validate(input, Some(action_id)).await?;
+
+        action.name = input.name;
+        action.shortcut = input.shortcut;
+        action.prompt_preset_id = input.prompt_preset_id;
+        action.source = input.source;
+        action.template_values = input.template_values;
+        action.provider_name = input.provider_name;
+        action.model = input.model;
+        action.enabled = input.enabled;
+        action.updated_at = Utc::now();
+
+        self.action_repo.update(&action).await
+    }
+
+    /// Shortcuts are compared case-insensitively so `Ctrl+Alt+E` and `ctrl+alt+e`
+    /// can't be bound twice; provider and model are only kept as a pair.
+    async fn validate(&self, mut input: QuickActionInput, current_id: Option<Uuid>) -> Result<QuickActionInput> {
+        input.name = input.name.trim().to_string();
+        if input.name.is_empty() {
+            return Err(anyhow!("Quick action name is required"));
+        }
+
+        input.shortcut = input.shortcut.trim().to_string();
+        if input.shortcut.is_empty() {
+            return Err(anyhow!("A shortcut is required"));
+        }
+        let taken = self.action_repo.find_all().await?
+            .into_iter()
+            .any(|a| Some(a.id) != current_id && a.shortcut.eq_ignore_ascii_case(&input.shortcut));
+        if taken {
+            return Err(anyhow!("Shortcut {} is already used by another quick action", input.shortcut));
+        }
+
+        if self.prompt_preset_repo.find_by_id(&input.prompt_preset_id).await?.is_none() {
+            return Err(anyhow!("Prompt preset not found: {}", input.prompt_preset_id));
+        }
+
+        input.provider_name = input.provider_name.filter(|s| !s.trim().is_empty());
+        input.model = input.model.filter(|s| !s.trim().is_empty());
+        if input.provider_name.is_none() || input.model.is_none() {
+            input.provider_name = None;
+            input.model = None;
+        }
+
+        Ok(input)
+    }
+}
@@ -0,0 +1,2 @@
+pub mod manage_quick_action;
+pub mod run_quick_action;
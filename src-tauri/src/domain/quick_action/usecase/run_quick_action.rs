@@ -0,0 +1,95 @@
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use crate::domain::{
+    ai::chat::{
+        repository::chat_repository::ChatRepository,
+        service::chat_service::{AIProviderType, ChatService, ChatServiceRequest},
+        usecase::create_chat::CreateChatUseCase,
+    },
+    quick_action::entity::{QuickAction, QuickActionResult},
+    user::repository::{
+        session_repository::SessionRepository,
+        user_api_key_repository::UserApiKeyRepository,
+    },
+};
+
+/// Longest input sent to the model; larger selections are cut at this many characters.
+const MAX_INPUT_CHARS: usize = 20_000;
+
+pub struct RunQuickActionUseCase {
+    chat_service: Arc<dyn ChatService>,
+    chat_repo: Arc<dyn ChatRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    user_api_key_repo: Arc<dyn UserApiKeyRepository>,
+}
+
+impl RunQuickActionUseCase {
+    pub fn new(
+        chat_service: Arc<dyn ChatService>,
+        chat_repo: Arc<dyn ChatRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        user_api_key_repo: Arc<dyn UserApiKeyRepository>,
+    ) -> Self {
+        Self { chat_service, chat_repo, session_repo, user_api_key_repo }
+    }
+
+    /// Opens a new chat with the action's preset and sends `text` as the user message.
+    pub async fn execute(&self, action: &QuickAction, text: String) -> Result<QuickActionResult> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(anyhow!("Nothing to send: the clipboard and the selection are empty"));
+        }
+        let text: String = text.chars().take(MAX_INPUT_CHARS).collect();
+
+        let session = self.session_repo.get().await?
+            .ok_or_else(|| anyhow!("No active session found"))?;
+        let user_id = session.user_id;
+
+        let (provider_name, model) = match (&action.provider_name, &action.model) {
+            (Some(provider), Some(model)) => (provider.clone(), model.clone()),
+            _ => {
+                let keys = self.user_api_key_repo.find_by_user_id(user_id).await?;
+                let key = keys.into_iter()
+                    .find(|k| k.provider.parse::<AIProviderType>().is_ok())
+                    .ok_or_else(|| anyhow!("No API key configured. Add one in settings to use quick actions."))?;
+                let model = match key.selected_model.filter(|m| !m.trim().is_empty()) {
+                    Some(model) => model,
+                    None => key.provider.parse::<AIProviderType>()?.default_model().to_string(),
+                };
+                (key.provider, model)
+            }
+        };
+
+        let chat = CreateChatUseCase::new(self.chat_repo.clone())
+            .execute(user_id, Some(action.name.clone()), Some(action.prompt_preset_id.clone()), Some(model.clone()), false)
+            .await?;
+
+        let mut template_values = action.template_values.clone();
+        template_values.insert("clipboard".to_string(), text.clone());
+
+        let request = ChatServiceRequest {
+            user_id,
+            chat_id: chat.id,
+            provider_name,
+            prompt: text.clone(),
+            model,
+            temperature: None,
+            max_tokens: None,
+            image: None,
+            output_language: None,
+            workspace_id: None,
+            template_values: Some(template_values),
+            mcp_resources: Vec::new(),
+        };
+
+        let (message, follow_ups) = self.chat_service.send_message_to_ai(request).await?;
+
+        Ok(QuickActionResult {
+            action_id: action.id,
+            chat_id: chat.id,
+            input: text,
+            message,
+            follow_ups,
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+/// What a retention rule does to the chats it matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionAction {
+    /// Deletes the chat and its messages
+    Delete,
+    /// Marks the chat as archived; nothing is removed
+    Archive,
+    /// Keeps the analyzed memories (summaries and importance) used by Smart RAG,
+    /// clears the raw text of summarized messages and deletes the rest
+    DropContent,
+}
+
+impl RetentionAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RetentionAction::Delete => "delete",
+            RetentionAction::Archive => "archive",
+            RetentionAction::DropContent => "drop_content",
+        }
+    }
+}
+
+impl std::str::FromStr for RetentionAction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "delete" => Ok(RetentionAction::Delete),
+            "archive" => Ok(RetentionAction::Archive),
+            "drop_content" => Ok(RetentionAction::DropContent),
+            _ => Err(anyhow::anyhow!("Unknown retention action: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionRule {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub action: RetentionAction,
+    /// Chats whose last activity is older than this are affected
+    pub older_than_days: i64,
+    pub keep_pinned: bool,
+    /// Keeps chats with at least one tag
+    pub keep_tagged: bool,
+    pub enabled: bool,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A chat a rule matched.
+#[derive(Debug, Clone, Serialize)]
+pub struct RetentionCandidate {
+    pub chat_id: Uuid,
+    pub title: Option<String>,
+    pub last_activity_at: DateTime<Utc>,
+    pub message_count: i64,
+}
+
+/// What one rule removed, or would remove when `dry_run` is set.
+#[derive(Debug, Clone, Serialize)]
+pub struct RetentionReport {
+    pub rule_id: Uuid,
+    pub rule_name: String,
+    pub action: RetentionAction,
+    pub dry_run: bool,
+    pub chats: Vec<RetentionCandidate>,
+    pub messages_affected: i64,
+}
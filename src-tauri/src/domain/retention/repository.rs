@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use super::entity::{RetentionAction, RetentionCandidate, RetentionRule};
+
+#[async_trait]
+pub trait RetentionRuleRepository: Send + Sync {
+    async fn create(&self, rule: &RetentionRule) -> Result<RetentionRule>;
+    async fn update(&self, rule: &RetentionRule) -> Result<RetentionRule>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<RetentionRule>>;
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<RetentionRule>>;
+    async fn find_enabled(&self) -> Result<Vec<RetentionRule>>;
+    async fn mark_run(&self, id: Uuid, ran_at: DateTime<Utc>) -> Result<()>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool>;
+
+    /// Chats of the rule's user it would act on now, oldest activity first.
+    async fn find_candidates(&self, rule: &RetentionRule, cutoff: DateTime<Utc>) -> Result<Vec<RetentionCandidate>>;
+    /// Applies `action` to the chats; returns the number of messages deleted or cleared.
+    async fn apply(&self, action: RetentionAction, chat_ids: &[Uuid]) -> Result<i64>;
+}
@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use crate::domain::retention::{
+    entity::{RetentionAction, RetentionReport, RetentionRule},
+    repository::RetentionRuleRepository,
+};
+
+pub struct ApplyRetentionUseCase {
+    rule_repo: Arc<dyn RetentionRuleRepository>,
+}
+
+impl ApplyRetentionUseCase {
+    pub fn new(rule_repo: Arc<dyn RetentionRuleRepository>) -> Self {
+        Self { rule_repo }
+    }
+
+    /// Reports what the user's enabled rules would remove right now, without touching anything.
+    pub async fn preview(&self, user_id: Uuid) -> Result<Vec<RetentionReport>> {
+        let rules = self.enabled_rules(user_id).await?;
+        self.evaluate(rules, true).await
+    }
+
+    /// Applies the user's enabled rules now.
+    pub async fn apply(&self, user_id: Uuid) -> Result<Vec<RetentionReport>> {
+        let rules = self.enabled_rules(user_id).await?;
+        self.evaluate(rules, false).await
+    }
+
+    /// Applies every enabled rule; used by the background job.
+    pub async fn apply_all(&self) -> Result<Vec<RetentionReport>> {
+        let rules = self.rule_repo.find_enabled().await?;
+        self.evaluate(rules, false).await
+    }
+
+    async fn enabled_rules(&self, user_id: Uuid) -> Result<Vec<RetentionRule>> {
+        Ok(self.rule_repo.find_by_user_id(user_id).await?
+            .into_iter()
+            .filter(|r| r.enabled)
+            .collect())
+    }
+
+    /// Rules run in order and a chat is only handled by the first rule that matches it,
+    /// so a dry run reports the same chats a real run would touch.
+    async fn evaluate(&self, rules: Vec<RetentionRule>, dry_run: bool) -> Result<Vec<RetentionReport>> {
+        let now = Utc::now();
+        let mut claimed: HashSet<Uuid> = HashSet::new();
+        let mut reports = Vec::new();
+
+        for rule in rules {
+            // Rules saved before the period was bounded may not fit a date
+            let Some(cutoff) = Duration::try_days(rule.older_than_days).and_then(|period| now.checked_sub_signed(period)) else {
+                log::warn!("[Retention] Skipping '{}': period of {} days is out of range", rule.name, rule.older_than_days);
+                continue;
+            };
+            let chats: Vec<_> = self.rule_repo.find_candidates(&rule, cutoff).await?
+                .into_iter()
+                .filter(|c| claimed.insert(c.chat_id))
+                .collect();
+
+            let messages_affected = if dry_run && rule.action == RetentionAction::Archive {
+                0
+            } else if dry_run {
+                chats.iter().map(|c| c.message_count).sum()
+            } else {
+                let chat_ids: Vec<Uuid> = chats.iter().map(|c| c.chat_id).collect();
+                let affected = if chat_ids.is_empty() {
+                    0
+                } else {
+                    self.rule_repo.apply(rule.action, &chat_ids).await?
+                };
+                self.rule_repo.mark_run(rule.id, now).await?;
+                if !chat_ids.is_empty() {
+                    log::info!(
+                        "[Retention] Rule '{}' ({}) processed {} chats, {} messages",
+                        rule.name, rule.action.as_str(), chat_ids.len(), affected
+                    );
+                }
+                affected
+            };
+
+            reports.push(RetentionReport {
+                rule_id: rule.id,
+                rule_name: rule.name,
+                action: rule.action,
+                dry_run,
+                chats,
+                messages_affected,
+            });
+        }
+
+        Ok(reports)
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use uuid::Uuid;
+use crate::domain::retention::{
+    entity::{RetentionAction, RetentionRule},
+    repository::RetentionRuleRepository,
+};
+
+/// Longest retention period accepted, about a century.
+const MAX_RETENTION_DAYS: i64 = 36_500;
+
+pub struct RetentionRuleInput {
+    pub name: String,
+    pub action: RetentionAction,
+    pub older_than_days: i64,
+    pub keep_pinned: bool,
+    pub keep_tagged: bool,
+    pub enabled: bool,
+}
+
+pub struct ManageRetentionRuleUseCase {
+    rule_repo: Arc<dyn RetentionRuleRepository>,
+}
+
+impl ManageRetentionRuleUseCase {
+    pub fn new(rule_repo: Arc<dyn RetentionRuleRepository>) -> Self {
+        Self { rule_repo }
+    }
+
+    pub async fn add(&self, user_id: Uuid, input: RetentionRuleInput) -> Result<RetentionRule> {
+        let input = Self::validate(input)?;
+
+        let rule = RetentionRule {
+            id: Uuid::new_v4(),
+            user_id,
+            name: input.name,
+            action: input.action,
+            older_than_days: input.older_than_days,
+            keep_pinned: input.keep_pinned,
+            keep_tagged: input.keep_tagged,
+            enabled: input.enabled,
+            last_run_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        self.rule_repo.create(&rule).await
+    }
+
+    pub async fn update(&self, user_id: Uuid, rule_id: Uuid, input: RetentionRuleInput) -> Result<RetentionRule> {
+        let mut rule = self.rule_repo.find_by_id(rule_id).await?
+            .filter(|rule| rule.user_id == user_id)
+            .ok_or_else(|| anyhow!("Retention rule not found"))?;
+        let input = Self::validate(input)?;
+
+        rule.name = input.name;
+        rule.action = input.action;
+        rule.older_than_days = input.older_than_days;
+        rule.keep_pinned = input.keep_pinned;
+        rule.keep_tagged = input.keep_tagged;
+        rule.enabled = input.enabled;
+        rule.updated_at = Utc::now();
+
+        self.rule_repo.update(&rule).await
+    }
+
+    fn validate(mut input: RetentionRuleInput) -> Result<RetentionRuleInput> {
+        input.name = input.name.trim().to_string();
+        if input.name.is_empty() {
+            return Err(anyhow!("Retention rule name is required"));
+        }
+        if !(1..=MAX_RETENTION_DAYS).contains(&input.older_than_days) {
+            return Err(anyhow!("Retention period must be between one day and {} days", MAX_RETENTION_DAYS));
+        }
+        Ok(input)
+    }
+}
@@ -0,0 +1,2 @@
+pub mod manage_rule;
+pub mod apply_retention;
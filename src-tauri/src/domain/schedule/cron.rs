@@ -0,0 +1,410 @@
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Datelike, Duration, Local, LocalResult, NaiveDate, TimeZone, Timelike};
+
+/// Days searched ahead for the next occurrence; long enough for `0 0 29 2 *`.
+const MAX_LOOKAHEAD_DAYS: i64 = 366 * 5;
+
+const MONTH_NAMES: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const DAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// A standard five-field cron expression (`minute hour day-of-month month day-of-week`),
+/// evaluated in local time.
+///
+/// Fields accept `*`, values, ranges (`1-5`), lists (`1,15`) and steps (`*/15`, `9-17/2`).
+/// Months and weekdays also accept three-letter names (`MON-FRI`, `JAN`), and Sunday
+/// can be written as 0 or 7. As in cron, when both day fields are restricted a day
+/// matches if either of them does. `@hourly`, `@daily`, `@weekly`, `@monthly` and
+/// `@weekdays` are accepted as shorthands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u32,
+    days_of_month: u32,
+    months: u16,
+    days_of_week: u8,
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<Self> {
+        let expression = expression.trim();
+        let expanded = match expression.to_lowercase().as_str() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            "@weekdays" => "0 9 * * 1-5",
+            _ => expression,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(anyhow!(
+                "Invalid schedule '{}': expected 5 fields (minute hour day month weekday)",
+                expression
+            ));
+        }
+
+        let minutes = parse_field(fields[0], 0, 59, &[], "minute")?;
+        let hours = parse_field(fields[1], 0, 23, &[], "hour")?;
+        let days_of_month = parse_field(fields[2], 1, 31, &[], "day of month")?;
+        let months = parse_field(fields[3], 1, 12, &MONTH_NAMES, "month")?;
+        // 7 is accepted as an alias for Sunday and folded into bit 0
+        let raw_days_of_week = parse_field(fields[4], 0, 7, &DAY_NAMES, "day of week")?;
+        let days_of_week = ((raw_days_of_week | (raw_days_of_week >> 7)) & 0x7f) as u8;
+
+        Ok(Self {
+            minutes,
+            hours: hours as u32,
+            days_of_month: days_of_month as u32,
+            months: months as u16,
+            days_of_week,
+            // As in vixie cron, a field starting with `*` (`*/2` too) doesn't restrict the day
+            any_day_of_month: fields[2].starts_with('*'),
+            any_day_of_week: fields[4].starts_with('*'),
+        })
+    }
+
+    /// First occurrence strictly after `after`, or `None` if the expression never
+    /// matches (e.g. `0 0 31 2 *`).
+    pub fn next_after<Tz: TimeZone>(&self, after: &DateTime<Tz>) -> Option<DateTime<Local>> {
+        self.next_after_in(after, &Local)
+    }
+
+    /// `next_after` with the wall clock of `zone`.
+    fn next_after_in<Tz: TimeZone, Z: TimeZone>(&self, after: &DateTime<Tz>, zone: &Z) -> Option<DateTime<Z>> {
+        let after = after.with_timezone(zone);
+        let start = after
+            .with_second(0)
+            .and_then(|t| t.with_nanosecond(0))
+            .unwrap_or(after)
+            + Duration::minutes(1);
+        let start_date = start.date_naive();
+
+        for offset in 0..MAX_LOOKAHEAD_DAYS {
+            let date = start_date + Duration::days(offset);
+            if !self.matches_date(date) {
+                continue;
+            }
+
+            let first_day = offset == 0;
+            for hour in 0..24u32 {
+                if self.hours & (1 << hour) == 0 || (first_day && hour < start.hour()) {
+                    continue;
+                }
+                for minute in 0..60u32 {
+                    if self.minutes & (1 << minute) == 0 {
+                        continue;
+                    }
+                    if first_day && hour == start.hour() && minute < start.minute() {
+                        continue;
+                    }
+
+                    let Some(naive) = date.and_hms_opt(hour, minute, 0) else { continue };
+                    match zone.from_local_datetime(&naive) {
+                        LocalResult::Single(time) => return Some(time),
+                        // Clocks went back: the first of the repeated times is used
+                        LocalResult::Ambiguous(earliest, _) => return Some(earliest),
+                        // Clocks went forward: this time doesn't exist today
+                        LocalResult::None => continue,
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// The next `count` occurrences after `after`.
+    pub fn upcoming<Tz: TimeZone>(&self, after: &DateTime<Tz>, count: usize) -> Vec<DateTime<Local>> {
+        let mut runs = Vec::with_capacity(count);
+        let mut cursor = after.with_timezone(&Local);
+        while runs.len() < count {
+            match self.next_after(&cursor) {
+                Some(next) => {
+                    runs.push(next);
+                    cursor = next;
+                }
+                None => break,
+            }
+        }
+        runs
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+
+        let day_of_month = self.days_of_month & (1 << date.day()) != 0;
+        let day_of_week = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+
+        if self.any_day_of_month || self.any_day_of_week {
+            day_of_month && day_of_week
+        } else {
+            day_of_month || day_of_week
+        }
+    }
+}
+
+/// Parses one field into a bitmask where bit `n` is set when value `n` matches.
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str], label: &str) -> Result<u64> {
+    let mut mask = 0u64;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse()
+                    .map_err(|_| anyhow!("Invalid {} step '{}'", label, step))?;
+                if step == 0 {
+                    return Err(anyhow!("Invalid {} step '0'", label));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((from, to)) = range.split_once('-') {
+            (parse_value(from, min, names, label)?, parse_value(to, min, names, label)?)
+        } else {
+            let value = parse_value(range, min, names, label)?;
+            // `5/15` means "from 5 to the end, every 15"
+            if part.contains('/') { (value, max) } else { (value, value) }
+        };
+
+        if start < min || end > max || start > end {
+            return Err(anyhow!("Invalid {} '{}': values must be between {} and {}", label, part, min, max));
+        }
+
+        let mut value = start;
+        while value <= end {
+            mask |= 1 << value;
+            value += step;
+        }
+    }
+
+    Ok(mask)
+}
+
+fn parse_value(value: &str, min: u32, names: &[&str], label: &str) -> Result<u32> {
+    let lower = value.to_lowercase();
+    if let Some(index) = names.iter().position(|name| *name == lower) {
+        return Ok(index as u32 + min);
+    }
+    value.parse().map_err(|_| anyhow!("Invalid {} '{}'", label, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDateTime, Utc};
+
+    /// Central European time in 2026: clocks go forward at 02:00 on March 29
+    /// and back at 03:00 on October 25.
+    #[derive(Debug, Clone, Copy)]
+    struct Cet2026;
+
+    impl Cet2026 {
+        fn winter() -> FixedOffset {
+            FixedOffset::east_opt(3600).unwrap()
+        }
+
+        fn summer() -> FixedOffset {
+            FixedOffset::east_opt(7200).unwrap()
+        }
+    }
+
+    impl TimeZone for Cet2026 {
+        type Offset = FixedOffset;
+
+        fn from_offset(_: &FixedOffset) -> Self {
+            Cet2026
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_hms_opt(0, 0, 0).unwrap())
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let spring = at(2026, 3, 29, 2, 0);
+            let autumn = at(2026, 10, 25, 2, 0);
+            if *local < spring {
+                LocalResult::Single(Self::winter())
+            } else if *local < spring + Duration::hours(1) {
+                LocalResult::None
+            } else if *local < autumn {
+                LocalResult::Single(Self::summer())
+            } else if *local < autumn + Duration::hours(1) {
+                LocalResult::Ambiguous(Self::summer(), Self::winter())
+            } else {
+                LocalResult::Single(Self::winter())
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_hms_opt(0, 0, 0).unwrap())
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            if *utc >= at(2026, 3, 29, 1, 0) && *utc < at(2026, 10, 25, 1, 0) {
+                Self::summer()
+            } else {
+                Self::winter()
+            }
+        }
+    }
+
+    fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap().and_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    /// Next occurrence in UTC, as a naive wall-clock time.
+    fn next(expression: &str, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let schedule = CronSchedule::parse(expression).unwrap();
+        schedule.next_after_in(&Utc.from_utc_datetime(&after), &Utc).map(|t| t.naive_utc())
+    }
+
+    fn upcoming(expression: &str, after: NaiveDateTime, count: usize) -> Vec<NaiveDateTime> {
+        let schedule = CronSchedule::parse(expression).unwrap();
+        let mut runs = Vec::new();
+        let mut cursor = Utc.from_utc_datetime(&after);
+        while runs.len() < count {
+            let Some(run) = schedule.next_after_in(&cursor, &Utc) else { break };
+            runs.push(run.naive_utc());
+            cursor = run;
+        }
+        runs
+    }
+
+    #[test]
+    fn next_is_strictly_after() {
+        assert_eq!(next("30 9 * * *", at(2026, 1, 1, 9, 30)), Some(at(2026, 1, 2, 9, 30)));
+        assert_eq!(next("30 9 * * *", at(2026, 1, 1, 9, 29)), Some(at(2026, 1, 1, 9, 30)));
+    }
+
+    #[test]
+    fn seconds_are_ignored() {
+        let after = at(2026, 1, 1, 9, 29) + Duration::seconds(59);
+        assert_eq!(next("30 9 * * *", after), Some(at(2026, 1, 1, 9, 30)));
+    }
+
+    #[test]
+    fn ranges_include_both_ends() {
+        let runs = upcoming("0 9-11 * * *", at(2026, 1, 1, 0, 0), 4);
+        assert_eq!(runs, vec![at(2026, 1, 1, 9, 0), at(2026, 1, 1, 10, 0), at(2026, 1, 1, 11, 0), at(2026, 1, 2, 9, 0)]);
+    }
+
+    #[test]
+    fn steps_over_wildcards_ranges_and_starts() {
+        assert_eq!(next("*/15 * * * *", at(2026, 1, 1, 10, 7)), Some(at(2026, 1, 1, 10, 15)));
+
+        let hours: Vec<u32> = upcoming("0 9-17/4 * * *", at(2026, 1, 1, 0, 0), 3).iter().map(|t| t.hour()).collect();
+        assert_eq!(hours, vec![9, 13, 17]);
+
+        let minutes: Vec<u32> = upcoming("5/20 0 * * *", at(2026, 1, 1, 0, 0), 3).iter().map(|t| t.minute()).collect();
+        assert_eq!(minutes, vec![5, 25, 45]);
+    }
+
+    #[test]
+    fn lists_combine_values_and_ranges() {
+        let minutes: Vec<u32> = upcoming("0,10-12,30 0 * * *", at(2026, 1, 1, 0, 0) - Duration::minutes(1), 5)
+            .iter()
+            .map(|t| t.minute())
+            .collect();
+        assert_eq!(minutes, vec![0, 10, 11, 12, 30]);
+    }
+
+    #[test]
+    fn weekday_and_month_names() {
+        // 2026-01-03 is a Saturday
+        assert_eq!(next("0 9 * * MON-FRI", at(2026, 1, 3, 0, 0)), Some(at(2026, 1, 5, 9, 0)));
+        assert_eq!(next("0 9 * * sat,Sun", at(2026, 1, 5, 0, 0)), Some(at(2026, 1, 10, 9, 0)));
+        assert_eq!(next("0 0 1 MAR *", at(2026, 1, 1, 0, 0)), Some(at(2026, 3, 1, 0, 0)));
+        assert_eq!(CronSchedule::parse("0 9 * * MON-FRI").unwrap(), CronSchedule::parse("0 9 * * 1-5").unwrap());
+    }
+
+    #[test]
+    fn seven_is_sunday() {
+        assert_eq!(CronSchedule::parse("0 0 * * 7").unwrap(), CronSchedule::parse("0 0 * * 0").unwrap());
+        // 2026-01-04 is a Sunday
+        assert_eq!(next("0 0 * * 7", at(2026, 1, 1, 0, 0)), Some(at(2026, 1, 4, 0, 0)));
+        assert_eq!(next("0 0 * * 5-7", at(2026, 1, 3, 12, 0)), Some(at(2026, 1, 4, 0, 0)));
+    }
+
+    #[test]
+    fn february_29_waits_for_a_leap_year() {
+        assert_eq!(next("0 0 29 2 *", at(2026, 3, 1, 0, 0)), Some(at(2028, 2, 29, 0, 0)));
+    }
+
+    #[test]
+    fn impossible_dates_never_match() {
+        assert_eq!(next("0 0 31 2 *", at(2026, 1, 1, 0, 0)), None);
+        assert_eq!(next("0 0 31 4,6,9,11 *", at(2026, 1, 1, 0, 0)), None);
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either() {
+        // 2026-01-02 is a Friday, before the 13th
+        assert_eq!(next("0 0 13 * FRI", at(2026, 1, 1, 0, 0)), Some(at(2026, 1, 2, 0, 0)));
+        assert_eq!(next("0 0 13 * FRI", at(2026, 1, 9, 0, 0)), Some(at(2026, 1, 13, 0, 0)));
+    }
+
+    #[test]
+    fn a_wildcard_day_field_restricts_nothing() {
+        assert_eq!(next("0 0 1 * *", at(2026, 1, 1, 0, 0)), Some(at(2026, 2, 1, 0, 0)));
+        assert_eq!(next("0 0 * * MON", at(2026, 1, 1, 0, 0)), Some(at(2026, 1, 5, 0, 0)));
+    }
+
+    #[test]
+    fn stepped_wildcard_day_fields_match_both() {
+        // Odd days that are Mondays: the 3rd (a Saturday) alone is not enough
+        assert_eq!(next("0 0 */2 * MON", at(2026, 1, 1, 0, 0)), Some(at(2026, 1, 5, 0, 0)));
+        assert_eq!(next("0 0 */2 * MON", at(2026, 1, 5, 0, 0)), Some(at(2026, 1, 19, 0, 0)));
+        // `*/7` is Sunday (0 and 7): the next 13th that falls on a Sunday
+        assert_eq!(next("0 0 13 * */7", at(2026, 1, 1, 0, 0)), Some(at(2026, 9, 13, 0, 0)));
+    }
+
+    #[test]
+    fn shorthands() {
+        assert_eq!(CronSchedule::parse("@daily").unwrap(), CronSchedule::parse("0 0 * * *").unwrap());
+        assert_eq!(CronSchedule::parse("@WEEKDAYS").unwrap(), CronSchedule::parse("0 9 * * 1-5").unwrap());
+        assert_eq!(next("@hourly", at(2026, 1, 1, 10, 30)), Some(at(2026, 1, 1, 11, 0)));
+    }
+
+    #[test]
+    fn invalid_expressions_are_rejected() {
+        for expression in [
+            "", "* * * *", "* * * * * *", "60 * * * *", "* 24 * * *", "* * 0 * *", "* * 32 * *",
+            "* * * 13 *", "* * * * 8", "*/0 * * * *", "5-1 * * * *", "a * * * *", "* * * * MON-",
+        ] {
+            assert!(CronSchedule::parse(expression).is_err(), "{:?} should be rejected", expression);
+        }
+    }
+
+    #[test]
+    fn times_skipped_by_dst_are_skipped() {
+        let schedule = CronSchedule::parse("30 2 * * *").unwrap();
+        let after = Cet2026.from_local_datetime(&at(2026, 3, 28, 12, 0)).unwrap();
+        let run = schedule.next_after_in(&after, &Cet2026).unwrap();
+        assert_eq!(run.naive_local(), at(2026, 3, 30, 2, 30));
+
+        // Hourly runs jump from 01:00 straight to 03:00
+        let schedule = CronSchedule::parse("0 * * * *").unwrap();
+        let after = Cet2026.from_local_datetime(&at(2026, 3, 29, 1, 0)).unwrap();
+        let run = schedule.next_after_in(&after, &Cet2026).unwrap();
+        assert_eq!(run.naive_local(), at(2026, 3, 29, 3, 0));
+        assert_eq!(run.signed_duration_since(after), Duration::hours(1));
+    }
+
+    #[test]
+    fn times_repeated_by_dst_use_the_first() {
+        let schedule = CronSchedule::parse("30 2 * * *").unwrap();
+        let after = Cet2026.from_local_datetime(&at(2026, 10, 24, 12, 0)).unwrap();
+        let run = schedule.next_after_in(&after, &Cet2026).unwrap();
+        assert_eq!(run.naive_local(), at(2026, 10, 25, 2, 30));
+        assert_eq!(*run.offset(), Cet2026::summer());
+    }
+}
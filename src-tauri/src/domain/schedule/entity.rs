@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::domain::ai::chat::entity::message::Message;
+
+/// Extra context gathered right before a scheduled prompt runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContextSource {
+    /// Calendar events from the start of the run's day, spanning `days` days
+    Calendar { days: i64 },
+    /// Messages of chats active in the last `hours` hours
+    RecentChats { hours: i64 },
+}
+
+/// What happens to occurrences that passed while the app was closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CatchUpPolicy {
+    /// Missed occurrences are dropped; the prompt waits for its next occurrence
+    Skip,
+    /// A single run stands in for all missed occurrences
+    RunOnce,
+    /// Every missed occurrence is run, oldest first (capped)
+    RunAll,
+}
+
+impl CatchUpPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CatchUpPolicy::Skip => "skip",
+            CatchUpPolicy::RunOnce => "run_once",
+            CatchUpPolicy::RunAll => "run_all",
+        }
+    }
+}
+
+impl std::str::FromStr for CatchUpPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "skip" => Ok(CatchUpPolicy::Skip),
+            "run_once" => Ok(CatchUpPolicy::RunOnce),
+            "run_all" => Ok(CatchUpPolicy::RunAll),
+            _ => Err(anyhow::anyhow!("Unknown catch-up policy: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledPrompt {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    /// Five-field cron expression, evaluated in local time
+    pub cron: String,
+    pub prompt_preset_id: Option<String>,
+    /// Instruction sent with the gathered context
+    pub prompt: String,
+    pub context_sources: Vec<ContextSource>,
+    pub provider_name: Option<String>,
+    pub model: Option<String>,
+    /// Chat the results are appended to; created on the first run
+    pub chat_id: Option<Uuid>,
+    pub deliver_email: bool,
+    pub deliver_notification: bool,
+    pub catch_up: CatchUpPolicy,
+    pub enabled: bool,
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// `None` when the expression has no future occurrence
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One completed run of a scheduled prompt.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduledRunResult {
+    pub schedule_id: Uuid,
+    pub schedule_name: String,
+    pub chat_id: Uuid,
+    pub message: Message,
+    /// The occurrence this run stands for; older than the run itself when catching up
+    pub scheduled_for: DateTime<Utc>,
+    /// Whether a desktop notification is raised for the run
+    pub notify: bool,
+    pub emailed: bool,
+    /// Delivery problems that didn't stop the run (e.g. the email couldn't be sent)
+    pub warnings: Vec<String>,
+}
@@ -0,0 +1,4 @@
+pub mod cron;
+pub mod entity;
+pub mod repository;
+pub mod usecase;
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use super::entity::ScheduledPrompt;
+
+#[async_trait]
+pub trait ScheduledPromptRepository: Send + Sync {
+    async fn create(&self, schedule: &ScheduledPrompt) -> Result<ScheduledPrompt>;
+    async fn update(&self, schedule: &ScheduledPrompt) -> Result<ScheduledPrompt>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ScheduledPrompt>>;
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<ScheduledPrompt>>;
+    /// Enabled prompts whose next occurrence is at or before `now`.
+    async fn find_due(&self, now: DateTime<Utc>) -> Result<Vec<ScheduledPrompt>>;
+    async fn mark_run(&self, id: Uuid, last_run_at: Option<DateTime<Utc>>, next_run_at: Option<DateTime<Utc>>) -> Result<()>;
+    async fn set_chat_id(&self, id: Uuid, chat_id: Uuid) -> Result<()>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool>;
+}
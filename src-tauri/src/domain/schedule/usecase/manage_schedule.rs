@@ -0,0 +1,146 @@
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::{
+    prompt_preset::repository::PromptPresetRepository,
+    schedule::{
+        cron::CronSchedule,
+        entity::{CatchUpPolicy, ContextSource, ScheduledPrompt},
+        repository::ScheduledPromptRepository,
+    },
+};
+
+/// Longest look-back/look-ahead a context source may ask for.
+const MAX_CONTEXT_HOURS: i64 = 24 * 31;
+
+pub struct ScheduledPromptInput {
+    pub name: String,
+    pub cron: String,
+    pub prompt_preset_id: Option<String>,
+    pub prompt: String,
+    pub context_sources: Vec<ContextSource>,
+    pub provider_name: Option<String>,
+    pub model: Option<String>,
+    pub deliver_email: bool,
+    pub deliver_notification: bool,
+    pub catch_up: CatchUpPolicy,
+    pub enabled: bool,
+}
+
+pub struct ManageScheduledPromptUseCase {
+    schedule_repo: Arc<dyn ScheduledPromptRepository>,
+    prompt_preset_repo: Arc<dyn PromptPresetRepository>,
+}
+
+impl ManageScheduledPromptUseCase {
+    pub fn new(
+        schedule_repo: Arc<dyn ScheduledPromptRepository>,
+        prompt_preset_repo: Arc<dyn PromptPresetRepository>,
+    ) -> Self {
+        Self { schedule_repo, prompt_preset_repo }
+    }
+
+    pub async fn add(&self, user_id: Uuid, input: ScheduledPromptInput) -> Result<ScheduledPrompt> {
+        let (input, cron) = self.validate(input).await?;
+        let now = Utc::now();
+
+        let schedule = ScheduledPrompt {
+            id: Uuid::new_v4(),
+            user_id,
+            name: input.name,
+            cron: input.cron,
+            prompt_preset_id: input.prompt_preset_id,
+            prompt: input.prompt,
+            context_sources: input.context_sources,
+            provider_name: input.provider_name,
+            model: input.model,
+            chat_id: None,
+            deliver_email: input.deliver_email,
+            deliver_notification: input.deliver_notification,
+            catch_up: input.catch_up,
+            enabled: input.enabled,
+            last_run_at: None,
+            next_run_at: cron.next_after(&now).map(|t| t.with_timezone(&Utc)),
+            created_at: now,
+            updated_at: now,
+        };
+
+        self.schedule_repo.create(&schedule).await
+    }
+
+    /// Updates the prompt. Changing the expression or re-enabling it starts counting
+    /// from now, so edits never trigger catch-up runs.
+    pub async fn update(&self, user_id: Uuid, schedule_id: Uuid, input: ScheduledPromptInput) -> Result<ScheduledPrompt> {
+        let mut schedule = self.schedule_repo.find_by_id(schedule_id).await?
+            .filter(|schedule| schedule.user_id == user_id)
+            .ok_or_else(|| anyhow!("Scheduled prompt not found"))?;
+        let (input, cron) = self.validate(input).await?;
+        let now = Utc::now();
+
+        if schedule.cron != input.cron || (!schedule.enabled && input.enabled) || schedule.next_run_at.is_none() {
+            schedule.next_run_at = cron.next_after(&now).map(|t| t.with_timezone(&Utc));
+        }
+
+        schedule.name = input.name;
+        schedule.cron = input.cron;
+        schedule.prompt_preset_id = input.prompt_preset_id;
+        schedule.prompt = input.prompt;
+        schedule.context_sources = input.context_sources;
+        schedule.provider_name = input.provider_name;
+        schedule.model = input.model;
+        schedule.deliver_email = input.deliver_email;
+        schedule.deliver_notification = input.deliver_notification;
+        schedule.catch_up = input.catch_up;
+        schedule.enabled = input.enabled;
+        schedule.updated_at = now;
+
+        self.schedule_repo.update(&schedule).await
+    }
+
+    /// Next `count` occurrences of an expression, for previewing it while editing.
+    pub fn preview(cron: &str, count: usize) -> Result<Vec<DateTime<Utc>>> {
+        let cron = CronSchedule::parse(cron)?;
+        Ok(cron.upcoming(&Utc::now(), count.clamp(1, 20))
+            .into_iter()
+            .map(|t| t.with_timezone(&Utc))
+            .collect())
+    }
+
+    async fn validate(&self, mut input: ScheduledPromptInput) -> Result<(ScheduledPromptInput, CronSchedule)> {
+        input.name = input.name.trim().to_string();
+        input.cron = input.cron.trim().to_string();
+        input.prompt = input.prompt.trim().to_string();
+        input.prompt_preset_id = input.prompt_preset_id.filter(|id| !id.trim().is_empty());
+
+        if input.name.is_empty() {
+            return Err(anyhow!("Scheduled prompt name is required"));
+        }
+        if input.prompt.is_empty() && input.prompt_preset_id.is_none() {
+            return Err(anyhow!("A prompt or a prompt preset is required"));
+        }
+
+        let cron = CronSchedule::parse(&input.cron)?;
+        if cron.next_after(&Utc::now()).is_none() {
+            return Err(anyhow!("Schedule '{}' never runs", input.cron));
+        }
+
+        if let Some(preset_id) = &input.prompt_preset_id {
+            if self.prompt_preset_repo.find_by_id(preset_id).await?.is_none() {
+                return Err(anyhow!("Prompt preset not found: {}", preset_id));
+            }
+        }
+
+        for source in &input.context_sources {
+            let hours = match source {
+                ContextSource::Calendar { days } => days.checked_mul(24),
+                ContextSource::RecentChats { hours } => Some(*hours),
+            };
+            if !hours.is_some_and(|hours| (1..=MAX_CONTEXT_HOURS).contains(&hours)) {
+                return Err(anyhow!("Context sources can cover between one hour and 31 days"));
+            }
+        }
+
+        Ok((input, cron))
+    }
+}
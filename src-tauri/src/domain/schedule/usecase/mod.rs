@@ -0,0 +1,2 @@
+pub mod manage_schedule;
+pub mod run_scheduled_prompt;
@@ -0,0 +1,379 @@
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Duration, Local, Utc};
+use uuid::Uuid;
+use crate::domain::{
+    ai::chat::{
+        repository::{chat_repository::ChatRepository, message_repository::MessageRepository},
+        service::chat_service::{AIProviderType, ChatService, ChatServiceRequest},
+        usecase::create_chat::CreateChatUseCase,
+    },
+    calendar::{repository::CalendarRepository, usecase::list_events::ListEventsUseCase},
+    notification::email::{
+        service::email_service::EmailService,
+        usecase::send_chat_summary_email::html_escape,
+    },
+    schedule::{
+        cron::CronSchedule,
+        entity::{CatchUpPolicy, ContextSource, ScheduledPrompt, ScheduledRunResult},
+        repository::ScheduledPromptRepository,
+    },
+    user::repository::{
+        session_repository::SessionRepository,
+        user_api_key_repository::UserApiKeyRepository,
+        user_repository::UserRepository,
+    },
+};
+
+/// How late an occurrence may run and still count as on time rather than missed.
+const ON_TIME_GRACE_MINUTES: i64 = 5;
+/// Most missed occurrences replayed by `CatchUpPolicy::RunAll`; older ones are dropped.
+const MAX_CATCH_UP_RUNS: usize = 10;
+/// Chats and messages read for `ContextSource::RecentChats`.
+const MAX_RECENT_CHATS: usize = 10;
+const MESSAGES_PER_CHAT: i64 = 10;
+const MAX_MESSAGE_CHARS: usize = 600;
+/// Longest context sent with one run; the rest is cut.
+const MAX_CONTEXT_CHARS: usize = 30_000;
+
+pub struct RunScheduledPromptUseCase {
+    schedule_repo: Arc<dyn ScheduledPromptRepository>,
+    chat_service: Arc<dyn ChatService>,
+    chat_repo: Arc<dyn ChatRepository>,
+    message_repo: Arc<dyn MessageRepository>,
+    calendar_repo: Arc<dyn CalendarRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    user_api_key_repo: Arc<dyn UserApiKeyRepository>,
+    email_service: Arc<EmailService>,
+}
+
+impl RunScheduledPromptUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        schedule_repo: Arc<dyn ScheduledPromptRepository>,
+        chat_service: Arc<dyn ChatService>,
+        chat_repo: Arc<dyn ChatRepository>,
+        message_repo: Arc<dyn MessageRepository>,
+        calendar_repo: Arc<dyn CalendarRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        user_api_key_repo: Arc<dyn UserApiKeyRepository>,
+        email_service: Arc<EmailService>,
+    ) -> Self {
+        Self {
+            schedule_repo,
+            chat_service,
+            chat_repo,
+            message_repo,
+            calendar_repo,
+            session_repo,
+            user_repo,
+            user_api_key_repo,
+            email_service,
+        }
+    }
+
+    /// Runs every prompt that is due, applying its catch-up policy to occurrences
+    /// missed while the app was closed, and moves it to its next occurrence.
+    pub async fn run_due(&self, now: DateTime<Utc>) -> Result<Vec<(ScheduledPrompt, Result<ScheduledRunResult>)>> {
+        let mut outcomes = Vec::new();
+
+        for schedule in self.schedule_repo.find_due(now).await? {
+            let cron = match CronSchedule::parse(&schedule.cron) {
+                Ok(cron) => cron,
+                Err(e) => {
+                    // Unparseable expressions are parked instead of retried every tick
+                    self.schedule_repo.mark_run(schedule.id, schedule.last_run_at, None).await?;
+                    outcomes.push((schedule, Err(e)));
+                    continue;
+                }
+            };
+
+            let (occurrences, total) = Self::pending_occurrences(&schedule, &cron, now);
+            let on_time_since = now - Duration::minutes(ON_TIME_GRACE_MINUTES);
+            let on_time = occurrences.iter().filter(|t| **t >= on_time_since).count();
+            let missed = total - on_time;
+
+            let to_run: Vec<DateTime<Utc>> = match schedule.catch_up {
+                CatchUpPolicy::Skip => occurrences.iter().copied().filter(|t| *t >= on_time_since).take(1).collect(),
+                CatchUpPolicy::RunOnce => occurrences.last().copied().into_iter().collect(),
+                CatchUpPolicy::RunAll => occurrences,
+            };
+
+            if missed > 0 {
+                log::info!(
+                    "[Schedule] '{}' missed {} occurrence(s); policy {} runs {}",
+                    schedule.name, missed, schedule.catch_up.as_str(), to_run.len()
+                );
+            }
+
+            let mut last_run_at = schedule.last_run_at;
+            let mut current = schedule.clone();
+            for scheduled_for in to_run {
+                let result = self.execute(&current, scheduled_for).await;
+                last_run_at = Some(Utc::now());
+                if let Ok(run) = &result {
+                    current.chat_id = Some(run.chat_id);
+                }
+                outcomes.push((current.clone(), result));
+            }
+
+            let next_run_at = cron.next_after(&now).map(|t| t.with_timezone(&Utc));
+            self.schedule_repo.mark_run(schedule.id, last_run_at, next_run_at).await?;
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Gathers the context, sends the prompt into the schedule's chat and delivers
+    /// the answer. Used by the scheduler and by "run now".
+    pub async fn execute(&self, schedule: &ScheduledPrompt, scheduled_for: DateTime<Utc>) -> Result<ScheduledRunResult> {
+        let session = self.session_repo.get().await?
+            .ok_or_else(|| anyhow!("No active session found"))?;
+        if session.user_id != schedule.user_id {
+            return Err(anyhow!("Scheduled prompt '{}' belongs to another account", schedule.name));
+        }
+        let user_id = schedule.user_id;
+
+        let (provider_name, model) = match (&schedule.provider_name, &schedule.model) {
+            (Some(provider), Some(model)) => (provider.clone(), model.clone()),
+            _ => {
+                let keys = self.user_api_key_repo.find_by_user_id(user_id).await?;
+                let key = keys.into_iter()
+                    .find(|k| k.provider.parse::<AIProviderType>().is_ok())
+                    .ok_or_else(|| anyhow!("No API key configured. Add one in settings to use scheduled prompts."))?;
+                let model = match key.selected_model.filter(|m| !m.trim().is_empty()) {
+                    Some(model) => model,
+                    None => key.provider.parse::<AIProviderType>()?.default_model().to_string(),
+                };
+                (key.provider, model)
+            }
+        };
+
+        let existing_chat = match schedule.chat_id {
+            Some(chat_id) => self.chat_repo.find_by_id(chat_id).await?,
+            None => None,
+        };
+        let chat = match existing_chat {
+            Some(chat) => chat,
+            None => {
+                let chat = CreateChatUseCase::new(self.chat_repo.clone())
+                    .execute(user_id, Some(schedule.name.clone()), schedule.prompt_preset_id.clone(), Some(model.clone()), false)
+                    .await?;
+                self.schedule_repo.set_chat_id(schedule.id, chat.id).await?;
+                chat
+            }
+        };
+
+        let prompt = self.build_prompt(schedule, scheduled_for, chat.id).await;
+
+        let request = ChatServiceRequest {
+            user_id,
+            chat_id: chat.id,
+            provider_name,
+            prompt,
+            model,
+            temperature: None,
+            max_tokens: None,
+            image: None,
+            output_language: None,
+            workspace_id: None,
+            template_values: None,
+            mcp_resources: Vec::new(),
+        };
+
+        let (message, _follow_ups) = self.chat_service.send_message_to_ai(request).await?;
+
+        let mut warnings = Vec::new();
+        let mut emailed = false;
+        if schedule.deliver_email {
+            match self.send_email(schedule, scheduled_for, &message.content).await {
+                Ok(()) => emailed = true,
+                Err(e) => {
+                    log::warn!("[Schedule] Failed to email '{}': {}", schedule.name, e);
+                    warnings.push(format!("Email not sent: {}", e));
+                }
+            }
+        }
+
+        Ok(ScheduledRunResult {
+            schedule_id: schedule.id,
+            schedule_name: schedule.name.clone(),
+            chat_id: chat.id,
+            message,
+            scheduled_for,
+            notify: schedule.deliver_notification,
+            emailed,
+            warnings,
+        })
+    }
+
+    /// The latest `MAX_CATCH_UP_RUNS` occurrences from `next_run_at` up to `now`,
+    /// oldest first, and how many occurrences there were in total.
+    fn pending_occurrences(schedule: &ScheduledPrompt, cron: &CronSchedule, now: DateTime<Utc>) -> (Vec<DateTime<Utc>>, usize) {
+        let mut occurrences = std::collections::VecDeque::with_capacity(MAX_CATCH_UP_RUNS + 1);
+        let mut total = 0;
+        let mut cursor = schedule.next_run_at;
+
+        while let Some(occurrence) = cursor.filter(|t| *t <= now) {
+            total += 1;
+            occurrences.push_back(occurrence);
+            if occurrences.len() > MAX_CATCH_UP_RUNS {
+                occurrences.pop_front();
+            }
+            cursor = cron.next_after(&occurrence).map(|t| t.with_timezone(&Utc));
+        }
+
+        (occurrences.into(), total)
+    }
+
+    async fn build_prompt(&self, schedule: &ScheduledPrompt, scheduled_for: DateTime<Utc>, own_chat_id: Uuid) -> String {
+        let local_time = scheduled_for.with_timezone(&Local);
+        let mut sections = vec![format!(
+            "Scheduled run \"{}\" for {}.",
+            schedule.name,
+            local_time.format("%A, %Y-%m-%d %H:%M")
+        )];
+
+        for source in &schedule.context_sources {
+            let section = match source {
+                ContextSource::Calendar { days } => self.calendar_context(schedule, local_time, *days).await,
+                ContextSource::RecentChats { hours } => self.recent_chats_context(schedule, scheduled_for, *hours, own_chat_id).await,
+            };
+            sections.push(section);
+        }
+
+        let mut context = sections.join("\n\n");
+        if context.chars().count() > MAX_CONTEXT_CHARS {
+            context = context.chars().take(MAX_CONTEXT_CHARS).collect();
+            context.push_str("\n[...]");
+        }
+
+        if schedule.prompt.is_empty() {
+            context
+        } else {
+            format!("{}\n\n---\n{}", schedule.prompt, context)
+        }
+    }
+
+    async fn calendar_context(&self, schedule: &ScheduledPrompt, local_time: DateTime<Local>, days: i64) -> String {
+        let start = local_time
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .and_then(|t| t.and_local_timezone(Local).earliest())
+            .unwrap_or(local_time)
+            .with_timezone(&Utc);
+        let end = start + Duration::days(days);
+
+        let events = match ListEventsUseCase::new(self.calendar_repo.clone(), self.session_repo.clone())
+            .execute(schedule.user_id)
+            .await
+        {
+            Ok(events) => events,
+            Err(e) => {
+                log::warn!("[Schedule] Calendar unavailable for '{}': {}", schedule.name, e);
+                return format!("## Calendar\n(calendar unavailable: {})", e);
+            }
+        };
+
+        let mut events: Vec<_> = events.into_iter()
+            .filter(|e| e.status != "cancelled" && e.end_at > start && e.start_at < end)
+            .collect();
+        events.sort_by_key(|e| e.start_at);
+
+        let mut lines = vec![format!("## Calendar ({} day(s) from {})", days, start.with_timezone(&Local).format("%Y-%m-%d"))];
+        if events.is_empty() {
+            lines.push("No events.".to_string());
+        }
+        for event in events {
+            let starts = event.start_at.with_timezone(&Local);
+            let ends = event.end_at.with_timezone(&Local);
+            let mut line = format!("- {} {}–{} {}", starts.format("%a %d/%m"), starts.format("%H:%M"), ends.format("%H:%M"), event.title);
+            if let Some(description) = event.description.filter(|d| !d.trim().is_empty()) {
+                line.push_str(&format!(": {}", truncate(&description, MAX_MESSAGE_CHARS)));
+            }
+            lines.push(line);
+        }
+
+        lines.join("\n")
+    }
+
+    async fn recent_chats_context(&self, schedule: &ScheduledPrompt, scheduled_for: DateTime<Utc>, hours: i64, own_chat_id: Uuid) -> String {
+        let since = scheduled_for - Duration::hours(hours);
+        let mut lines = vec![format!("## Chats from the last {} hour(s)", hours)];
+
+        let chats = match self.chat_repo.find_by_user_id(schedule.user_id).await {
+            Ok(chats) => chats,
+            Err(e) => {
+                log::warn!("[Schedule] Failed to load chats for '{}': {}", schedule.name, e);
+                lines.push("(chats unavailable)".to_string());
+                return lines.join("\n");
+            }
+        };
+
+        let mut chats: Vec<_> = chats.into_iter()
+            .filter(|c| !c.is_incognito && c.id != own_chat_id && c.updated_at >= since)
+            .collect();
+        chats.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+
+        if chats.is_empty() {
+            lines.push("No chats.".to_string());
+        }
+
+        for chat in chats.into_iter().take(MAX_RECENT_CHATS) {
+            let messages = match self.message_repo.find_recent_by_chat_id(chat.id, MESSAGES_PER_CHAT).await {
+                Ok(messages) => messages,
+                Err(e) => {
+                    log::warn!("[Schedule] Failed to load messages of chat {}: {}", chat.id, e);
+                    continue;
+                }
+            };
+
+            lines.push(format!("\n### {}", chat.title.as_deref().unwrap_or("Untitled Chat")));
+            for message in messages.iter().filter(|m| m.created_at >= since && m.role != "system") {
+                let content = message.summary.as_deref()
+                    .filter(|s| message.content.chars().count() > MAX_MESSAGE_CHARS && !s.trim().is_empty())
+                    .unwrap_or(&message.content);
+                lines.push(format!("{}: {}", message.role, truncate(content, MAX_MESSAGE_CHARS)));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    async fn send_email(&self, schedule: &ScheduledPrompt, scheduled_for: DateTime<Utc>, content: &str) -> Result<()> {
+        let user = self.user_repo.find_by_id(schedule.user_id).await?
+            .ok_or_else(|| anyhow!("User not found"))?;
+
+        let date = scheduled_for.with_timezone(&Local).format("%Y-%m-%d %H:%M");
+        let subject = format!("{} — {}", schedule.name, date);
+        let html = format!(
+            r#"<!DOCTYPE html>
+<html>
+<head><meta charset="UTF-8"></head>
+<body style="font-family: Arial, sans-serif; line-height: 1.6; color: #333; max-width: 800px; margin: 0 auto; padding: 20px;">
+    <h2>{}</h2>
+    <p style="color: #888;">{}</p>
+    <div style="white-space: pre-wrap;">{}</div>
+</body>
+</html>"#,
+            html_escape(&schedule.name),
+            date,
+            html_escape(content)
+        );
+        let text = format!("{}\n{}\n\n{}", schedule.name, date, content);
+
+        self.email_service.send_basic_email(&user.email, &subject, &html, &text).await
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(max_chars).collect();
+    cut.push('…');
+    cut
+}
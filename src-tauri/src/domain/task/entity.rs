@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    Open,
+    Done,
+    /// Extracted by mistake or no longer relevant; kept so it isn't extracted again
+    Dismissed,
+}
+
+impl TaskStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Open => "open",
+            TaskStatus::Done => "done",
+            TaskStatus::Dismissed => "dismissed",
+        }
+    }
+}
+
+impl std::str::FromStr for TaskStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "open" => Ok(TaskStatus::Open),
+            "done" => Ok(TaskStatus::Done),
+            "dismissed" => Ok(TaskStatus::Dismissed),
+            _ => Err(anyhow::anyhow!("Unknown task status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Task {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// Chat the task was extracted from; `None` for tasks added by hand
+    pub chat_id: Option<Uuid>,
+    pub source_message_id: Option<Uuid>,
+    pub title: String,
+    pub notes: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    /// The due date has no time of day; `due_at` is local midnight of that day
+    pub due_all_day: bool,
+    pub status: TaskStatus,
+    /// Event created when the task was pushed to the calendar
+    pub calendar_event_id: Option<Uuid>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A todo as returned by the model, before it's stored.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExtractedTask {
+    pub title: String,
+    /// `YYYY-MM-DD` or `YYYY-MM-DDTHH:MM`, in the user's local time
+    #[serde(default)]
+    pub due_date: Option<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// Index of the source message in the transcript sent for on-demand extraction
+    #[serde(default)]
+    pub message: Option<usize>,
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use anyhow::Result;
+use super::entity::{Task, TaskStatus};
+
+#[async_trait]
+pub trait TaskRepository: Send + Sync {
+    async fn create(&self, task: &Task) -> Result<Task>;
+    async fn update(&self, task: &Task) -> Result<Task>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Task>>;
+    /// Tasks of the user, optionally with one status; open tasks with the nearest due date first.
+    async fn find_by_user_id(&self, user_id: Uuid, status: Option<TaskStatus>) -> Result<Vec<Task>>;
+    async fn find_by_chat_id(&self, chat_id: Uuid) -> Result<Vec<Task>>;
+    async fn delete(&self, id: Uuid) -> Result<()>;
+}
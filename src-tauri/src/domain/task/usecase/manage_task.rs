@@ -0,0 +1,94 @@
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::task::{
+    entity::{Task, TaskStatus},
+    repository::TaskRepository,
+};
+
+pub struct TaskInput {
+    pub title: String,
+    pub notes: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub due_all_day: bool,
+    pub status: TaskStatus,
+}
+
+pub struct ManageTaskUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+}
+
+impl ManageTaskUseCase {
+    pub fn new(task_repo: Arc<dyn TaskRepository>) -> Self {
+        Self { task_repo }
+    }
+
+    pub async fn add(&self, user_id: Uuid, input: TaskInput) -> Result<Task> {
+        let input = Self::validate(input)?;
+        let now = Utc::now();
+
+        let task = Task {
+            id: Uuid::new_v4(),
+            user_id,
+            chat_id: None,
+            source_message_id: None,
+            title: input.title,
+            notes: input.notes,
+            due_at: input.due_at,
+            due_all_day: input.due_all_day && input.due_at.is_some(),
+            status: input.status,
+            calendar_event_id: None,
+            completed_at: (input.status == TaskStatus::Done).then_some(now),
+            created_at: now,
+            updated_at: now,
+        };
+
+        self.task_repo.create(&task).await
+    }
+
+    pub async fn update(&self, task_id: Uuid, input: TaskInput) -> Result<Task> {
+        let mut task = self.task_repo.find_by_id(task_id).await?
+            .ok_or_else(|| anyhow!("Task not found"))?;
+        let input = Self::validate(input)?;
+
+        task.title = input.title;
+        task.notes = input.notes;
+        task.due_at = input.due_at;
+        task.due_all_day = input.due_all_day && input.due_at.is_some();
+        Self::apply_status(&mut task, input.status);
+        task.updated_at = Utc::now();
+
+        self.task_repo.update(&task).await
+    }
+
+    pub async fn set_status(&self, task_id: Uuid, status: TaskStatus) -> Result<Task> {
+        let mut task = self.task_repo.find_by_id(task_id).await?
+            .ok_or_else(|| anyhow!("Task not found"))?;
+
+        Self::apply_status(&mut task, status);
+        task.updated_at = Utc::now();
+
+        self.task_repo.update(&task).await
+    }
+
+    fn apply_status(task: &mut Task, status: TaskStatus) {
+        if status == TaskStatus::Done && task.status != TaskStatus::Done {
+            task.completed_at = Some(Utc::now());
+        } else if status != TaskStatus::Done {
+            task.completed_at = None;
+        }
+        task.status = status;
+    }
+
+    fn validate(mut input: TaskInput) -> Result<TaskInput> {
+        input.title = input.title.trim().to_string();
+        if input.title.is_empty() {
+            return Err(anyhow!("Task title is required"));
+        }
+        input.notes = input.notes
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty());
+        Ok(input)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod manage_task;
+pub mod record_extracted_tasks;
+pub mod push_to_calendar;
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Duration, Local, Utc};
+use uuid::Uuid;
+use crate::domain::{
+    calendar::{
+        entity::GoogleCalendarEvent,
+        repository::CalendarRepository,
+        usecase::create_event::CreateEventUseCase,
+    },
+    task::{entity::Task, repository::TaskRepository},
+    user::repository::session_repository::SessionRepository,
+};
+
+const DEFAULT_DURATION_MINUTES: i64 = 30;
+/// Longest event a task can become: a week.
+const MAX_DURATION_MINUTES: i64 = 7 * 24 * 60;
+/// Local hour used for tasks due on a day without a time.
+const ALL_DAY_START_HOUR: u32 = 9;
+
+pub struct PushTaskToCalendarUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+    calendar_repo: Arc<dyn CalendarRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+}
+
+impl PushTaskToCalendarUseCase {
+    pub fn new(
+        task_repo: Arc<dyn TaskRepository>,
+        calendar_repo: Arc<dyn CalendarRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+    ) -> Self {
+        Self { task_repo, calendar_repo, session_repo }
+    }
+
+    /// Creates a calendar event for the task. The event starts at `start_at` when
+    /// given, otherwise at the task's due time (9:00 for date-only due dates).
+    pub async fn execute(&self, task_id: Uuid, start_at: Option<DateTime<Utc>>, duration_minutes: Option<i64>) -> Result<(Task, GoogleCalendarEvent)> {
+        let mut task = self.task_repo.find_by_id(task_id).await?
+            .ok_or_else(|| anyhow!("Task not found"))?;
+
+        if let Some(event_id) = task.calendar_event_id {
+            if self.calendar_repo.find_by_id(event_id).await?.is_some() {
+                return Err(anyhow!("Task is already on the calendar"));
+            }
+        }
+
+        let start_at = match (start_at, task.due_at) {
+            (Some(start_at), _) => start_at,
+            (None, Some(due_at)) if task.due_all_day => due_at
+                .with_timezone(&Local)
+                .date_naive()
+                .and_hms_opt(ALL_DAY_START_HOUR, 0, 0)
+                .and_then(|t| t.and_local_timezone(Local).earliest())
+                .map(|t| t.with_timezone(&Utc))
+                .unwrap_or(due_at),
+            (None, Some(due_at)) => due_at,
+            (None, None) => return Err(anyhow!("The task has no due date; pick a time for the event")),
+        };
+        let duration = duration_minutes.unwrap_or(DEFAULT_DURATION_MINUTES);
+        if !(1..=MAX_DURATION_MINUTES).contains(&duration) {
+            return Err(anyhow!("Event duration must be between one minute and {} minutes", MAX_DURATION_MINUTES));
+        }
+        let end_at = start_at.checked_add_signed(Duration::minutes(duration))
+            .ok_or_else(|| anyhow!("Event end is out of range"))?;
+
+        let event = CreateEventUseCase::new(self.calendar_repo.clone(), self.session_repo.clone())
+            .execute(
+                task.user_id,
+                task.title.clone(),
+                task.notes.clone(),
+                start_at,
+                end_at,
+                task.chat_id,
+            )
+            .await?;
+
+        task.calendar_event_id = Some(event.id);
+        task.updated_at = Utc::now();
+        let task = self.task_repo.update(&task).await?;
+
+        Ok((task, event))
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+use anyhow::Result;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc};
+use uuid::Uuid;
+use crate::domain::task::{
+    entity::{ExtractedTask, Task, TaskStatus},
+    repository::TaskRepository,
+};
+
+/// Longest title kept from the model; the rest goes to the notes.
+const MAX_TITLE_CHARS: usize = 200;
+
+/// Stores todos found by the model, skipping the ones the chat already has
+/// (including dismissed ones, so they don't come back on the next pass).
+pub struct RecordExtractedTasksUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+}
+
+impl RecordExtractedTasksUseCase {
+    pub fn new(task_repo: Arc<dyn TaskRepository>) -> Self {
+        Self { task_repo }
+    }
+
+    /// `source_message_ids` maps `ExtractedTask::message` to a message id; tasks
+    /// without an index (or with an unknown one) use `default_message_id`.
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        chat_id: Uuid,
+        default_message_id: Option<Uuid>,
+        source_message_ids: &[Uuid],
+        extracted: Vec<ExtractedTask>,
+    ) -> Result<Vec<Task>> {
+        if extracted.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut known: Vec<String> = self.task_repo.find_by_chat_id(chat_id).await?
+            .iter()
+            .map(|t| normalize_title(&t.title))
+            .collect();
+
+        let mut created = Vec::new();
+        for item in extracted {
+            let title = item.title.trim();
+            if title.is_empty() {
+                continue;
+            }
+            let key = normalize_title(title);
+            if known.contains(&key) {
+                continue;
+            }
+            known.push(key);
+
+            let (title, overflow) = if title.chars().count() > MAX_TITLE_CHARS {
+                (title.chars().take(MAX_TITLE_CHARS).collect::<String>(), Some(title.to_string()))
+            } else {
+                (title.to_string(), None)
+            };
+            let notes = item.notes
+                .map(|n| n.trim().to_string())
+                .filter(|n| !n.is_empty())
+                .or(overflow);
+            let (due_at, due_all_day) = match item.due_date.as_deref().and_then(parse_due_date) {
+                Some((due_at, all_day)) => (Some(due_at), all_day),
+                None => (None, false),
+            };
+            let source_message_id = item.message
+                .and_then(|i| source_message_ids.get(i).copied())
+                .or(default_message_id);
+
+            let now = Utc::now();
+            let task = Task {
+                id: Uuid::new_v4(),
+                user_id,
+                chat_id: Some(chat_id),
+                source_message_id,
+                title,
+                notes,
+                due_at,
+                due_all_day,
+                status: TaskStatus::Open,
+                calendar_event_id: None,
+                completed_at: None,
+                created_at: now,
+                updated_at: now,
+            };
+            created.push(self.task_repo.create(&task).await?);
+        }
+
+        Ok(created)
+    }
+}
+
+/// Parses a due date written by the model in local time. Returns the instant and
+/// whether it was a date without a time of day.
+pub fn parse_due_date(value: &str) -> Option<(DateTime<Utc>, bool)> {
+    let value = value.trim();
+    if value.is_empty() || value.eq_ignore_ascii_case("null") {
+        return None;
+    }
+
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Some((at.with_timezone(&Utc), false));
+    }
+    for format in ["%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(value, format) {
+            return naive.and_local_timezone(Local).earliest().map(|at| (at.with_timezone(&Utc), false));
+        }
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return date.and_hms_opt(0, 0, 0)?
+            .and_local_timezone(Local)
+            .earliest()
+            .map(|at| (at.with_timezone(&Utc), true));
+    }
+
+    None
+}
+
+fn normalize_title(title: &str) -> String {
+    title.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(['.', '!'])
+        .to_lowercase()
+}
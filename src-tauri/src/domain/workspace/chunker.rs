@@ -0,0 +1,241 @@
+use crate::domain::document::chunker::chunk_text;
+
+/// A slice of a source file, ideally covering one symbol (function, type, section).
+#[derive(Debug, Clone)]
+pub struct CodeChunk {
+    pub symbol: Option<String>,
+    pub content: String,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+const MAX_CHUNK_LINES: usize = 80;
+const MAX_CHUNK_CHARS: usize = 3000;
+// Small neighbouring symbols (one-line consts, short helpers) are kept together
+const MIN_CHUNK_LINES: usize = 6;
+
+// Symbols are only detected at shallow indentation (top level or one level of impl/class)
+const MAX_SYMBOL_INDENT: usize = 4;
+
+const SKIPPED_FILE_NAMES: &[&str] = &[
+    "package-lock.json", "yarn.lock", "pnpm-lock.yaml", "Cargo.lock", "poetry.lock", "go.sum",
+];
+
+/// Returns the language name for files worth indexing, or `None` to skip the file.
+pub fn language_for(relative_path: &str) -> Option<&'static str> {
+    let file_name = relative_path.rsplit('/').next().unwrap_or(relative_path);
+    if SKIPPED_FILE_NAMES.contains(&file_name) || file_name.contains(".min.") {
+        return None;
+    }
+
+    let extension = file_name.rsplit_once('.').map(|(_, ext)| ext.to_lowercase())?;
+    let language = match extension.as_str() {
+        "rs" => "rust",
+        "ts" | "tsx" | "mts" | "cts" => "typescript",
+        "js" | "jsx" | "mjs" | "cjs" => "javascript",
+        "py" => "python",
+        "go" => "go",
+        "java" => "java",
+        "kt" | "kts" => "kotlin",
+        "swift" => "swift",
+        "c" | "h" => "c",
+        "cc" | "cpp" | "cxx" | "hpp" | "hh" => "cpp",
+        "cs" => "csharp",
+        "rb" => "ruby",
+        "php" => "php",
+        "scala" => "scala",
+        "dart" => "dart",
+        "lua" => "lua",
+        "sh" | "bash" | "zsh" => "shell",
+        "sql" => "sql",
+        "vue" => "vue",
+        "svelte" => "svelte",
+        "css" | "scss" => "css",
+        "html" => "html",
+        "md" | "markdown" | "mdx" => "markdown",
+        "toml" => "toml",
+        "yaml" | "yml" => "yaml",
+        "json" => "json",
+        _ => return None,
+    };
+
+    Some(language)
+}
+
+/// Splits a source file at symbol boundaries, keeping leading doc comments and
+/// attributes with the symbol they describe. Markdown is split by section.
+pub fn chunk_source(text: &str, language: &str) -> Vec<CodeChunk> {
+    if language == "markdown" {
+        return chunk_text(text)
+            .into_iter()
+            .map(|c| CodeChunk {
+                symbol: c.content.lines().next()
+                    .filter(|l| l.starts_with('#'))
+                    .map(|l| l.trim_start_matches('#').trim().to_string()),
+                content: c.content,
+                start_line: c.start_line,
+                end_line: c.end_line,
+            })
+            .collect();
+    }
+
+    let lines: Vec<&str> = text.lines().collect();
+    let mut chunks = Vec::new();
+    let mut start = 0; // index into `lines` where the current chunk begins
+    let mut symbol: Option<String> = None;
+    let mut chars = 0;
+
+    for (i, line) in lines.iter().enumerate() {
+        let new_symbol = detect_symbol(line, language);
+        let length = i - start;
+
+        if length > 0 {
+            let at_boundary = new_symbol.is_some() && length >= MIN_CHUNK_LINES;
+            let too_big = length >= MAX_CHUNK_LINES || chars + line.len() > MAX_CHUNK_CHARS;
+
+            if at_boundary || too_big {
+                // Leave doc comments/attributes right above a symbol for the next chunk
+                let mut split = if at_boundary { leading_comment_start(&lines, start, i) } else { i };
+                if split == start {
+                    split = i;
+                }
+
+                // A symbol cut for size continues in the next chunk under the same name
+                let carried = if at_boundary { None } else { symbol.clone() };
+                push_chunk(&mut chunks, &lines, start, split, symbol.take());
+                symbol = carried;
+                chars = lines[split..i].iter().map(|l| l.len() + 1).sum();
+                start = split;
+            }
+        }
+
+        if symbol.is_none() {
+            symbol = new_symbol;
+        }
+        chars += line.len() + 1;
+    }
+
+    if start < lines.len() {
+        push_chunk(&mut chunks, &lines, start, lines.len(), symbol);
+    }
+
+    chunks
+}
+
+fn push_chunk(chunks: &mut Vec<CodeChunk>, lines: &[&str], start: usize, end: usize, symbol: Option<String>) {
+    let content = lines[start..end].join("\n");
+    if content.trim().is_empty() {
+        return;
+    }
+
+    chunks.push(CodeChunk {
+        symbol,
+        content,
+        start_line: start + 1,
+        end_line: end,
+    });
+}
+
+/// Walks back from `index` over comment/attribute lines directly above it.
+fn leading_comment_start(lines: &[&str], floor: usize, index: usize) -> usize {
+    let mut i = index;
+    while i > floor {
+        let prev = lines[i - 1].trim_start();
+        let is_leading = prev.starts_with("//")
+            || prev.starts_with("/*")
+            || prev.starts_with('*')
+            || prev.starts_with("#[")
+            || prev.starts_with('@')
+            || prev.starts_with("\"\"\"");
+        if !is_leading {
+            break;
+        }
+        i -= 1;
+    }
+    i
+}
+
+fn detect_symbol(line: &str, language: &str) -> Option<String> {
+    let indent = line.len() - line.trim_start().len();
+    if indent > MAX_SYMBOL_INDENT || line.trim().is_empty() {
+        return None;
+    }
+
+    const MODIFIERS: &[&str] = &[
+        "pub", "pub(crate)", "pub(super)", "async", "unsafe", "const", "extern", "export",
+        "default", "declare", "abstract", "public", "private", "protected", "static",
+        "final", "override", "open", "internal", "sealed", "data", "inline", "\"C\"",
+    ];
+
+    let keywords: &[&str] = match language {
+        "rust" => &["fn", "struct", "enum", "trait", "impl", "mod", "type", "macro_rules!"],
+        "typescript" | "javascript" | "vue" | "svelte" => &["function", "function*", "class", "interface", "type", "enum", "const", "let"],
+        "python" => &["def", "class"],
+        "go" => &["func", "type"],
+        "java" | "kotlin" | "scala" | "csharp" | "swift" | "dart" => &["class", "interface", "enum", "fun", "func", "def", "object", "struct", "record", "trait"],
+        "ruby" => &["def", "class", "module"],
+        "php" => &["function", "class", "interface", "trait"],
+        "lua" => &["function", "local"],
+        "shell" => &["function"],
+        "sql" => &["create"],
+        _ => return None,
+    };
+
+    let mut tokens = line.split_whitespace().peekable();
+    while let Some(&token) = tokens.peek() {
+        // `const`/`let` are keywords for JS/TS (`const handler = () => ...`), modifiers elsewhere
+        let is_js_binding = (token == "const" || token == "let") && keywords.contains(&token);
+        if MODIFIERS.contains(&token) && !is_js_binding {
+            tokens.next();
+        } else {
+            break;
+        }
+    }
+
+    let keyword = tokens.next()?;
+    let keyword_lower = keyword.to_lowercase();
+    if !keywords.contains(&keyword_lower.as_str()) {
+        return None;
+    }
+
+    if keyword == "impl" {
+        let rest = line.trim().trim_end_matches('{').trim();
+        return Some(rest.to_string());
+    }
+
+    // JS/TS bindings only count when they define a function or component
+    if (keyword == "const" || keyword == "let") && !(line.contains("=>") || line.contains("function")) {
+        return None;
+    }
+
+    if keyword == "local" && !line.contains("function") {
+        return None;
+    }
+
+    const NAME_NOISE: &[&str] = &[
+        "function", "table", "index", "view", "trigger", "virtual", "unique", "or",
+        "replace", "if", "not", "exists", "procedure",
+    ];
+    let mut rest = tokens.skip_while(|t| NAME_NOISE.contains(&t.to_lowercase().as_str()));
+    let mut candidate = rest.next()?;
+
+    // Go methods: `func (r *Repo) Name(...)`
+    if candidate.starts_with('(') {
+        if !candidate.ends_with(')') {
+            rest.find(|t| t.ends_with(')'))?;
+        }
+        candidate = rest.next()?;
+    }
+
+    let name: String = candidate
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$' || *c == '.' || *c == ':')
+        .collect();
+
+    let name = name.trim_end_matches(':').to_string();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
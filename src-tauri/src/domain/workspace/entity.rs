@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::domain::document::entity::DocumentCitation;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub root_path: String,
+    pub last_indexed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceFile {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub relative_path: String,
+    pub language: String,
+    pub mtime: i64,
+    pub content_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceChunk {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub file_id: Uuid,
+    pub symbol: Option<String>,
+    pub content: String,
+    pub start_line: i64,
+    pub end_line: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceChunkMatch {
+    pub chunk: WorkspaceChunk,
+    pub relative_path: String,
+}
+
+impl WorkspaceChunkMatch {
+    pub fn citation(&self) -> DocumentCitation {
+        DocumentCitation {
+            source: "workspace".to_string(),
+            source_id: self.chunk.workspace_id.to_string(),
+            file_name: self.relative_path.rsplit('/').next().unwrap_or(&self.relative_path).to_string(),
+            file_path: self.relative_path.clone(),
+            start_line: self.chunk.start_line,
+            end_line: self.chunk.end_line,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IndexReport {
+    pub files_indexed: usize,
+    pub files_unchanged: usize,
+    pub files_removed: usize,
+    pub chunks_written: usize,
+}
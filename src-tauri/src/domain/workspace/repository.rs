@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use super::entity::{Workspace, WorkspaceChunk, WorkspaceChunkMatch, WorkspaceFile};
+
+#[async_trait]
+pub trait WorkspaceRepository: Send + Sync {
+    async fn create(&self, workspace: &Workspace) -> Result<Workspace>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Workspace>>;
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Workspace>>;
+    async fn find_all(&self) -> Result<Vec<Workspace>>;
+    async fn set_last_indexed_at(&self, id: Uuid, at: DateTime<Utc>) -> Result<()>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool>;
+
+    async fn find_files(&self, workspace_id: Uuid) -> Result<Vec<WorkspaceFile>>;
+    /// Inserts or updates a file row. When `chunks` is `Some`, the file's chunks are replaced.
+    async fn upsert_file(&self, file: &WorkspaceFile, chunks: Option<&[WorkspaceChunk]>) -> Result<()>;
+    async fn delete_file(&self, file_id: Uuid) -> Result<()>;
+
+    async fn search_chunks(&self, workspace_id: Uuid, query: &str, limit: i64) -> Result<Vec<WorkspaceChunkMatch>>;
+}
@@ -0,0 +1,204 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::UNIX_EPOCH;
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use lazy_static::lazy_static;
+use crate::domain::workspace::{
+    chunker::{chunk_source, language_for},
+    entity::{IndexReport, WorkspaceChunk, WorkspaceFile},
+    repository::WorkspaceRepository,
+};
+use crate::infrastructure::workspace::file_walker::list_files;
+
+// Source files larger than this are almost always generated
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+lazy_static! {
+    // Workspaces currently being indexed, so a manual reindex and the background
+    // refresh never walk the same tree at the same time
+    static ref INDEXING: Mutex<HashSet<Uuid>> = Mutex::new(HashSet::new());
+}
+
+/// Marks a workspace as being indexed until dropped, so a cancelled or
+/// panicking run doesn't leave it marked.
+struct IndexingGuard(Uuid);
+
+impl IndexingGuard {
+    fn acquire(workspace_id: Uuid) -> Result<Self> {
+        if !INDEXING.lock().unwrap_or_else(|e| e.into_inner()).insert(workspace_id) {
+            return Err(anyhow!("Workspace is already being indexed"));
+        }
+        Ok(Self(workspace_id))
+    }
+}
+
+impl Drop for IndexingGuard {
+    fn drop(&mut self) {
+        INDEXING.lock().unwrap_or_else(|e| e.into_inner()).remove(&self.0);
+    }
+}
+
+enum FileChange {
+    Unchanged,
+    /// Modification time moved but the content is identical
+    Touched(WorkspaceFile),
+    Changed(WorkspaceFile, Vec<WorkspaceChunk>),
+}
+
+pub struct IndexWorkspaceUseCase {
+    workspace_repo: Arc<dyn WorkspaceRepository>,
+}
+
+impl IndexWorkspaceUseCase {
+    pub fn new(workspace_repo: Arc<dyn WorkspaceRepository>) -> Self {
+        Self { workspace_repo }
+    }
+
+    /// Incrementally indexes a workspace: only files whose content changed since the
+    /// last run are re-chunked, and files that disappeared are dropped.
+    pub async fn execute(&self, workspace_id: Uuid) -> Result<IndexReport> {
+        let _guard = IndexingGuard::acquire(workspace_id)?;
+        self.index(workspace_id).await
+    }
+
+    /// Refreshes every registered workspace. Used by the background indexer.
+    pub async fn refresh_all(&self) -> Result<()> {
+        for workspace in self.workspace_repo.find_all().await? {
+            if INDEXING.lock().unwrap_or_else(|e| e.into_inner()).contains(&workspace.id) {
+                continue;
+            }
+
+            match self.execute(workspace.id).await {
+                Ok(report) if report.files_indexed > 0 || report.files_removed > 0 => {
+                    log::info!("[Workspace] Refreshed {}: {:?}", workspace.name, report);
+                }
+                Ok(_) => {}
+                Err(e) => log::warn!("[Workspace] Failed to refresh {}: {}", workspace.name, e),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn index(&self, workspace_id: Uuid) -> Result<IndexReport> {
+        let workspace = self.workspace_repo.find_by_id(workspace_id).await?
+            .ok_or_else(|| anyhow!("Workspace not found"))?;
+
+        let existing: HashMap<String, WorkspaceFile> = self.workspace_repo
+            .find_files(workspace_id).await?
+            .into_iter()
+            .map(|f| (f.relative_path.clone(), f))
+            .collect();
+
+        let root = PathBuf::from(&workspace.root_path);
+        let known = existing.clone();
+
+        // Walking and hashing a large tree is blocking work
+        let (changes, seen) = tokio::task::spawn_blocking(move || scan(&root, workspace_id, &known)).await??;
+
+        let mut report = IndexReport::default();
+        for change in changes {
+            match change {
+                FileChange::Unchanged => report.files_unchanged += 1,
+                FileChange::Touched(file) => {
+                    self.workspace_repo.upsert_file(&file, None).await?;
+                    report.files_unchanged += 1;
+                }
+                FileChange::Changed(file, chunks) => {
+                    self.workspace_repo.upsert_file(&file, Some(&chunks)).await?;
+                    report.files_indexed += 1;
+                    report.chunks_written += chunks.len();
+                }
+            }
+        }
+
+        for (path, file) in &existing {
+            if !seen.contains(path) {
+                self.workspace_repo.delete_file(file.id).await?;
+                report.files_removed += 1;
+            }
+        }
+
+        self.workspace_repo.set_last_indexed_at(workspace_id, Utc::now()).await?;
+
+        Ok(report)
+    }
+}
+
+fn scan(
+    root: &Path,
+    workspace_id: Uuid,
+    existing: &HashMap<String, WorkspaceFile>,
+) -> Result<(Vec<FileChange>, HashSet<String>)> {
+    let mut changes = Vec::new();
+    let mut seen = HashSet::new();
+
+    for relative_path in list_files(root)? {
+        let Some(language) = language_for(&relative_path) else {
+            continue;
+        };
+
+        let full_path = root.join(&relative_path);
+        let metadata = match fs::metadata(&full_path) {
+            Ok(m) if m.is_file() && m.len() <= MAX_FILE_SIZE => m,
+            _ => continue,
+        };
+
+        let mtime = metadata.modified().ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+
+        let previous = existing.get(&relative_path);
+        if previous.is_some_and(|f| f.mtime == mtime) {
+            seen.insert(relative_path);
+            changes.push(FileChange::Unchanged);
+            continue;
+        }
+
+        // Skip binaries and non UTF-8 files
+        let text = match fs::read(&full_path).ok().and_then(|b| String::from_utf8(b).ok()) {
+            Some(t) if !t.contains('\0') => t,
+            _ => continue,
+        };
+
+        let content_hash = format!("{:x}", Sha256::digest(text.as_bytes()));
+        let file = WorkspaceFile {
+            id: previous.map(|f| f.id).unwrap_or_else(Uuid::new_v4),
+            workspace_id,
+            relative_path: relative_path.clone(),
+            language: language.to_string(),
+            mtime,
+            content_hash,
+        };
+
+        seen.insert(relative_path);
+
+        if previous.is_some_and(|f| f.content_hash == file.content_hash) {
+            changes.push(FileChange::Touched(file));
+            continue;
+        }
+
+        let chunks = chunk_source(&text, language)
+            .into_iter()
+            .map(|c| WorkspaceChunk {
+                id: Uuid::new_v4(),
+                workspace_id,
+                file_id: file.id,
+                symbol: c.symbol,
+                content: c.content,
+                start_line: c.start_line as i64,
+                end_line: c.end_line as i64,
+            })
+            .collect();
+
+        changes.push(FileChange::Changed(file, chunks));
+    }
+
+    Ok((changes, seen))
+}
@@ -0,0 +1,2 @@
+pub mod sqlite_repository;
+pub mod sqlite_log_repository;
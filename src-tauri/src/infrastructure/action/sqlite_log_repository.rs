@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::domain::action::{
+    entity::ActionLogEntry,
+    repository::ActionLogRepository,
+};
+
+pub struct SqliteActionLogRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteActionLogRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn parse_uuid(value: &str) -> Result<Uuid, sqlx::Error> {
+        Uuid::parse_str(value).map_err(|e| sqlx::Error::Decode(Box::new(e)))
+    }
+
+    fn map_entry(row: sqlx::sqlite::SqliteRow) -> Result<ActionLogEntry, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let user_id_str: String = row.get("user_id");
+        let chat_id_str: String = row.get("chat_id");
+        let message_id_str: String = row.get("message_id");
+        let action_id_str: Option<String> = row.get("action_id");
+        let arguments_str: String = row.get("arguments");
+        let undo_handle_str: Option<String> = row.get("undo_handle");
+
+        Ok(ActionLogEntry {
+            id: Self::parse_uuid(&id_str)?,
+            user_id: Self::parse_uuid(&user_id_str)?,
+            chat_id: Self::parse_uuid(&chat_id_str)?,
+            message_id: Self::parse_uuid(&message_id_str)?,
+            action_id: action_id_str.as_deref().map(Self::parse_uuid).transpose()?,
+            tool: row.get("tool"),
+            arguments: serde_json::from_str(&arguments_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            result: row.get("result"),
+            succeeded: row.get("succeeded"),
+            undo_handle: undo_handle_str
+                .map(|s| serde_json::from_str(&s))
+                .transpose()
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            undone_at: row.get("undone_at"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl ActionLogRepository for SqliteActionLogRepository {
+    async fn create(&self, entry: &ActionLogEntry) -> Result<ActionLogEntry> {
+        let undo_handle = entry.undo_handle.as_ref().map(serde_json::to_string).transpose()?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO action_log (id, user_id, chat_id, message_id, action_id, tool, arguments, result, succeeded, undo_handle, undone_at, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+            "#
+        )
+        .bind(entry.id.to_string())
+        .bind(entry.user_id.to_string())
+        .bind(entry.chat_id.to_string())
+        .bind(entry.message_id.to_string())
+        .bind(entry.action_id.map(|id| id.to_string()))
+        .bind(entry.tool.clone())
+        .bind(entry.arguments.to_string())
+        .bind(entry.result.clone())
+        .bind(entry.succeeded)
+        .bind(undo_handle)
+        .bind(entry.undone_at)
+        .bind(entry.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save action log entry: {}", e))?;
+
+        Ok(entry.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ActionLogEntry>> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, user_id, chat_id, message_id, action_id, tool, arguments, result, succeeded, undo_handle, undone_at, created_at
+            FROM action_log
+            WHERE id = ?1
+            "#
+        )
+        .bind(id.to_string())
+        .try_map(Self::map_entry)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid, chat_id: Option<Uuid>, limit: i64, offset: i64) -> Result<Vec<ActionLogEntry>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, user_id, chat_id, message_id, action_id, tool, arguments, result, succeeded, undo_handle, undone_at, created_at
+            FROM action_log
+            WHERE user_id = ?1 AND (?2 IS NULL OR chat_id = ?2)
+            ORDER BY created_at DESC
+            LIMIT ?3 OFFSET ?4
+            "#
+        )
+        .bind(user_id.to_string())
+        .bind(chat_id.map(|id| id.to_string()))
+        .bind(limit)
+        .bind(offset)
+        .try_map(Self::map_entry)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn mark_undone(&self, id: Uuid, at: DateTime<Utc>) -> Result<bool> {
+        let updated = sqlx::query("UPDATE action_log SET undone_at = ?2 WHERE id = ?1 AND undone_at IS NULL")
+            .bind(id.to_string())
+            .bind(at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to update action log entry: {}", e))?
+            .rows_affected();
+
+        Ok(updated == 1)
+    }
+
+    async fn clear_undone(&self, id: Uuid) -> Result<()> {
+        sqlx::query("UPDATE action_log SET undone_at = NULL WHERE id = ?1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to update action log entry: {}", e))?;
+
+        Ok(())
+    }
+}
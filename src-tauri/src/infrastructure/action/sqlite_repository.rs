@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use crate::domain::action::{
+    entity::{ActionStatus, ProposedAction},
+    repository::ProposedActionRepository,
+};
+
+pub struct SqliteProposedActionRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteProposedActionRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn parse_uuid(value: &str) -> Result<Uuid, sqlx::Error> {
+        Uuid::parse_str(value).map_err(|e| sqlx::Error::Decode(Box::new(e)))
+    }
+
+    fn map_action(row: sqlx::sqlite::SqliteRow) -> Result<ProposedAction, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let message_id_str: String = row.get("message_id");
+        let chat_id_str: String = row.get("chat_id");
+        let user_id_str: String = row.get("user_id");
+        let arguments_str: String = row.get("arguments");
+        let status_str: String = row.get("status");
+
+        Ok(ProposedAction {
+            id: Self::parse_uuid(&id_str)?,
+            message_id: Self::parse_uuid(&message_id_str)?,
+            chat_id: Self::parse_uuid(&chat_id_str)?,
+            user_id: Self::parse_uuid(&user_id_str)?,
+            tool: row.get("tool"),
+            title: row.get("title"),
+            arguments: serde_json::from_str(&arguments_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            status: status_str.parse().map_err(|e: anyhow::Error| sqlx::Error::Decode(e.into()))?,
+            result: row.get("result"),
+            provider_name: row.get("provider_name"),
+            model: row.get("model"),
+            created_at: row.get("created_at"),
+            resolved_at: row.get("resolved_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl ProposedActionRepository for SqliteProposedActionRepository {
+    async fn create(&self, action: &ProposedAction) -> Result<ProposedAction> {
+        sqlx::query(
+            r#"
+            INSERT INTO proposed_actions (id, message_id, chat_id, user_id, tool, title, arguments, status, result, provider_name, model, created_at, resolved_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+            "#
+        )
+        .bind(action.id.to_string())
+        .bind(action.message_id.to_string())
+        .bind(action.chat_id.to_string())
+        .bind(action.user_id.to_string())
+        .bind(action.tool.clone())
+        .bind(action.title.clone())
+        .bind(action.arguments.to_string())
+        .bind(action.status.as_str())
+        .bind(action.result.clone())
+        .bind(action.provider_name.clone())
+        .bind(action.model.clone())
+        .bind(action.created_at)
+        .bind(action.resolved_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save proposed action: {}", e))?;
+
+        Ok(action.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ProposedAction>> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, message_id, chat_id, user_id, tool, title, arguments, status, result, provider_name, model, created_at, resolved_at
+            FROM proposed_actions
+            WHERE id = ?1
+            "#
+        )
+        .bind(id.to_string())
+        .try_map(Self::map_action)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    async fn find_by_chat_id(&self, chat_id: Uuid) -> Result<Vec<ProposedAction>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, message_id, chat_id, user_id, tool, title, arguments, status, result, provider_name, model, created_at, resolved_at
+            FROM proposed_actions
+            WHERE chat_id = ?1
+            ORDER BY created_at ASC
+            "#
+        )
+        .bind(chat_id.to_string())
+        .try_map(Self::map_action)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn update(&self, action: &ProposedAction) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE proposed_actions
+            SET title = ?2, arguments = ?3, status = ?4, result = ?5, resolved_at = ?6
+            WHERE id = ?1
+            "#
+        )
+        .bind(action.id.to_string())
+        .bind(action.title.clone())
+        .bind(action.arguments.to_string())
+        .bind(action.status.as_str())
+        .bind(action.result.clone())
+        .bind(action.resolved_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to update proposed action: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn claim_pending(&self, id: Uuid, status: ActionStatus) -> Result<bool> {
+        let updated = sqlx::query("UPDATE proposed_actions SET status = ?2 WHERE id = ?1 AND status = 'pending'")
+            .bind(id.to_string())
+            .bind(status.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to update proposed action: {}", e))?
+            .rows_affected();
+
+        Ok(updated == 1)
+    }
+}
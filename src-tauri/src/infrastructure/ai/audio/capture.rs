@@ -0,0 +1,372 @@
+use std::sync::{Arc, Mutex};
+use anyhow::{Result, anyhow};
+use cpal::traits::{DeviceTrait, HostTrait};
+use crate::domain::ai::audio::audio_input::{
+    AudioBuffer, AudioDevice, AudioDeviceConfig, AudioDeviceKind, i16_to_f32, u16_to_f32,
+};
+
+/// Name fragments of devices that record an output rather than a microphone.
+const MONITOR_MARKERS: &[&str] = &[
+    "monitor", "stereo mix", "loopback", "what u hear", "blackhole", "soundflower", "background music",
+];
+
+/// A running input stream whose audio is collected as mono samples in `buffer`.
+/// The stream stops when this is dropped; it must stay on the thread that opened it.
+pub struct CaptureStream {
+    pub source: CaptureSource,
+    pub buffer: Arc<Mutex<AudioBuffer>>,
+    pub device_name: String,
+}
+
+/// What feeds a [`CaptureStream`]; it's only held so that dropping it stops the capture.
+#[allow(dead_code)]
+pub enum CaptureSource {
+    Cpal(cpal::Stream),
+    /// A `parec` recording of a PulseAudio/PipeWire monitor source.
+    #[cfg(target_os = "linux")]
+    Process(linux::MonitorRecorder),
+}
+
+impl CaptureStream {
+    pub fn sample_rate(&self) -> u32 {
+        self.buffer.lock().map(|b| b.sample_rate).unwrap_or(0)
+    }
+
+    /// Takes the samples captured since the last call.
+    pub fn drain(&self) -> Vec<f32> {
+        self.buffer.lock()
+            .map(|mut b| std::mem::take(&mut b.samples))
+            .unwrap_or_default()
+    }
+}
+
+/// Input and monitor devices with the formats they support.
+pub fn list_devices(host: &cpal::Host) -> Result<Vec<AudioDevice>> {
+    let default_input = host.default_input_device().and_then(|d| d.name().ok());
+    let mut devices: Vec<AudioDevice> = Vec::new();
+
+    let inputs = host.input_devices()
+        .map_err(|e| anyhow!("Failed to list input devices: {}", e))?;
+    for device in inputs {
+        let Ok(name) = device.name() else { continue };
+        if devices.iter().any(|d| d.name == name) {
+            continue;
+        }
+        let configs = device.supported_input_configs()
+            .map(|configs| configs.map(|c| device_config(&c)).collect())
+            .unwrap_or_default();
+        devices.push(AudioDevice {
+            kind: if is_monitor_name(&name) { AudioDeviceKind::Monitor } else { AudioDeviceKind::Input },
+            is_default: default_input.as_deref() == Some(name.as_str()),
+            name,
+            configs,
+        });
+    }
+
+    // Any output can be recorded through WASAPI loopback
+    #[cfg(target_os = "windows")]
+    {
+        let default_output = host.default_output_device().and_then(|d| d.name().ok());
+        if let Ok(outputs) = host.output_devices() {
+            for device in outputs {
+                let Ok(name) = device.name() else { continue };
+                let configs = device.supported_output_configs()
+                    .map(|configs| configs.map(|c| device_config(&c)).collect())
+                    .unwrap_or_default();
+                devices.push(AudioDevice {
+                    kind: AudioDeviceKind::Monitor,
+                    is_default: default_output.as_deref() == Some(name.as_str()),
+                    name,
+                    configs,
+                });
+            }
+        }
+    }
+
+    // Pulse monitors aren't ALSA devices; show the one system capture will use
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(monitor) = linux::default_monitor_source() {
+            devices.push(AudioDevice {
+                name: monitor,
+                kind: AudioDeviceKind::Monitor,
+                is_default: true,
+                configs: Vec::new(),
+            });
+        }
+    }
+
+    Ok(devices)
+}
+
+/// Names of the input devices, cheap enough to poll for hotplug.
+#[cfg(not(target_os = "linux"))]
+pub fn input_device_names(host: &cpal::Host) -> Vec<String> {
+    host.input_devices()
+        .map(|devices| devices.filter_map(|d| d.name().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Names of the input devices, cheap enough to poll for hotplug. cpal's ALSA
+/// enumeration opens every PCM device, so the hints are read directly instead;
+/// devices that can't be opened are listed too, which is fine for noticing changes.
+#[cfg(target_os = "linux")]
+pub fn input_device_names(_host: &cpal::Host) -> Vec<String> {
+    use alsa::{Direction, device_name::HintIter};
+
+    HintIter::new_str(None, "pcm")
+        .map(|hints| {
+            hints
+                .filter(|hint| hint.direction != Some(Direction::Playback))
+                .filter_map(|hint| hint.name)
+                .filter(|name| name != "null")
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The input device called `preferred`, or the default one when it's unset or
+/// no longer connected.
+pub fn select_input_device(host: &cpal::Host, preferred: Option<&str>) -> Result<cpal::Device> {
+    if let Some(preferred) = preferred {
+        let found = host.input_devices()
+            .map_err(|e| anyhow!("Failed to list input devices: {}", e))?
+            .find(|d| d.name().map(|name| name == preferred).unwrap_or(false));
+        match found {
+            Some(device) => return Ok(device),
+            None => log::warn!("[Audio] Input device '{}' not found, using the default", preferred),
+        }
+    }
+
+    host.default_input_device()
+        .ok_or_else(|| anyhow!("No input device available"))
+}
+
+/// Opens the preferred input device, falling back to the default one.
+pub fn open_microphone(host: &cpal::Host, preferred: Option<&str>) -> Result<CaptureStream> {
+    let device = select_input_device(host, preferred)?;
+    let config = device.default_input_config()
+        .map_err(|e| anyhow!("Config error: {:?}", e))?;
+    capture_mono(&device, config)
+}
+
+/// Opens what the computer is playing.
+///
+/// - Linux: the monitor source of the default PulseAudio/PipeWire sink, recorded
+///   with `parec`.
+/// - Windows: WASAPI loopback on the default output device.
+/// - macOS: a loopback driver (BlackHole, Soundflower, Loopback), since the OS
+///   has no built-in way to record its output.
+///
+/// Falls back to any input device whose name says it is a monitor.
+pub fn open_system_audio(host: &cpal::Host) -> Result<CaptureStream> {
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(monitor) = linux::default_monitor_source() {
+            match linux::open_monitor(&monitor) {
+                Ok(capture) => return Ok(capture),
+                Err(e) => log::warn!("[Audio] Failed to open monitor source {}: {}", monitor, e),
+            }
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        if let Some(device) = host.default_output_device() {
+            // WASAPI records an output device's mix when it's opened as an input
+            let config = device.default_output_config()
+                .map_err(|e| anyhow!("Config error: {:?}", e))?;
+            return capture_mono(&device, config);
+        }
+    }
+
+    let device = host.input_devices()
+        .map_err(|e| anyhow!("Failed to list input devices: {}", e))?
+        .find(|d| d.name().map(|name| is_monitor_name(&name)).unwrap_or(false))
+        .ok_or_else(|| {
+            if cfg!(target_os = "macos") {
+                anyhow!("System audio capture on macOS needs a loopback driver such as BlackHole")
+            } else {
+                anyhow!("No system audio (monitor/loopback) source found")
+            }
+        })?;
+    let config = device.default_input_config()
+        .map_err(|e| anyhow!("Config error: {:?}", e))?;
+    capture_mono(&device, config)
+}
+
+fn is_monitor_name(name: &str) -> bool {
+    let name = name.to_lowercase();
+    MONITOR_MARKERS.iter().any(|m| name.contains(m))
+}
+
+fn device_config(config: &cpal::SupportedStreamConfigRange) -> AudioDeviceConfig {
+    AudioDeviceConfig {
+        channels: config.channels(),
+        min_sample_rate: config.min_sample_rate().0,
+        max_sample_rate: config.max_sample_rate().0,
+        sample_format: format!("{:?}", config.sample_format()).to_lowercase(),
+    }
+}
+
+/// Starts `device` and downmixes whatever format it delivers into a mono buffer.
+fn capture_mono(device: &cpal::Device, config: cpal::SupportedStreamConfig) -> Result<CaptureStream> {
+    use cpal::traits::StreamTrait;
+
+    let channels = config.channels();
+    let buffer = Arc::new(Mutex::new(AudioBuffer::new(config.sample_rate().0)));
+    let stream_config: cpal::StreamConfig = config.clone().into();
+    let err_fn = |err| log::error!("[Audio] A stream error occurred: {}", err);
+
+    let sink = buffer.clone();
+    let stream = match config.sample_format() {
+        cpal::SampleFormat::F32 => device.build_input_stream(
+            &stream_config,
+            move |data: &[f32], _: &_| {
+                if let Ok(mut buffer) = sink.lock() {
+                    buffer.push_interleaved(data, channels);
+                }
+            },
+            err_fn,
+            None,
+        ),
+        cpal::SampleFormat::I16 => device.build_input_stream(
+            &stream_config,
+            move |data: &[i16], _: &_| {
+                let samples: Vec<f32> = data.iter().map(|&s| i16_to_f32(s)).collect();
+                if let Ok(mut buffer) = sink.lock() {
+                    buffer.push_interleaved(&samples, channels);
+                }
+            },
+            err_fn,
+            None,
+        ),
+        cpal::SampleFormat::U16 => device.build_input_stream(
+            &stream_config,
+            move |data: &[u16], _: &_| {
+                let samples: Vec<f32> = data.iter().map(|&s| u16_to_f32(s)).collect();
+                if let Ok(mut buffer) = sink.lock() {
+                    buffer.push_interleaved(&samples, channels);
+                }
+            },
+            err_fn,
+            None,
+        ),
+        other => return Err(anyhow!("Unsupported sample format: {:?}", other)),
+    }
+    .map_err(|e| anyhow!("Failed to build input stream: {:?}", e))?;
+
+    stream.play().map_err(|e| anyhow!("Failed to play stream: {:?}", e))?;
+
+    Ok(CaptureStream {
+        source: CaptureSource::Cpal(stream),
+        buffer,
+        device_name: device.name().unwrap_or_else(|_| "unknown".to_string()),
+    })
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::io::Read;
+    use std::process::{Child, Command, Stdio};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use anyhow::{Result, anyhow};
+    use crate::domain::ai::audio::audio_input::AudioBuffer;
+    use super::{CaptureSource, CaptureStream};
+
+    /// `<default sink>.monitor`, asked from `pactl` (PulseAudio or pipewire-pulse).
+    pub fn default_monitor_source() -> Option<String> {
+        let output = Command::new("pactl").arg("get-default-sink").output().ok()
+            .filter(|o| o.status.success());
+        let sink = match output {
+            Some(output) => String::from_utf8_lossy(&output.stdout).trim().to_string(),
+            // Older pactl versions have no get-default-sink
+            None => {
+                let output = Command::new("pactl").arg("info").output().ok()?;
+                String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Default Sink:"))
+                    .map(|sink| sink.trim().to_string())?
+            }
+        };
+
+        (!sink.is_empty()).then(|| format!("{}.monitor", sink))
+    }
+
+    /// Sample rate `parec` is asked to record at; it resamples whatever the sink plays.
+    const MONITOR_SAMPLE_RATE: u32 = 48_000;
+
+    /// A running `parec` whose output is read into the capture buffer on its own
+    /// thread. The process is killed when this is dropped.
+    pub struct MonitorRecorder {
+        child: Child,
+    }
+
+    impl Drop for MonitorRecorder {
+        fn drop(&mut self) {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+
+    /// Records `monitor` with `parec` as raw mono f32 samples. Going through a
+    /// separate process leaves the environment of this one alone, which other
+    /// threads may be reading.
+    pub fn open_monitor(monitor: &str) -> Result<CaptureStream> {
+        let mut child = Command::new("parec")
+            .arg(format!("--device={}", monitor))
+            .arg("--format=float32le")
+            .arg(format!("--rate={}", MONITOR_SAMPLE_RATE))
+            .arg("--channels=1")
+            .arg("--raw")
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| anyhow!("Failed to start parec: {}", e))?;
+        let mut stdout = child.stdout.take()
+            .ok_or_else(|| anyhow!("parec has no output"))?;
+
+        // parec exits right away when it can't connect to the source
+        std::thread::sleep(Duration::from_millis(200));
+        if let Ok(Some(status)) = child.try_wait() {
+            return Err(anyhow!("parec exited with {}", status));
+        }
+
+        let buffer = Arc::new(Mutex::new(AudioBuffer::new(MONITOR_SAMPLE_RATE)));
+        let sink = buffer.clone();
+        std::thread::spawn(move || {
+            let mut bytes = [0u8; 4096];
+            let mut pending: Vec<u8> = Vec::new();
+            loop {
+                let read = match stdout.read(&mut bytes) {
+                    Ok(0) => break,
+                    Ok(read) => read,
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        log::error!("[Audio] Failed to read from parec: {}", e);
+                        break;
+                    }
+                };
+                // Reads don't always end on a sample boundary
+                pending.extend_from_slice(&bytes[..read]);
+                let whole = pending.len() - pending.len() % 4;
+                let samples: Vec<f32> = pending[..whole]
+                    .chunks_exact(4)
+                    .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                    .collect();
+                pending.drain(..whole);
+                if let Ok(mut buffer) = sink.lock() {
+                    buffer.push_interleaved(&samples, 1);
+                }
+            }
+        });
+
+        Ok(CaptureStream {
+            source: CaptureSource::Process(MonitorRecorder { child }),
+            buffer,
+            device_name: monitor.to_string(),
+        })
+    }
+}
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use anyhow::{Result, anyhow};
+use base64::{Engine as _, engine::general_purpose};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::json;
+use crate::domain::ai::audio::{
+    audio_input::{AudioBuffer, WHISPER_SAMPLE_RATE},
+    transcribe_audio::{TranscriptionProvider, clean_transcript, language_code},
+};
+use crate::infrastructure::ai::provider::gemini::GEMINI_API_BASE_URL;
+
+#[derive(Debug, Deserialize)]
+struct GeminiAudioResponse {
+    candidates: Option<Vec<GeminiAudioCandidate>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeminiAudioCandidate {
+    content: Option<GeminiAudioContent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeminiAudioContent {
+    #[serde(default)]
+    parts: Vec<GeminiAudioPart>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeminiAudioPart {
+    text: Option<String>,
+}
+
+/// Sends the audio inline to a Gemini model and asks for a verbatim transcript.
+/// Gemini gives no timings, so transcripts are a single segment.
+pub struct GeminiTranscriptionClient {
+    client: Client,
+    api_key: String,
+    model: String,
+}
+
+impl GeminiTranscriptionClient {
+    pub fn new(api_key: String, model: String) -> Self {
+        Self { client: Client::new(), api_key, model }
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for GeminiTranscriptionClient {
+    async fn transcribe(&self, audio: &AudioBuffer, language: &str) -> Result<String> {
+        let wav = if audio.sample_rate == WHISPER_SAMPLE_RATE {
+            audio.to_wav_bytes()?
+        } else {
+            audio.resampled(WHISPER_SAMPLE_RATE).to_wav_bytes()?
+        };
+
+        let language = language_code(language);
+        let mut prompt = String::from(
+            "Transcribe the speech in this audio verbatim. Reply with the transcript only, \
+             without timestamps or commentary. If there is no speech, reply with nothing.",
+        );
+        if !language.is_empty() {
+            prompt.push_str(&format!(" The speech is in the language with code '{}'.", language));
+        }
+
+        let request = json!({
+            "contents": [{
+                "role": "user",
+                "parts": [
+                    { "text": prompt },
+                    { "inlineData": { "mimeType": "audio/wav", "data": general_purpose::STANDARD.encode(wav) } },
+                ],
+            }],
+            "generationConfig": { "temperature": 0.0 },
+        });
+
+        let url = format!("{}/{}:generateContent", GEMINI_API_BASE_URL, self.model);
+        let response = self.client.post(&url)
+            .header("x-goog-api-key", &self.api_key)
+            .json(&request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+        if !status.is_success() {
+            return Err(anyhow!("Gemini API error: Status {}, Response: {}", status, response_text));
+        }
+
+        let response: GeminiAudioResponse = serde_json::from_str(&response_text)
+            .map_err(|e| anyhow!("Failed to parse Gemini API response: {} - Raw: {}", e, response_text))?;
+
+        let text = response.candidates
+            .and_then(|candidates| candidates.into_iter().next())
+            .and_then(|candidate| candidate.content)
+            .map(|content| {
+                content.parts.into_iter()
+                    .filter_map(|part| part.text)
+                    .collect::<Vec<_>>()
+                    .join("")
+            })
+            .unwrap_or_default();
+        Ok(clean_transcript(&text))
+    }
+}
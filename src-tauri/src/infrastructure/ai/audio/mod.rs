@@ -0,0 +1,8 @@
+pub mod whisper_client;
+pub mod capture;
+pub mod sqlite_transcription_settings_repository;
+pub mod sqlite_vad_settings_repository;
+pub mod openai_transcription;
+pub mod gemini_transcription;
+pub mod whisper_local;
+pub mod whisper_models;
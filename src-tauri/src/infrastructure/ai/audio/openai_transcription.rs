@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use anyhow::{Result, anyhow};
+use reqwest::{Client, multipart};
+use serde::Deserialize;
+use crate::domain::ai::audio::{
+    audio_input::{AudioBuffer, WHISPER_SAMPLE_RATE},
+    transcribe_audio::{TranscriptionProvider, language_code},
+    transcript::{Transcript, TranscriptSegment},
+};
+
+pub const OPENAI_API_BASE_URL: &str = "https://api.openai.com/v1";
+
+/// `verbose_json` response; plain `json` only has `text`.
+#[derive(Debug, Deserialize)]
+struct OpenAITranscriptionResponse {
+    text: String,
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    segments: Option<Vec<OpenAITranscriptionSegment>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAITranscriptionSegment {
+    /// Seconds
+    start: f64,
+    end: f64,
+    text: String,
+    #[serde(default)]
+    avg_logprob: Option<f64>,
+}
+
+/// OpenAI's `/audio/transcriptions`, or the same endpoint on another server
+/// (faster-whisper-server, whisper.cpp's server, LocalAI...).
+pub struct OpenAITranscriptionClient {
+    client: Client,
+    base_url: String,
+    api_key: Option<String>,
+    model: String,
+}
+
+impl OpenAITranscriptionClient {
+    pub fn new(base_url: &str, api_key: Option<String>, model: String) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key: api_key.filter(|key| !key.is_empty()),
+            model,
+        }
+    }
+
+    /// The gpt-4o transcription models only answer with plain text; whisper
+    /// models and compatible servers give timed segments.
+    fn response_format(&self) -> &'static str {
+        if self.model.starts_with("gpt-4o") { "json" } else { "verbose_json" }
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for OpenAITranscriptionClient {
+    async fn transcribe(&self, audio: &AudioBuffer, language: &str) -> Result<String> {
+        Ok(self.transcribe_segments(audio, language).await?.text())
+    }
+
+    async fn transcribe_segments(&self, audio: &AudioBuffer, language: &str) -> Result<Transcript> {
+        // 16 kHz mono is all the models use, and keeps uploads small
+        let wav = if audio.sample_rate == WHISPER_SAMPLE_RATE {
+            audio.to_wav_bytes()?
+        } else {
+            audio.resampled(WHISPER_SAMPLE_RATE).to_wav_bytes()?
+        };
+        let file = multipart::Part::bytes(wav)
+            .file_name("audio.wav")
+            .mime_str("audio/wav")?;
+
+        let language = language_code(language);
+        let mut form = multipart::Form::new()
+            .part("file", file)
+            .text("model", self.model.clone())
+            .text("response_format", self.response_format());
+        if !language.is_empty() {
+            form = form.text("language", language.to_string());
+        }
+
+        let mut request = self.client
+            .post(format!("{}/audio/transcriptions", self.base_url))
+            .multipart(form);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("Authorization", format!("Bearer {}", api_key));
+        }
+
+        let response = request.send()
+            .await
+            .map_err(|e| anyhow!("Failed to reach transcription server: {}", e))?;
+        let status = response.status();
+        let response_text = response.text().await?;
+        if !status.is_success() {
+            return Err(anyhow!("Transcription API error: Status {}, Response: {}", status, response_text));
+        }
+
+        let response: OpenAITranscriptionResponse = serde_json::from_str(&response_text)
+            .map_err(|e| anyhow!("Failed to parse transcription response: {} - Raw: {}", e, response_text))?;
+
+        let segments = match response.segments {
+            Some(segments) => segments.into_iter()
+                .filter(|segment| !segment.text.trim().is_empty())
+                .map(|segment| TranscriptSegment {
+                    text: segment.text.trim().to_string(),
+                    start_ms: (segment.start.max(0.0) * 1000.0) as u64,
+                    end_ms: (segment.end.max(0.0) * 1000.0) as u64,
+                    // Mean log probability of the segment's tokens
+                    confidence: segment.avg_logprob.map(|logprob| logprob.exp() as f32),
+                    speaker: None,
+                })
+                .collect(),
+            None if response.text.trim().is_empty() => Vec::new(),
+            None => vec![TranscriptSegment {
+                text: response.text.trim().to_string(),
+                start_ms: 0,
+                end_ms: audio.duration_ms(),
+                confidence: None,
+                speaker: None,
+            }],
+        };
+
+        // verbose_json names the language ("portuguese"); prefer the code asked for
+        let language = if language.is_empty() {
+            response.language.unwrap_or_default()
+        } else {
+            language.to_string()
+        };
+        Ok(Transcript { language, segments })
+    }
+}
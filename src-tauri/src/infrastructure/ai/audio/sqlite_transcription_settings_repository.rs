@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use crate::domain::ai::audio::{
+    repository::TranscriptionSettingsRepository,
+    transcribe_audio::{TranscriptionProviderType, TranscriptionSettings},
+};
+
+pub struct SqliteTranscriptionSettingsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTranscriptionSettingsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TranscriptionSettingsRepository for SqliteTranscriptionSettingsRepository {
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<TranscriptionSettings>> {
+        let row = sqlx::query("SELECT provider, model, base_url FROM transcription_settings WHERE user_id = ?")
+            .bind(user_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to fetch transcription settings: {}", e))?;
+
+        row.map(|row| {
+            let provider: String = row.get("provider");
+            let provider = provider.parse::<TranscriptionProviderType>()?;
+            Ok(TranscriptionSettings {
+                provider,
+                model: row.get("model"),
+                base_url: row.get("base_url"),
+            })
+        })
+        .transpose()
+    }
+
+    async fn save(&self, user_id: Uuid, settings: &TranscriptionSettings) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO transcription_settings (user_id, provider, model, base_url, updated_at)
+            VALUES (?1, ?2, ?3, ?4, CURRENT_TIMESTAMP)
+            ON CONFLICT(user_id) DO UPDATE SET
+                provider = excluded.provider,
+                model = excluded.model,
+                base_url = excluded.base_url,
+                updated_at = excluded.updated_at
+            "#
+        )
+        .bind(user_id.to_string())
+        .bind(settings.provider.to_string_key())
+        .bind(settings.model.clone())
+        .bind(settings.base_url.clone())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save transcription settings: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use crate::domain::ai::audio::{repository::VadSettingsRepository, vad::VadConfig};
+
+pub struct SqliteVadSettingsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteVadSettingsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl VadSettingsRepository for SqliteVadSettingsRepository {
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<VadConfig>> {
+        let row = sqlx::query("SELECT config FROM vad_settings WHERE user_id = ?")
+            .bind(user_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to fetch VAD settings: {}", e))?;
+
+        row.map(|row| {
+            let config: String = row.get("config");
+            serde_json::from_str(&config).map_err(|e| anyhow!("Invalid VAD settings: {}", e))
+        })
+        .transpose()
+    }
+
+    async fn save(&self, user_id: Uuid, config: &VadConfig) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO vad_settings (user_id, config, updated_at)
+            VALUES (?1, ?2, CURRENT_TIMESTAMP)
+            ON CONFLICT(user_id) DO UPDATE SET
+                config = excluded.config,
+                updated_at = excluded.updated_at
+            "#
+        )
+        .bind(user_id.to_string())
+        .bind(serde_json::to_string(config)?)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save VAD settings: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use std::path::{Path, PathBuf};
+use async_trait::async_trait;
+use anyhow::{Result, anyhow};
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::domain::ai::audio::{
+    audio_input::{AudioBuffer, WHISPER_SAMPLE_RATE},
+    transcribe_audio::{TranscriptionProvider, language_code},
+    transcript::{Transcript, TranscriptSegment},
+};
+
+/// The parts of whisper.cpp's `-ojf` output that are used.
+#[derive(Deserialize)]
+struct CliOutput {
+    result: CliResult,
+    transcription: Vec<CliSegment>,
+}
+
+#[derive(Deserialize)]
+struct CliResult {
+    language: String,
+}
+
+#[derive(Deserialize)]
+struct CliSegment {
+    offsets: CliOffsets,
+    text: String,
+    #[serde(default)]
+    tokens: Vec<CliToken>,
+}
+
+#[derive(Deserialize)]
+struct CliOffsets {
+    from: u64,
+    to: u64,
+}
+
+#[derive(Deserialize)]
+struct CliToken {
+    text: String,
+    p: f32,
+}
+
+/// Runs the bundled whisper.cpp binary on WAV files.
+pub struct WhisperCliClient {
+    binary_path: PathBuf,
+    model_path: PathBuf,
+}
+
+impl WhisperCliClient {
+    pub fn new(binary_path: PathBuf, model_path: PathBuf) -> Self {
+        Self { binary_path, model_path }
+    }
+
+    pub async fn transcribe_file(&self, audio_path: &Path, language: &str) -> Result<Transcript> {
+        // -ojf writes the segments with their timestamps and token probabilities
+        // to <prefix>.json
+        let output_prefix = std::env::temp_dir().join(format!("primer_transcript_{}", Uuid::new_v4()));
+        let output_path = output_prefix.with_extension("json");
+
+        // -m model, -f file, -l lang, -ojf (full JSON), -of output prefix
+        let output = tokio::process::Command::new(&self.binary_path)
+            .arg("-m")
+            .arg(&self.model_path)
+            .arg("-f")
+            .arg(audio_path)
+            .arg("-l")
+            .arg(language_code(language))
+            .arg("-ojf")
+            .arg("-of")
+            .arg(&output_prefix)
+            .output()
+            .await
+            .map_err(|e| anyhow!("Failed to execute whisper: {}", e))?;
+
+        if !output.status.success() {
+            let _ = std::fs::remove_file(&output_path);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow!("Whisper failed: {}", stderr));
+        }
+
+        let json = std::fs::read_to_string(&output_path);
+        let _ = std::fs::remove_file(&output_path);
+        let json = json.map_err(|e| anyhow!("Failed to read whisper output: {}", e))?;
+        let output: CliOutput = serde_json::from_str(&json)
+            .map_err(|e| anyhow!("Failed to parse whisper output: {}", e))?;
+
+        let segments = output.transcription.into_iter()
+            .filter(|segment| !segment.text.trim().is_empty())
+            .map(|segment| {
+                // [_BEG_], [_TT_42] and <|endoftext|> are control tokens, not words
+                let words: Vec<f32> = segment.tokens.iter()
+                    .filter(|token| !token.text.starts_with("[_") && !token.text.starts_with("<|"))
+                    .map(|token| token.p)
+                    .collect();
+                TranscriptSegment {
+                    text: segment.text.trim().to_string(),
+                    start_ms: segment.offsets.from,
+                    end_ms: segment.offsets.to,
+                    confidence: (!words.is_empty()).then(|| words.iter().sum::<f32>() / words.len() as f32),
+                    speaker: None,
+                }
+            })
+            .collect();
+
+        Ok(Transcript { language: output.result.language, segments })
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for WhisperCliClient {
+    async fn transcribe(&self, audio: &AudioBuffer, language: &str) -> Result<String> {
+        Ok(self.transcribe_segments(audio, language).await?.text())
+    }
+
+    async fn transcribe_segments(&self, audio: &AudioBuffer, language: &str) -> Result<Transcript> {
+        let path = std::env::temp_dir().join(format!("primer_chunk_{}.wav", Uuid::new_v4()));
+        if audio.sample_rate == WHISPER_SAMPLE_RATE {
+            audio.write_wav(&path)?;
+        } else {
+            audio.resampled(WHISPER_SAMPLE_RATE).write_wav(&path)?;
+        }
+
+        let result = self.transcribe_file(&path, language).await;
+        let _ = std::fs::remove_file(&path);
+        result
+    }
+}
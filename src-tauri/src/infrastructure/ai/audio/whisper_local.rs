@@ -0,0 +1,208 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use async_trait::async_trait;
+use anyhow::{Result, anyhow};
+use log::info;
+use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters, WhisperError, WhisperState};
+use crate::domain::ai::audio::{
+    audio_input::{AudioBuffer, WHISPER_SAMPLE_RATE},
+    transcribe_audio::{TranscriptionProvider, language_code},
+    transcript::{Transcript, TranscriptSegment},
+};
+
+/// Called with the percentage done while a transcription runs.
+pub type ProgressCallback = Arc<dyn Fn(i32) + Send + Sync>;
+
+struct LoadedModel {
+    path: PathBuf,
+    state: WhisperState,
+    /// Ids from here up are timestamps and control tokens, not words
+    first_special_token: i32,
+}
+
+/// whisper.cpp linked into the app. The last model used stays loaded, so only
+/// the first transcription pays for reading it; using another model replaces it.
+/// Transcriptions run one at a time.
+pub struct WhisperEngine {
+    loaded: Mutex<Option<LoadedModel>>,
+}
+
+impl Default for WhisperEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WhisperEngine {
+    pub fn new() -> Self {
+        Self { loaded: Mutex::new(None) }
+    }
+
+    /// Loads `model_path` unless it's the model already loaded. Blocks.
+    pub fn load(&self, model_path: &Path) -> Result<()> {
+        let mut loaded = self.loaded.lock().map_err(|_| anyhow!("Whisper engine is unavailable"))?;
+        Self::ensure_loaded(&mut loaded, model_path)
+    }
+
+    /// Frees the loaded model.
+    pub fn unload(&self) {
+        if let Ok(mut loaded) = self.loaded.lock() {
+            if let Some(model) = loaded.take() {
+                info!("[Whisper] Unloaded {:?}", model.path);
+            }
+        }
+    }
+
+    pub fn loaded_model(&self) -> Option<PathBuf> {
+        self.loaded.lock().ok()?.as_ref().map(|model| model.path.clone())
+    }
+
+    /// Transcribes 16 kHz mono samples into timed segments. Blocks for as long
+    /// as the model takes.
+    pub fn transcribe(
+        &self,
+        model_path: &Path,
+        samples: &[f32],
+        language: &str,
+        progress: Option<ProgressCallback>,
+    ) -> Result<Transcript> {
+        let mut loaded = self.loaded.lock().map_err(|_| anyhow!("Whisper engine is unavailable"))?;
+        Self::ensure_loaded(&mut loaded, model_path)?;
+        let model = loaded.as_mut().ok_or_else(|| anyhow!("Whisper model is not loaded"))?;
+
+        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+        params.set_n_threads(thread_count());
+        params.set_language(Some(if language.is_empty() { "auto" } else { language }));
+        // Calls are unrelated audio; don't prompt with the previous call's text
+        params.set_no_context(true);
+        params.set_print_special(false);
+        params.set_print_progress(false);
+        params.set_print_realtime(false);
+        params.set_print_timestamps(false);
+        if let Some(progress) = progress {
+            params.set_progress_callback_safe(move |percentage: i32| progress(percentage));
+        }
+
+        model.state.full(params, samples)
+            .map_err(|e| anyhow!("Whisper failed: {}", e))?;
+
+        let state = &model.state;
+        let whisper_error = |e: WhisperError| anyhow!("Whisper failed: {}", e);
+        let count = state.full_n_segments().map_err(whisper_error)?;
+        let mut segments = Vec::with_capacity(count as usize);
+        for segment in 0..count {
+            let text = state.full_get_segment_text_lossy(segment).map_err(whisper_error)?;
+            let text = text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            // Timestamps are in centiseconds
+            let start = state.full_get_segment_t0(segment).map_err(whisper_error)?;
+            let end = state.full_get_segment_t1(segment).map_err(whisper_error)?;
+
+            let mut probability_sum = 0.0;
+            let mut words = 0;
+            for token in 0..state.full_n_tokens(segment).map_err(whisper_error)? {
+                if state.full_get_token_id(segment, token).map_err(whisper_error)? >= model.first_special_token {
+                    continue;
+                }
+                probability_sum += state.full_get_token_prob(segment, token).map_err(whisper_error)?;
+                words += 1;
+            }
+
+            segments.push(TranscriptSegment {
+                text: text.to_string(),
+                start_ms: start.max(0) as u64 * 10,
+                end_ms: end.max(0) as u64 * 10,
+                confidence: (words > 0).then(|| probability_sum / words as f32),
+                speaker: None,
+            });
+        }
+
+        let language = state.full_lang_id_from_state().ok()
+            .and_then(whisper_rs::get_lang_str)
+            .unwrap_or(language)
+            .to_string();
+        Ok(Transcript { language, segments })
+    }
+
+    fn ensure_loaded(loaded: &mut Option<LoadedModel>, model_path: &Path) -> Result<()> {
+        if loaded.as_ref().is_some_and(|model| model.path == model_path) {
+            return Ok(());
+        }
+        // Free the previous model before allocating the next one
+        *loaded = None;
+
+        info!("[Whisper] Loading {:?}", model_path);
+        let path = model_path.to_str()
+            .ok_or_else(|| anyhow!("Model path is not valid UTF-8: {:?}", model_path))?;
+        let context = WhisperContext::new_with_params(path, WhisperContextParameters::default())
+            .map_err(|e| anyhow!("Failed to load whisper model: {}", e))?;
+        let state = context.create_state()
+            .map_err(|e| anyhow!("Failed to initialize whisper model: {}", e))?;
+
+        *loaded = Some(LoadedModel {
+            path: model_path.to_path_buf(),
+            state,
+            first_special_token: context.token_eot(),
+        });
+        Ok(())
+    }
+}
+
+fn thread_count() -> i32 {
+    std::thread::available_parallelism()
+        .map(|n| n.get().min(8))
+        .unwrap_or(4) as i32
+}
+
+/// Transcribes with the in-process engine and a given model file.
+pub struct WhisperLocalClient {
+    engine: Arc<WhisperEngine>,
+    model_path: PathBuf,
+    progress: Option<ProgressCallback>,
+}
+
+impl WhisperLocalClient {
+    pub fn new(engine: Arc<WhisperEngine>, model_path: PathBuf) -> Self {
+        Self { engine, model_path, progress: None }
+    }
+
+    pub fn with_progress(mut self, progress: Option<ProgressCallback>) -> Self {
+        self.progress = progress;
+        self
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for WhisperLocalClient {
+    async fn transcribe(&self, audio: &AudioBuffer, language: &str) -> Result<String> {
+        Ok(self.transcribe_segments(audio, language).await?.text())
+    }
+
+    async fn transcribe_segments(&self, audio: &AudioBuffer, language: &str) -> Result<Transcript> {
+        let samples = if audio.sample_rate == WHISPER_SAMPLE_RATE {
+            audio.samples.clone()
+        } else {
+            audio.resampled(WHISPER_SAMPLE_RATE).samples
+        };
+        let engine = self.engine.clone();
+        let model_path = self.model_path.clone();
+        let language = language_code(language).to_string();
+        let progress = self.progress.clone();
+
+        tokio::task::spawn_blocking(move || engine.transcribe(&model_path, &samples, &language, progress))
+            .await
+            .map_err(|e| anyhow!("Transcription task failed: {}", e))?
+    }
+
+    fn warm_up(&self) {
+        let engine = self.engine.clone();
+        let model_path = self.model_path.clone();
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = engine.load(&model_path) {
+                log::warn!("[Whisper] Failed to preload {:?}: {}", model_path, e);
+            }
+        });
+    }
+}
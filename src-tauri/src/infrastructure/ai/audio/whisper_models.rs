@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use anyhow::{Result, anyhow};
+use futures_util::StreamExt;
+use log::{info, warn};
+use reqwest::{Client, StatusCode, header};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use crate::domain::ai::audio::whisper_model::{find_model, model_file_name};
+
+const MODEL_REPO_URL: &str = "https://huggingface.co/ggerganov/whisper.cpp/resolve/main";
+/// Lists the repository's files with the SHA-256 and size of each LFS object.
+const MODEL_MANIFEST_URL: &str = "https://huggingface.co/api/models/ggerganov/whisper.cpp/tree/main";
+const MANIFEST_FILE: &str = "manifest.json";
+const PARTIAL_EXTENSION: &str = "part";
+
+/// What a model file must hash to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub sha256: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepoFile {
+    path: String,
+    lfs: Option<RepoLfs>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepoLfs {
+    oid: String,
+    size: u64,
+}
+
+/// Cancel flags of the downloads in progress, by model name.
+#[derive(Default)]
+pub struct WhisperDownloads {
+    running: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl WhisperDownloads {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn start(&self, name: &str) -> Result<Arc<AtomicBool>> {
+        let mut running = self.running.lock().map_err(|_| anyhow!("Download registry is unavailable"))?;
+        if running.contains_key(name) {
+            return Err(anyhow!("Model {} is already downloading", name));
+        }
+        let cancelled = Arc::new(AtomicBool::new(false));
+        running.insert(name.to_string(), cancelled.clone());
+        Ok(cancelled)
+    }
+
+    fn finish(&self, name: &str) {
+        if let Ok(mut running) = self.running.lock() {
+            running.remove(name);
+        }
+    }
+
+    pub fn is_downloading(&self, name: &str) -> bool {
+        self.running.lock().map(|running| running.contains_key(name)).unwrap_or(false)
+    }
+
+    /// Stops the download of `name`; what was downloaded is kept for resuming.
+    /// Returns whether there was one.
+    pub fn cancel(&self, name: &str) -> bool {
+        match self.running.lock().ok().and_then(|running| running.get(name).cloned()) {
+            Some(cancelled) => {
+                cancelled.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Downloaded whisper models. New models go to `models_dir`, which is writable;
+/// models shipped with the app in `bundled_dir` are used but never modified.
+pub struct WhisperModelStore {
+    models_dir: PathBuf,
+    bundled_dir: Option<PathBuf>,
+    client: Client,
+}
+
+impl WhisperModelStore {
+    pub fn new(models_dir: PathBuf, bundled_dir: Option<PathBuf>) -> Self {
+        Self { models_dir, bundled_dir, client: Client::new() }
+    }
+
+    /// Where `name` is installed, downloaded models first.
+    pub fn path(&self, name: &str) -> Option<PathBuf> {
+        let file_name = model_file_name(name);
+        std::iter::once(self.models_dir.join(&file_name))
+            .chain(self.bundled_dir.as_ref().map(|dir| dir.join(&file_name)))
+            .find(|path| path.is_file())
+    }
+
+    pub fn is_bundled(&self, path: &Path) -> bool {
+        self.bundled_dir.as_ref().is_some_and(|dir| path.starts_with(dir))
+    }
+
+    /// Bytes of an interrupted download of `name` that a new one resumes from.
+    pub fn partial_size(&self, name: &str) -> u64 {
+        fs_len(&self.partial_path(name))
+    }
+
+    fn partial_path(&self, name: &str) -> PathBuf {
+        self.models_dir.join(format!("{}.{}", model_file_name(name), PARTIAL_EXTENSION))
+    }
+
+    /// The expected hash and size of every model file. Fetched from the model
+    /// repository and cached, so models can be verified offline.
+    pub async fn manifest(&self) -> Result<HashMap<String, ManifestEntry>> {
+        let cache_path = self.models_dir.join(MANIFEST_FILE);
+        match self.fetch_manifest().await {
+            Ok(manifest) => {
+                std::fs::create_dir_all(&self.models_dir)
+                    .map_err(|e| anyhow!("Failed to create models directory: {}", e))?;
+                if let Err(e) = std::fs::write(&cache_path, serde_json::to_string_pretty(&manifest)?) {
+                    warn!("[Whisper] Failed to cache model manifest: {}", e);
+                }
+                Ok(manifest)
+            }
+            Err(e) => {
+                warn!("[Whisper] Failed to fetch model manifest, using the cached one: {}", e);
+                let cached = std::fs::read_to_string(&cache_path)
+                    .map_err(|_| anyhow!("Model manifest is unavailable: {}", e))?;
+                serde_json::from_str(&cached).map_err(|e| anyhow!("Invalid cached model manifest: {}", e))
+            }
+        }
+    }
+
+    async fn fetch_manifest(&self) -> Result<HashMap<String, ManifestEntry>> {
+        let files: Vec<RepoFile> = self.client.get(MODEL_MANIFEST_URL)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(files.into_iter()
+            .filter_map(|file| {
+                let lfs = file.lfs?;
+                Some((file.path, ManifestEntry { sha256: lfs.oid, size: lfs.size }))
+            })
+            .collect())
+    }
+
+    /// Downloads `name` into the models directory, resuming an interrupted
+    /// download, and installs it once its SHA-256 matches the manifest.
+    /// `progress` gets the bytes downloaded so far and the total.
+    pub async fn download(
+        &self,
+        name: &str,
+        downloads: &WhisperDownloads,
+        progress: impl Fn(u64, u64),
+    ) -> Result<PathBuf> {
+        if find_model(name).is_none() {
+            return Err(anyhow!("Unknown whisper model: {}", name));
+        }
+        let cancelled = downloads.start(name)?;
+        let result = self.download_verified(name, &cancelled, progress).await;
+        downloads.finish(name);
+        result
+    }
+
+    async fn download_verified(&self, name: &str, cancelled: &AtomicBool, progress: impl Fn(u64, u64)) -> Result<PathBuf> {
+        let file_name = model_file_name(name);
+        let expected = self.manifest().await?
+            .remove(&file_name)
+            .ok_or_else(|| anyhow!("Model {} is not in the manifest", file_name))?;
+
+        std::fs::create_dir_all(&self.models_dir)
+            .map_err(|e| anyhow!("Failed to create models directory: {}", e))?;
+        let partial_path = self.partial_path(name);
+        let mut downloaded = fs_len(&partial_path);
+        if downloaded > expected.size {
+            // Not a prefix of this model
+            let _ = std::fs::remove_file(&partial_path);
+            downloaded = 0;
+        }
+
+        if downloaded < expected.size {
+            let mut request = self.client.get(format!("{}/{}", MODEL_REPO_URL, file_name));
+            if downloaded > 0 {
+                info!("[Whisper] Resuming {} from {} bytes", file_name, downloaded);
+                request = request.header(header::RANGE, format!("bytes={}-", downloaded));
+            }
+            let response = request.send()
+                .await
+                .map_err(|e| anyhow!("Failed to start download: {}", e))?
+                .error_for_status()
+                .map_err(|e| anyhow!("Failed to start download: {}", e))?;
+
+            // A server that ignores the range sends the whole file again
+            let resumed = response.status() == StatusCode::PARTIAL_CONTENT;
+            if !resumed {
+                downloaded = 0;
+            }
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(resumed)
+                .truncate(!resumed)
+                .open(&partial_path)
+                .map_err(|e| anyhow!("Failed to create file: {}", e))?;
+
+            let mut stream = response.bytes_stream();
+            while let Some(chunk) = stream.next().await {
+                if cancelled.load(Ordering::SeqCst) {
+                    info!("[Whisper] Download of {} cancelled at {} bytes", file_name, downloaded);
+                    return Err(anyhow!("Download cancelled"));
+                }
+                let chunk = chunk.map_err(|e| anyhow!("Error while downloading: {}", e))?;
+                file.write_all(&chunk)
+                    .map_err(|e| anyhow!("Error while writing to file: {}", e))?;
+                downloaded += chunk.len() as u64;
+                progress(downloaded, expected.size);
+            }
+            file.flush().map_err(|e| anyhow!("Error while writing to file: {}", e))?;
+        }
+
+        let verify_path = partial_path.clone();
+        let sha256 = tokio::task::spawn_blocking(move || sha256_file(&verify_path))
+            .await
+            .map_err(|e| anyhow!("Verification task failed: {}", e))??;
+        if !sha256.eq_ignore_ascii_case(&expected.sha256) {
+            let _ = std::fs::remove_file(&partial_path);
+            return Err(anyhow!("Checksum mismatch for {}: expected {}, got {}", file_name, expected.sha256, sha256));
+        }
+
+        let model_path = self.models_dir.join(&file_name);
+        std::fs::rename(&partial_path, &model_path)
+            .map_err(|e| anyhow!("Failed to install model: {}", e))?;
+        info!("[Whisper] Installed {:?}", model_path);
+        Ok(model_path)
+    }
+
+    /// Whether the installed `name` hashes to what the manifest says.
+    pub async fn verify(&self, name: &str) -> Result<bool> {
+        let path = self.path(name).ok_or_else(|| anyhow!("Model {} is not downloaded", name))?;
+        let expected = self.manifest().await?
+            .remove(&model_file_name(name))
+            .ok_or_else(|| anyhow!("Model {} is not in the manifest", name))?;
+
+        let sha256 = tokio::task::spawn_blocking(move || sha256_file(&path))
+            .await
+            .map_err(|e| anyhow!("Verification task failed: {}", e))??;
+        Ok(sha256.eq_ignore_ascii_case(&expected.sha256))
+    }
+
+    /// Removes a downloaded model and any partial download of it.
+    pub fn delete(&self, name: &str) -> Result<()> {
+        let _ = std::fs::remove_file(self.partial_path(name));
+        let Some(path) = self.path(name) else {
+            return Ok(());
+        };
+        if self.is_bundled(&path) {
+            return Err(anyhow!("Model {} ships with the app and can't be deleted", name));
+        }
+        std::fs::remove_file(&path).map_err(|e| anyhow!("Failed to delete model: {}", e))?;
+        info!("[Whisper] Deleted {:?}", path);
+        Ok(())
+    }
+}
+
+fn fs_len(path: &Path) -> u64 {
+    std::fs::metadata(path).map(|metadata| metadata.len()).unwrap_or(0)
+}
+
+fn sha256_file(path: &Path) -> Result<String> {
+    let mut file = std::fs::File::open(path).map_err(|e| anyhow!("Failed to open {:?}: {}", path, e))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 1 << 20];
+    loop {
+        let read = file.read(&mut buffer).map_err(|e| anyhow!("Failed to read {:?}: {}", path, e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
@@ -1,26 +1,43 @@
 use async_trait::async_trait;
 use anyhow::{Result, anyhow};
 use std::sync::Arc;
+use std::time::Instant;
 use uuid::Uuid;
 use chrono::Utc;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use crate::domain::ai::provider::{
-    AiProvider, ChatCompletionRequest, ChatMessage,
+    AiProvider, ChatCompletionRequest, ChatCompletionUsage, ChatMessage,
 };
 use crate::domain::ai::chat::repository::message_repository::MessageRepository;
 use crate::domain::ai::chat::repository::chat_repository::ChatRepository;
-use crate::domain::prompt_preset::repository::PromptPresetRepository;
-use crate::domain::ai::chat::entity::message::Message;
+use crate::domain::prompt_preset::{repository::PromptPresetRepository, template};
+use crate::domain::ai::chat::entity::{chat::Chat, generation::MessageGeneration, message::Message};
+use crate::domain::document::entity::DocumentCitation;
 use crate::domain::ai::chat::service::{
-    chat_service::{ChatService, ChatServiceRequest, AIProviderType},
+    chat_service::{ChatService, ChatServiceRequest, AIProviderType, CompareCandidate, CompareResult, CompareTarget},
 };
+use crate::domain::user::entity::user_api_key::UserApiKey;
 use crate::domain::user::repository::user_api_key_repository::UserApiKeyRepository;
 use crate::domain::calendar::usecase::create_event::CreateEventUseCase;
 use crate::domain::notion::usecase::create_page::CreatePageUseCase;
+use crate::domain::document::repository::DocumentRepository;
+use crate::domain::workspace::repository::WorkspaceRepository;
+use crate::domain::feedback::entity::REJECTED_MESSAGE_TYPE;
+use crate::domain::action::{
+    entity::{ActionLogEntry, ActionOutcome, ActionStatus, ProposedAction, UndoHandle, CALENDAR_EVENT_TOOL, MCP_TOOL, NOTION_PAGE_TOOL},
+    notifier::ActionNotifier,
+    repository::{ActionLogRepository, ProposedActionRepository},
+};
+use crate::domain::task::{
+    entity::{ExtractedTask, Task},
+    repository::TaskRepository,
+    usecase::record_extracted_tasks::RecordExtractedTasksUseCase,
+};
+use crate::infrastructure::mcp::manager::McpManager;
 
 use crate::domain::config::repository::ConfigRepository;
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 struct AiCalendarEvent {
     summary: String,
     description: Option<String>,
@@ -28,13 +45,31 @@ struct AiCalendarEvent {
     end_time: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 struct NotionPageRequest {
     title: String,
     content: String,
     parent_id: Option<String>,
 }
 
+#[derive(Deserialize)]
+struct AiToolCall {
+    server: String,
+    tool: String,
+    #[serde(default)]
+    arguments: serde_json::Value,
+}
+
+/// Arguments stored on an MCP action; only `arguments` can be edited by the user.
+#[derive(Serialize, Deserialize)]
+struct McpCallArguments {
+    server_id: Uuid,
+    server_name: String,
+    tool: String,
+    #[serde(default)]
+    arguments: serde_json::Value,
+}
+
 #[derive(Deserialize)]
 struct AiResponse {
     answer: String,
@@ -42,6 +77,8 @@ struct AiResponse {
     follow_ups: Vec<String>,
     calendar_event: Option<AiCalendarEvent>,
     notion_page: Option<NotionPageRequest>,
+    #[serde(default)]
+    tool_call: Option<AiToolCall>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -49,6 +86,17 @@ struct MessageAnalysis {
     summary: String,
     importance: i32,
     message_type: String,
+    #[serde(default)]
+    tasks: Vec<ExtractedTask>,
+}
+
+/// Most recent messages sent for on-demand task extraction.
+const TASK_EXTRACTION_MESSAGES: i64 = 60;
+
+#[derive(Deserialize, Debug)]
+struct TaskExtraction {
+    #[serde(default)]
+    tasks: Vec<ExtractedTask>,
 }
 
 pub struct ChatServiceImpl {
@@ -62,6 +110,13 @@ pub struct ChatServiceImpl {
     openrouter_provider: Arc<dyn AiProvider>,
     create_event_usecase: Arc<CreateEventUseCase>,
     create_page_usecase: Arc<CreatePageUseCase>,
+    document_repo: Arc<dyn DocumentRepository>,
+    workspace_repo: Arc<dyn WorkspaceRepository>,
+    mcp_manager: Arc<McpManager>,
+    action_repo: Arc<dyn ProposedActionRepository>,
+    action_notifier: Arc<ActionNotifier>,
+    action_log_repo: Arc<dyn ActionLogRepository>,
+    task_repo: Arc<dyn TaskRepository>,
 }
 
 impl ChatServiceImpl {
@@ -76,6 +131,13 @@ impl ChatServiceImpl {
         openrouter_provider: Arc<dyn AiProvider>,
         create_event_usecase: Arc<CreateEventUseCase>,
         create_page_usecase: Arc<CreatePageUseCase>,
+        document_repo: Arc<dyn DocumentRepository>,
+        workspace_repo: Arc<dyn WorkspaceRepository>,
+        mcp_manager: Arc<McpManager>,
+        action_repo: Arc<dyn ProposedActionRepository>,
+        action_notifier: Arc<ActionNotifier>,
+        action_log_repo: Arc<dyn ActionLogRepository>,
+        task_repo: Arc<dyn TaskRepository>,
     ) -> Self {
         Self {
             config_repo,
@@ -88,6 +150,13 @@ impl ChatServiceImpl {
             openrouter_provider,
             create_event_usecase,
             create_page_usecase,
+            document_repo,
+            workspace_repo,
+            mcp_manager,
+            action_repo,
+            action_notifier,
+            action_log_repo,
+            task_repo,
         }
     }
 
@@ -95,8 +164,10 @@ impl ChatServiceImpl {
         provider: Arc<dyn AiProvider>,
         api_key: String,
         model: String,
+        user_id: Uuid,
         message: Message,
-        message_repo: Arc<dyn MessageRepository>
+        message_repo: Arc<dyn MessageRepository>,
+        task_repo: Arc<dyn TaskRepository>,
     ) -> Result<()> {
         // --- LAYER 1 & 2: Heuristic Filters (Cheap) ---
         if !Self::should_analyze_message(&message) {
@@ -121,14 +192,19 @@ impl ChatServiceImpl {
             1. "summary": A concise 1-sentence summary (max 20 words) in Portuguese. IF IMPORTANCE IS 0, RETURN EMPTY STRING "".
             2. "importance": Integer 0-100.
             3. "message_type": One of ['chat', 'decision', 'code', 'summary', 'meeting'].
+            4. "tasks": Action items the user committed to or asked to be reminded of (not things the assistant suggests).
+               Each item: {"title": short imperative sentence in the message's language, "due_date": "YYYY-MM-DD" or "YYYY-MM-DDTHH:MM" or null, "notes": string or null}.
+               Return [] when there are none.
 
             Example JSON:
             {
                 "summary": "Decisão de usar Rocket como framework web.",
                 "importance": 80,
-                "message_type": "decision"
+                "message_type": "decision",
+                "tasks": [{"title": "Migrar a API para Rocket", "due_date": "2025-03-14", "notes": null}]
             }
         "#;
+        let system_prompt = format!("{}\nToday is {}.", system_prompt, chrono::Local::now().format("%Y-%m-%d (%A)"));
 
         let request = ChatCompletionRequest {
             model,
@@ -173,17 +249,39 @@ impl ChatServiceImpl {
 
                      match serde_json::from_str::<MessageAnalysis>(clean_content) {
                          Ok(analysis) => {
-                             // Only update if importance > 0 or it's not empty
-                             if analysis.importance > 0 && !analysis.summary.is_empty() {
-                                 let mut updated_message = message;
-                                 updated_message.summary = Some(analysis.summary.clone());
-                                 updated_message.importance = analysis.importance;
-                                 updated_message.message_type = analysis.message_type;
-                                 
-                                 if let Err(e) = message_repo.update(updated_message).await {
-                                     log::error!("Failed to update message analysis: {}", e);
-                                 } else {
-                                     log::info!("Message analyzed successfully: Importance {}, Summary: {:?}", analysis.importance, analysis.summary);
+                             let has_memory = analysis.importance > 0 && !analysis.summary.is_empty();
+                             if has_memory || !analysis.tasks.is_empty() {
+                                 // Feedback may have landed while we were analyzing; re-read so a
+                                 // rejected answer stays out of memory and a boost is not lost
+                                 let (message_id, chat_id) = (message.id, message.chat_id);
+                                 let current = message_repo.find_by_id(message_id).await?.unwrap_or(message);
+                                 if current.message_type == REJECTED_MESSAGE_TYPE {
+                                     log::info!("Skipping analysis update for rejected message {}", current.id);
+                                     return Ok(());
+                                 }
+
+                                 if !analysis.tasks.is_empty() {
+                                     match RecordExtractedTasksUseCase::new(task_repo)
+                                         .execute(user_id, chat_id, Some(message_id), &[], analysis.tasks)
+                                         .await
+                                     {
+                                         Ok(tasks) if !tasks.is_empty() => log::info!("Extracted {} task(s) from message {}", tasks.len(), message_id),
+                                         Ok(_) => {}
+                                         Err(e) => log::error!("Failed to save extracted tasks: {}", e),
+                                     }
+                                 }
+
+                                 if has_memory {
+                                     let mut updated_message = current;
+                                     updated_message.summary = Some(analysis.summary.clone());
+                                     updated_message.importance = analysis.importance.max(updated_message.importance);
+                                     updated_message.message_type = analysis.message_type;
+
+                                     if let Err(e) = message_repo.update(updated_message).await {
+                                         log::error!("Failed to update message analysis: {}", e);
+                                     } else {
+                                         log::info!("Message analyzed successfully: Importance {}, Summary: {:?}", analysis.importance, analysis.summary);
+                                     }
                                  }
                              }
                          },
@@ -266,84 +364,420 @@ impl ChatServiceImpl {
 
         false
     }
-}
-
-
 
-#[async_trait]
-impl ChatService for ChatServiceImpl {
-    async fn send_message_to_ai(&self, request: ChatServiceRequest) -> Result<(Message, Vec<String>)> {
-        // 1. Get user's API key
-        let user_api_keys = self.user_api_key_repo.find_by_user_id(request.user_id).await?;
-        let provider_type = request.provider_name.parse::<AIProviderType>() // Changed from AIProviderType::from_str
-            .map_err(|e| anyhow!("Unsupported AI provider: {}", e))?; // Handle error from parse
+    fn resolve_provider(&self, user_api_keys: &[UserApiKey], provider_name: &str) -> Result<(Arc<dyn AiProvider>, String)> {
+        let provider_type = provider_name.parse::<AIProviderType>()
+            .map_err(|e| anyhow!("Unsupported AI provider: {}", e))?;
 
         let api_key_entry = user_api_keys.iter()
             .find(|key| key.provider == provider_type.to_string_key())
-            .ok_or_else(|| anyhow!("API key not found for provider: {}", request.provider_name))?;
-        
-        let api_key = &api_key_entry.api_key;
+            .ok_or_else(|| anyhow!("API key not found for provider: {}", provider_name))?;
 
-        // 2. Select AI provider early (to use for analysis)
         let ai_provider = match provider_type {
             AIProviderType::Gemini => self.gemini_provider.clone(),
             AIProviderType::OpenAI => self.openai_provider.clone(),
             AIProviderType::OpenRouter => self.openrouter_provider.clone(),
         };
 
-        // 3. Save user's message first
-        let user_message = Message {
+        Ok((ai_provider, api_key_entry.api_key.clone()))
+    }
+
+    fn parse_ai_response(content: &str) -> AiResponse {
+        let clean_content = content.trim();
+
+        // Helper to try parsing a string as AiResponse
+        let try_parse = |s: &str| -> Option<AiResponse> {
+            serde_json::from_str::<AiResponse>(s).ok()
+        };
+
+        // Strategy 1: Direct parse
+        if let Some(parsed) = try_parse(clean_content) {
+            return parsed;
+        }
+
+        // Strategy 2: Locate JSON object bounds { ... }
+        // This handles ```json wrappers and conversational pre/post-ambles
+        let extracted = clean_content.find('{')
+            .and_then(|start| clean_content.rfind('}').map(|end| (start, end)))
+            .and_then(|(start, end)| {
+                if start < end {
+                    try_parse(&clean_content[start..=end])
+                } else {
+                    None
+                }
+            });
+
+        extracted.unwrap_or_else(|| {
+            log::warn!("Failed to parse AI JSON response. Fallback to raw text.");
+            // Fallback: Treat entire content as the answer, no tip/follow-ups
+            AiResponse {
+                answer: content.to_string(),
+                tip: None,
+                follow_ups: vec![],
+                calendar_event: None,
+                notion_page: None,
+                tool_call: None,
+            }
+        })
+    }
+
+    /// Stores provider/model/latency/usage for an assistant message. Failures are
+    /// only logged: the reply itself is already saved.
+    async fn record_generation(&self, message: &Message, provider_name: &str, model: &str, latency_ms: i64, usage: &ChatCompletionUsage) -> Option<MessageGeneration> {
+        let generation = MessageGeneration {
+            message_id: message.id,
+            provider: provider_name.to_lowercase(),
+            model: model.to_string(),
+            latency_ms,
+            prompt_tokens: usage.prompt_tokens as i64,
+            completion_tokens: usage.completion_tokens as i64,
+            total_tokens: usage.total_tokens as i64,
+            created_at: Utc::now(),
+        };
+
+        match self.message_repo.save_generation(&generation).await {
+            Ok(()) => Some(generation),
+            Err(e) => {
+                log::warn!("Failed to record generation stats for message {}: {}", message.id, e);
+                None
+            }
+        }
+    }
+
+    /// Runs one compare-mode target and stores its reply as an alternative.
+    /// Tool calls are ignored: only the reply that gets picked should act.
+    async fn generate_alternative(
+        &self,
+        user_api_keys: &[UserApiKey],
+        request: &ChatServiceRequest,
+        user_message: &Message,
+        chat_messages: Vec<ChatMessage>,
+        citations: &[DocumentCitation],
+        target: &CompareTarget,
+    ) -> Result<(Message, Option<MessageGeneration>)> {
+        let (ai_provider, api_key) = self.resolve_provider(user_api_keys, &target.provider_name)?;
+
+        let chat_req = ChatCompletionRequest {
+            model: target.model.clone(),
+            messages: chat_messages,
+            temperature: request.temperature,
+            max_tokens: request.max_tokens,
+        };
+
+        let started = Instant::now();
+        let ai_response = ai_provider.chat_completion(&api_key, chat_req).await?;
+        let latency_ms = started.elapsed().as_millis() as i64;
+
+        let content = ai_response.choices.first()
+            .map(|choice| choice.message.content.clone())
+            .ok_or_else(|| anyhow!("No response from AI"))?;
+
+        let parsed = Self::parse_ai_response(&content);
+
+        let message = Message {
             id: Uuid::new_v4(),
             chat_id: request.chat_id,
-            role: "user".to_string(),
-            content: request.prompt.clone(),
+            role: "assistant".to_string(),
+            content: parsed.answer,
             created_at: Utc::now(),
             summary: None,
             message_type: "chat".to_string(),
             importance: 0,
-            follow_ups: None,
-            tip: None,
+            reply_to_id: Some(user_message.id),
+            is_alternative: true,
+            follow_ups: if parsed.follow_ups.is_empty() { None } else { Some(parsed.follow_ups) },
+            tip: parsed.tip,
+            citations: if citations.is_empty() { None } else { Some(citations.to_vec()) },
         };
-        self.message_repo.create(user_message.clone()).await?;
 
-        // 4. Spawn Background Analysis Agent for User Message
-        let user_analysis_provider = ai_provider.clone();
-        let user_analysis_api_key = api_key.clone();
-        let user_analysis_model = request.model.clone();
-        let user_analysis_message = user_message.clone();
-        let user_analysis_repo = self.message_repo.clone();
-
-        tokio::spawn(async move {
-            if let Err(e) = Self::analyze_message(
-                user_analysis_provider,
-                user_analysis_api_key,
-                user_analysis_model,
-                user_analysis_message,
-                user_analysis_repo
-            ).await {
-                log::error!("User message background analysis failed: {}", e);
+        self.message_repo.create(message.clone()).await?;
+        let generation = self.record_generation(&message, &target.provider_name, &target.model, latency_ms, &ai_response.usage).await;
+
+        Ok((message, generation))
+    }
+
+    fn new_action(request: &ChatServiceRequest, message_id: Uuid, tool: &str, arguments: serde_json::Value) -> ProposedAction {
+        ProposedAction {
+            id: Uuid::new_v4(),
+            message_id,
+            chat_id: request.chat_id,
+            user_id: request.user_id,
+            tool: tool.to_string(),
+            title: Self::action_title(tool, &arguments),
+            arguments,
+            status: ActionStatus::Pending,
+            result: None,
+            provider_name: Some(request.provider_name.clone()),
+            model: Some(request.model.clone()),
+            created_at: Utc::now(),
+            resolved_at: None,
+        }
+    }
+
+    fn action_title(tool: &str, arguments: &serde_json::Value) -> String {
+        let field = |name: &str| arguments.get(name).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+        match tool {
+            CALENDAR_EVENT_TOOL => format!("Agendar evento: {} ({})", field("summary"), field("start_time")),
+            NOTION_PAGE_TOOL => format!("Criar página no Notion: {}", field("title")),
+            MCP_TOOL => format!("Executar {}/{}", field("server_name"), field("tool")),
+            other => other.to_string(),
+        }
+    }
+
+    /// Replaces the arguments of a pending action with the user's edited version.
+    /// For MCP calls only the tool arguments are editable, not the server or tool.
+    fn apply_edit(action: &mut ProposedAction, edited: serde_json::Value) -> Result<()> {
+        let arguments = match action.tool.as_str() {
+            CALENDAR_EVENT_TOOL => {
+                let event: AiCalendarEvent = serde_json::from_value(edited)
+                    .map_err(|e| anyhow!("Invalid event: {}", e))?;
+                serde_json::to_value(&event)?
             }
-        });
+            NOTION_PAGE_TOOL => {
+                let page: NotionPageRequest = serde_json::from_value(edited)
+                    .map_err(|e| anyhow!("Invalid Notion page: {}", e))?;
+                serde_json::to_value(&page)?
+            }
+            MCP_TOOL => {
+                if !edited.is_object() {
+                    return Err(anyhow!("MCP tool arguments must be a JSON object"));
+                }
+                let mut call: McpCallArguments = serde_json::from_value(action.arguments.clone())?;
+                call.arguments = edited;
+                serde_json::to_value(&call)?
+            }
+            other => return Err(anyhow!("Unknown action tool: {}", other)),
+        };
 
-        // 5. Fetch chat and preset
-        let mut chat = self.chat_repo.find_by_id(request.chat_id).await?
+        action.title = Self::action_title(&action.tool, &arguments);
+        action.arguments = arguments;
+        Ok(())
+    }
+
+    /// Performs the side effect and returns the confirmation shown to the user,
+    /// plus how to revert it when that is possible.
+    async fn execute_action(&self, action: &ProposedAction) -> Result<(String, Option<UndoHandle>)> {
+        match action.tool.as_str() {
+            CALENDAR_EVENT_TOOL => {
+                let event: AiCalendarEvent = serde_json::from_value(action.arguments.clone())?;
+                let start = chrono::DateTime::parse_from_rfc3339(&event.start_time)
+                    .map(|dt| dt.with_timezone(&Utc));
+                let end = chrono::DateTime::parse_from_rfc3339(&event.end_time)
+                    .map(|dt| dt.with_timezone(&Utc));
+
+                let (Ok(start), Ok(end)) = (start, end) else {
+                    return Err(anyhow!("Falha ao agendar: Formato de data inválido gerado pela IA."));
+                };
+
+                let saved_event = self.create_event_usecase.execute(
+                    action.user_id,
+                    event.summary,
+                    event.description,
+                    start,
+                    end,
+                    Some(action.chat_id)
+                ).await
+                    .map_err(|e| anyhow!("Falha ao agendar evento: {}", e))?;
+
+                Ok((
+                    format!("Evento agendado: **{}** ({})", saved_event.title, saved_event.start_at.format("%d/%m %H:%M")),
+                    Some(UndoHandle::CalendarEvent { event_id: saved_event.id }),
+                ))
+            }
+            NOTION_PAGE_TOOL => {
+                let page: NotionPageRequest = serde_json::from_value(action.arguments.clone())?;
+                let page_id = self.create_page_usecase.execute(
+                    action.user_id,
+                    page.title,
+                    page.content,
+                    page.parent_id
+                ).await
+                    .map_err(|e| anyhow!("Falha ao criar página no Notion: {}", e))?;
+
+                Ok((
+                    format!("Página criada no Notion! (ID: {})", page_id),
+                    Some(UndoHandle::NotionPage { page_id }),
+                ))
+            }
+            MCP_TOOL => {
+                let call: McpCallArguments = serde_json::from_value(action.arguments.clone())?;
+                let result = self.mcp_manager.call_tool(call.server_id, &call.tool, call.arguments).await?;
+                if result.is_error {
+                    return Err(anyhow!(result.content));
+                }
+                // MCP tools are opaque, there is no generic way to revert them
+                Ok((result.content, None))
+            }
+            other => Err(anyhow!("Unknown action tool: {}", other)),
+        }
+    }
+
+    /// Executes an action, records the outcome on it (the action itself is not
+    /// persisted here) and writes the invocation to the action log.
+    async fn run_action(&self, action: &mut ProposedAction) {
+        let undo_handle = match self.execute_action(action).await {
+            Ok((result, undo_handle)) => {
+                action.status = ActionStatus::Executed;
+                action.result = Some(result);
+                undo_handle
+            }
+            Err(e) => {
+                log::error!("Action '{}' failed: {}", action.title, e);
+                action.status = ActionStatus::Failed;
+                action.result = Some(e.to_string());
+                None
+            }
+        };
+        action.resolved_at = Some(Utc::now());
+
+        let entry = ActionLogEntry {
+            id: Uuid::new_v4(),
+            user_id: action.user_id,
+            chat_id: action.chat_id,
+            message_id: action.message_id,
+            action_id: Some(action.id),
+            tool: action.tool.clone(),
+            arguments: action.arguments.clone(),
+            result: action.result.clone(),
+            succeeded: action.status == ActionStatus::Executed,
+            undo_handle,
+            undone_at: None,
+            created_at: Utc::now(),
+        };
+        if let Err(e) = self.action_log_repo.create(&entry).await {
+            log::error!("Failed to write action log for '{}': {}", action.title, e);
+        }
+    }
+
+    /// Asks the model that proposed an MCP call to answer with the tool output.
+    async fn tool_follow_up(&self, action: &ProposedAction) -> Result<Message> {
+        let provider_name = action.provider_name.clone()
+            .ok_or_else(|| anyhow!("Action has no provider to answer with"))?;
+        let model = action.model.clone()
+            .ok_or_else(|| anyhow!("Action has no model to answer with"))?;
+        let call: McpCallArguments = serde_json::from_value(action.arguments.clone())?;
+
+        let proposing_message = self.message_repo.find_by_id(action.message_id).await?
+            .ok_or_else(|| anyhow!("Message that proposed the action no longer exists"))?;
+        let user_message = match proposing_message.reply_to_id {
+            Some(id) => self.message_repo.find_by_id(id).await?,
+            None => None,
+        }.ok_or_else(|| anyhow!("Original user message not found"))?;
+
+        let user_api_keys = self.user_api_key_repo.find_by_user_id(action.user_id).await?;
+        let (ai_provider, api_key) = self.resolve_provider(&user_api_keys, &provider_name)?;
+
+        let mut chat = self.chat_repo.find_by_id(action.chat_id).await?
             .ok_or_else(|| anyhow!("Chat not found"))?;
-        
-        let mut system_prompt = None;
+
+        let request = ChatServiceRequest {
+            user_id: action.user_id,
+            chat_id: action.chat_id,
+            provider_name: provider_name.clone(),
+            prompt: user_message.content.clone(),
+            model: model.clone(),
+            temperature: None,
+            max_tokens: None,
+            image: None,
+            output_language: None,
+            workspace_id: None,
+            template_values: None,
+            mcp_resources: Vec::new(),
+        };
+        let (mut chat_messages, citations) = self.build_context(&request, &user_message, &chat, false).await?;
+
+        chat_messages.push(ChatMessage {
+            role: "system".to_string(),
+            content: format!(
+                "### RESULTADO DA FERRAMENTA {}/{}{}:\n{}\n\nUse this result to answer the user's last message.",
+                call.server_name,
+                call.tool,
+                if action.status == ActionStatus::Failed { " (ERRO)" } else { "" },
+                action.result.as_deref().unwrap_or_default()
+            ),
+            image: None,
+        });
+
+        let chat_req = ChatCompletionRequest {
+            model: model.clone(),
+            messages: chat_messages,
+            temperature: None,
+            max_tokens: None,
+        };
+
+        let started = Instant::now();
+        let ai_response = ai_provider.chat_completion(&api_key, chat_req).await?;
+        let latency_ms = started.elapsed().as_millis() as i64;
+
+        let content = ai_response.choices.first()
+            .map(|choice| choice.message.content.clone())
+            .ok_or_else(|| anyhow!("No response from AI"))?;
+        let parsed = Self::parse_ai_response(&content);
+
+        let ai_message = Message {
+            id: Uuid::new_v4(),
+            chat_id: action.chat_id,
+            role: "assistant".to_string(),
+            content: format!("🔧 {}/{}\n\n{}", call.server_name, call.tool, parsed.answer),
+            created_at: Utc::now(),
+            summary: None,
+            message_type: "chat".to_string(),
+            importance: 0,
+            reply_to_id: None,
+            is_alternative: false,
+            follow_ups: if parsed.follow_ups.is_empty() { None } else { Some(parsed.follow_ups) },
+            tip: parsed.tip,
+            citations: if citations.is_empty() { None } else { Some(citations) },
+        };
+
+        self.message_repo.create(ai_message.clone()).await?;
+        self.record_generation(&ai_message, &provider_name, &model, latency_ms, &ai_response.usage).await;
+
+        chat.updated_at = Utc::now();
+        if let Err(e) = self.chat_repo.update(chat).await {
+            log::warn!("Failed to update chat timestamp: {}", e);
+        }
+
+        Ok(ai_message)
+    }
+
+    /// Builds the prompt for a user turn: preset and tool instructions, memory,
+    /// history highlights, retrieved documents/code and the recent messages.
+    /// `include_mcp_tools` is off where tool calls would be ignored (compare mode).
+    async fn build_context(
+        &self,
+        request: &ChatServiceRequest,
+        user_message: &Message,
+        chat: &Chat,
+        include_mcp_tools: bool,
+    ) -> Result<(Vec<ChatMessage>, Vec<DocumentCitation>)> {
+        let mut preset = None;
         if let Some(preset_id) = &chat.prompt_preset_id {
-             if let Some(preset) = self.prompt_preset_repo.find_by_id(preset_id).await? {
-                 system_prompt = Some(preset.prompt);
-             }
+             preset = self.prompt_preset_repo.find_by_id(preset_id).await?;
         }
 
-        // 6. Fetch previous messages and build smart context
-        let previous_messages = self.message_repo.find_by_chat_id(request.chat_id).await?;
-        
+        // Fetch only the recent messages sent in full; older ones are represented by highlights
+        let recent_count = 4;
+        let recent_msgs = self.message_repo.find_recent_by_chat_id(request.chat_id, recent_count).await?;
+        let past_count = self.message_repo.count_by_chat_id(request.chat_id).await?
+            .saturating_sub(recent_msgs.len() as i64);
+
         // 6.1 Check if Smart RAG is enabled
         let app_config = self.config_repo.get().await.unwrap_or_default();
+
+        // 6.1.1 Render preset template variables
+        let system_prompt = preset.map(|preset| {
+            let language = request.output_language.as_deref().unwrap_or(&app_config.language);
+            let mut values = template::built_in_values(language, chat.title.as_deref());
+            if let Some(extra) = &request.template_values {
+                values.extend(extra.clone());
+            }
+            template::render(&preset.prompt, &preset.parameters, &values)
+        });
         
-        // 6.2 Fetch Global High-Importance Context (Last 50 chats, Top 6 items) ONLY if enabled
-        let global_summaries = if app_config.enable_smart_rag {
+        // 6.2 Fetch Global High-Importance Context (Last 50 chats, Top 6 items) ONLY if enabled.
+        // Incognito chats neither feed nor read the memory pool.
+        let global_summaries = if app_config.enable_smart_rag && !chat.is_incognito {
             self.message_repo.find_high_importance_summaries(request.user_id, 50, 6).await
                 .unwrap_or_default()
         } else {
@@ -395,32 +829,14 @@ impl ChatService for ChatServiceImpl {
              });
         }
 
-        // 6.3. Split messages into Recent (last 4) and Past
-        let recent_count = 4;
-        let mut all_msgs = previous_messages;
-        
-        let recent_msgs = if all_msgs.len() > recent_count {
-            all_msgs.split_off(all_msgs.len() - recent_count)
-        } else {
-            let m = all_msgs.clone();
-            all_msgs.clear();
-            m
-        };
-        let past_msgs = all_msgs;
-
         // 6.3. Process Past Messages (Summaries + Importance)
-        if !past_msgs.is_empty() {
-            let mut highlights: Vec<_> = past_msgs.iter()
-                .filter(|m| m.summary.is_some() && m.importance > 10)
-                .collect();
-            
-            // Sort by importance DESC
-            highlights.sort_by(|a, b| b.importance.cmp(&a.importance));
-            
+        if past_count > 0 {
             // Take top 5 or 10 depending on history size
-            let limit = if past_msgs.len() > 20 { 10 } else { 5 };
-            let top_highlights = highlights.into_iter().take(limit);
-            
+            let limit = if past_count > 20 { 10 } else { 5 };
+            let top_highlights = self.message_repo
+                .find_highlights_by_chat_id(request.chat_id, 10, recent_count, limit)
+                .await?;
+
             let mut history_context = String::from("### CONTEXTO RELEVANTE DO HISTÓRICO (RESUMIDO):\n");
             let mut found_any = false;
 
@@ -440,7 +856,114 @@ impl ChatService for ChatServiceImpl {
             }
         }
 
-        // 6.4. Add Recent Messages (Full Text)
+        // 6.4. Retrieve relevant document chunks (chat attachments + global library)
+        let document_matches = self.document_repo
+            .search_chunks(request.user_id, request.chat_id, &request.prompt, 5)
+            .await
+            .unwrap_or_else(|e| {
+                log::warn!("Document retrieval failed: {}", e);
+                Vec::new()
+            });
+
+        let mut citations: Vec<_> = document_matches.iter().map(|m| m.citation()).collect();
+
+        if !document_matches.is_empty() {
+            let mut documents_context = String::from(
+                "### DOCUMENTOS RELEVANTES:\nWhen you use these excerpts, cite them as [file:start-end].\n"
+            );
+            for m in &document_matches {
+                documents_context.push_str(&format!(
+                    "\n[{}:{}-{}]\n{}\n",
+                    m.file_name, m.chunk.start_line, m.chunk.end_line, m.chunk.content
+                ));
+            }
+
+            chat_messages.push(ChatMessage {
+                role: "system".to_string(),
+                content: documents_context,
+                image: None,
+            });
+        }
+
+        // 6.5. Retrieve relevant code from the selected workspace
+        if let Some(workspace_id) = request.workspace_id {
+            let workspace = self.workspace_repo.find_by_id(workspace_id).await?;
+            if workspace.map_or(true, |w| w.user_id != request.user_id) {
+                return Err(anyhow!("Workspace not found"));
+            }
+
+            let code_matches = self.workspace_repo
+                .search_chunks(workspace_id, &request.prompt, 6)
+                .await
+                .unwrap_or_else(|e| {
+                    log::warn!("Workspace retrieval failed: {}", e);
+                    Vec::new()
+                });
+
+            if !code_matches.is_empty() {
+                let mut workspace_context = String::from(
+                    "### CONTEXTO DO WORKSPACE:\nWhen you reference this code, cite the file path and lines as [path:start-end].\n"
+                );
+                for m in &code_matches {
+                    let symbol = m.chunk.symbol.as_ref().map(|s| format!(" ({})", s)).unwrap_or_default();
+                    workspace_context.push_str(&format!(
+                        "\n[{}:{}-{}]{}\n```\n{}\n```\n",
+                        m.relative_path, m.chunk.start_line, m.chunk.end_line, symbol, m.chunk.content
+                    ));
+                }
+
+                chat_messages.push(ChatMessage {
+                    role: "system".to_string(),
+                    content: workspace_context,
+                    image: None,
+                });
+
+                citations.extend(code_matches.iter().map(|m| m.citation()));
+            }
+        }
+
+        // 6.5.1. Attached MCP resources
+        let mut resources_context = String::new();
+        for resource in &request.mcp_resources {
+            match self.mcp_manager.read_resource(resource.server_id, &resource.uri).await {
+                Ok(content) => resources_context.push_str(&format!("\n[{}]\n{}\n", resource.uri, content)),
+                Err(e) => log::warn!("Failed to read MCP resource {}: {}", resource.uri, e),
+            }
+        }
+        if !resources_context.is_empty() {
+            chat_messages.push(ChatMessage {
+                role: "system".to_string(),
+                content: format!("### RECURSOS ANEXADOS (MCP):\n{}", resources_context),
+                image: None,
+            });
+        }
+
+        // 6.5.2. Tools from the user's MCP servers (off in incognito chats: actions are persisted)
+        if include_mcp_tools && !chat.is_incognito {
+            let tools = self.mcp_manager.tools_for_user(request.user_id).await;
+            if !tools.is_empty() {
+                let mut tools_context = String::from(
+                    "### FERRAMENTAS EXTERNAS (MCP):\nTo use one of these tools, add a 'tool_call' field to your JSON response: {\"server\": string, \"tool\": string, \"arguments\": object}. The arguments must follow the tool's schema. The user approves each call before it runs and you will then receive its result, so only call a tool when it is needed and say in 'answer' what you are about to do.\n"
+                );
+                for tool in &tools {
+                    tools_context.push_str(&format!(
+                        "\n- {}/{}: {}\n  arguments: {}\n",
+                        tool.server_name,
+                        tool.name,
+                        tool.description.as_deref().unwrap_or(""),
+                        tool.input_schema
+                    ));
+                }
+
+                chat_messages.push(ChatMessage {
+                    role: "system".to_string(),
+                    content: tools_context,
+                    image: None,
+                });
+            }
+        }
+
+        // 6.6. Add Recent Messages (Full Text)
         for msg in recent_msgs {
             let image = if msg.id == user_message.id {
                 request.image.clone()
@@ -454,6 +977,72 @@ impl ChatService for ChatServiceImpl {
             });
         }
 
+        Ok((chat_messages, citations))
+    }
+}
+
+
+
+#[async_trait]
+impl ChatService for ChatServiceImpl {
+    async fn send_message_to_ai(&self, request: ChatServiceRequest) -> Result<(Message, Vec<String>)> {
+        // 1. Get user's API key
+        let user_api_keys = self.user_api_key_repo.find_by_user_id(request.user_id).await?;
+
+        // 2. Select AI provider early (to use for analysis)
+        let (ai_provider, api_key) = self.resolve_provider(&user_api_keys, &request.provider_name)?;
+        let api_key = &api_key;
+
+        // 3. Fetch chat (incognito chats skip analysis and tools)
+        let mut chat = self.chat_repo.find_by_id(request.chat_id).await?
+            .ok_or_else(|| anyhow!("Chat not found"))?;
+
+        // 4. Save user's message first
+        let user_message = Message {
+            id: Uuid::new_v4(),
+            chat_id: request.chat_id,
+            role: "user".to_string(),
+            content: request.prompt.clone(),
+            created_at: Utc::now(),
+            summary: None,
+            message_type: "chat".to_string(),
+            importance: 0,
+            reply_to_id: None,
+            is_alternative: false,
+            follow_ups: None,
+            tip: None,
+            citations: None,
+        };
+        self.message_repo.create(user_message.clone()).await?;
+
+        // 5. Spawn Background Analysis Agent for User Message
+        if !chat.is_incognito {
+            let user_analysis_provider = ai_provider.clone();
+            let user_analysis_api_key = api_key.clone();
+            let user_analysis_model = request.model.clone();
+            let user_analysis_message = user_message.clone();
+            let user_analysis_repo = self.message_repo.clone();
+            let user_analysis_task_repo = self.task_repo.clone();
+            let user_analysis_user_id = request.user_id;
+
+            tokio::spawn(async move {
+                if let Err(e) = Self::analyze_message(
+                    user_analysis_provider,
+                    user_analysis_api_key,
+                    user_analysis_model,
+                    user_analysis_user_id,
+                    user_analysis_message,
+                    user_analysis_repo,
+                    user_analysis_task_repo,
+                ).await {
+                    log::error!("User message background analysis failed: {}", e);
+                }
+            });
+        }
+
+        // 6. Build smart context
+        let (chat_messages, citations) = self.build_context(&request, &user_message, &chat, true).await?;
+
         let chat_req = ChatCompletionRequest {
             model: request.model.clone(),
             messages: chat_messages,
@@ -462,7 +1051,9 @@ impl ChatService for ChatServiceImpl {
         };
 
         // 7. Call AI provider
+        let started = Instant::now();
         let ai_response = ai_provider.chat_completion(api_key, chat_req).await?;
+        let latency_ms = started.elapsed().as_millis() as i64;
 
         // 8. Extract AI response content
         let ai_response_message_content = ai_response.choices.first() 
@@ -474,95 +1065,74 @@ impl ChatService for ChatServiceImpl {
             .unwrap_or_else(|| "assistant".to_string()); 
 
         // Parse JSON response
-        let (mut answer, tip, follow_ups, calendar_event, notion_page) = {
-            let clean_content = ai_response_message_content.trim();
-            
-            // Helper to try parsing a string as AiResponse
-            let try_parse = |s: &str| -> Option<AiResponse> {
-                serde_json::from_str::<AiResponse>(s).ok()
-            };
+        let AiResponse { answer, tip, follow_ups, calendar_event, notion_page, tool_call } =
+            Self::parse_ai_response(&ai_response_message_content);
+        let mut answer = answer;
 
-            // Strategy 1: Direct parse
-            if let Some(parsed) = try_parse(clean_content) {
-                (parsed.answer, parsed.tip, parsed.follow_ups, parsed.calendar_event, parsed.notion_page)
-            } else {
-                // Strategy 2: Locate JSON object bounds { ... }
-                // This handles ```json wrappers and conversational pre/post-ambles
-                let extracted = clean_content.find('{')
-                    .and_then(|start| clean_content.rfind('}').map(|end| (start, end)))
-                    .and_then(|(start, end)| {
-                        if start < end {
-                            try_parse(&clean_content[start..=end])
-                        } else {
-                            None
-                        }
-                    });
-
-                match extracted {
-                    Some(parsed) => (parsed.answer, parsed.tip, parsed.follow_ups, parsed.calendar_event, parsed.notion_page),
-                    None => {
-                        log::warn!("Failed to parse AI JSON response. Fallback to raw text.");
-                        // Fallback: Treat entire content as the answer, no tip/follow-ups
-                        (ai_response_message_content.clone(), None, vec![], None, None)
-                    }
-                }
+        // Proposed actions are persisted, so incognito chats drop tool requests
+        let (calendar_event, notion_page, tool_call) = if chat.is_incognito {
+            if calendar_event.is_some() || notion_page.is_some() || tool_call.is_some() {
+                answer.push_str("\n\n🕶️ Ações e ferramentas ficam desativadas em chats anônimos.");
             }
+            (None, None, None)
+        } else {
+            (calendar_event, notion_page, tool_call)
         };
 
-        // Execute Calendar Tool if present
+        // Tool fields become proposed actions; nothing runs without approval
+        let ai_message_id = Uuid::new_v4();
+        let mut actions = Vec::new();
+
         if let Some(event) = calendar_event {
-            let start = chrono::DateTime::parse_from_rfc3339(&event.start_time)
-                .map(|dt| dt.with_timezone(&Utc));
-            let end = chrono::DateTime::parse_from_rfc3339(&event.end_time)
-                .map(|dt| dt.with_timezone(&Utc));
-
-            match (start, end) {
-                (Ok(s), Ok(e)) => {
-                    match self.create_event_usecase.execute(
-                        request.user_id, 
-                        event.summary, 
-                        event.description, 
-                        s, 
-                        e, 
-                        Some(request.chat_id)
-                    ).await {
-                        Ok(saved_event) => {
-                             answer.push_str(&format!("\n\n✅ Evento agendado: **{}** ({})", saved_event.title, saved_event.start_at.format("%d/%m %H:%M")));
-                        },
-                        Err(err) => {
-                             answer.push_str(&format!("\n\n❌ Falha ao agendar evento: {}", err));
-                             log::error!("Failed to create calendar event from AI: {}", err);
-                        }
-                    }
-                },
-                _ => {
-                    answer.push_str("\n\n❌ Falha ao agendar: Formato de data inválido gerado pela IA.");
+            actions.push(Self::new_action(&request, ai_message_id, CALENDAR_EVENT_TOOL, serde_json::to_value(&event)?));
+        }
+
+        if let Some(page) = notion_page {
+            actions.push(Self::new_action(&request, ai_message_id, NOTION_PAGE_TOOL, serde_json::to_value(&page)?));
+        }
+
+        if let Some(call) = tool_call {
+            let tools = self.mcp_manager.tools_for_user(request.user_id).await;
+            match tools.into_iter().find(|t| t.server_name.eq_ignore_ascii_case(&call.server) && t.name == call.tool) {
+                Some(tool) => {
+                    let arguments = McpCallArguments {
+                        server_id: tool.server_id,
+                        server_name: tool.server_name,
+                        tool: tool.name,
+                        arguments: call.arguments,
+                    };
+                    actions.push(Self::new_action(&request, ai_message_id, MCP_TOOL, serde_json::to_value(&arguments)?));
+                }
+                None => {
+                    answer.push_str(&format!("\n\n❌ Ferramenta MCP indisponível: {}/{}", call.server, call.tool));
+                    log::warn!("AI requested unknown MCP tool {}/{}", call.server, call.tool);
                 }
             }
         }
 
-        // Execute Notion Tool if present
-        if let Some(page) = notion_page {
-            match self.create_page_usecase.execute(
-                request.user_id,
-                page.title,
-                page.content,
-                page.parent_id
-            ).await {
-                Ok(page_id) => {
-                    // We don't have the URL easily unless we reconstruct it or fetch it, but usually ID is enough for confirmation
-                    answer.push_str(&format!("\n\n✅ Página criada no Notion! (ID: {})", page_id));
-                },
-                Err(err) => {
-                    answer.push_str(&format!("\n\n❌ Falha ao criar página no Notion: {}", err));
-                    log::error!("Failed to create Notion page from AI: {}", err);
+        // Auto-approved calendar/Notion actions run now so the confirmation is part of the answer.
+        // Auto-approved MCP calls run after the message is saved, since they add a follow-up reply.
+        let auto_approve_tools = self.config_repo.get().await.unwrap_or_default().auto_approve_tools;
+        for action in actions.iter_mut() {
+            let auto_approved = auto_approve_tools.contains(&action.approval_key());
+            if auto_approved && action.tool != MCP_TOOL {
+                self.run_action(action).await;
+                let result = action.result.clone().unwrap_or_default();
+                if action.status == ActionStatus::Executed {
+                    answer.push_str(&format!("\n\n✅ {}", result));
+                } else {
+                    answer.push_str(&format!("\n\n❌ {}: {}", action.title, result));
                 }
+            } else if auto_approved {
+                answer.push_str(&format!("\n\n🔧 {}", action.title));
+            } else {
+                answer.push_str(&format!("\n\n⏳ Aguardando sua aprovação: {}", action.title));
             }
         }
 
         // 9. Save AI response to message repository (only content)
         let ai_message = Message {
-            id: Uuid::new_v4(),
+            id: ai_message_id,
             chat_id: request.chat_id,
             role: ai_response_message_role,
             content: answer.clone(), // Clone here to use in spawn
@@ -570,30 +1140,57 @@ impl ChatService for ChatServiceImpl {
             summary: None,
             message_type: "chat".to_string(),
             importance: 0,
+            reply_to_id: Some(user_message.id),
+            is_alternative: false,
             follow_ups: if follow_ups.is_empty() { None } else { Some(follow_ups.clone()) },
             tip: tip.clone(),
+            citations: if citations.is_empty() { None } else { Some(citations) },
         };
 
         self.message_repo.create(ai_message.clone()).await?;
+        self.record_generation(&ai_message, &request.provider_name, &request.model, latency_ms, &ai_response.usage).await;
 
-        // 10. Spawn Background Analysis Agent for AI Response
-        let analysis_provider = ai_provider.clone();
-        let analysis_api_key = api_key.clone();
-        let analysis_model = request.model.clone();
-        let analysis_message = ai_message.clone();
-        let analysis_repo = self.message_repo.clone();
-
-        tokio::spawn(async move {
-            if let Err(e) = Self::analyze_message(
-                analysis_provider,
-                analysis_api_key,
-                analysis_model,
-                analysis_message,
-                analysis_repo
-            ).await {
-                log::error!("AI response background analysis failed: {}", e);
+        for action in &actions {
+            if let Err(e) = self.action_repo.create(action).await {
+                log::error!("Failed to save proposed action '{}': {}", action.title, e);
+                continue;
             }
-        });
+            if action.status != ActionStatus::Pending {
+                continue;
+            }
+            if auto_approve_tools.contains(&action.approval_key()) {
+                if let Err(e) = self.approve_action(action.user_id, action.id, None).await {
+                    log::error!("Auto-approved action '{}' failed: {}", action.title, e);
+                }
+            } else {
+                self.action_notifier.notify(action);
+            }
+        }
+
+        // 10. Spawn Background Analysis Agent for AI Response
+        if !chat.is_incognito {
+            let analysis_provider = ai_provider.clone();
+            let analysis_api_key = api_key.clone();
+            let analysis_model = request.model.clone();
+            let analysis_message = ai_message.clone();
+            let analysis_repo = self.message_repo.clone();
+            let analysis_task_repo = self.task_repo.clone();
+            let analysis_user_id = request.user_id;
+
+            tokio::spawn(async move {
+                if let Err(e) = Self::analyze_message(
+                    analysis_provider,
+                    analysis_api_key,
+                    analysis_model,
+                    analysis_user_id,
+                    analysis_message,
+                    analysis_repo,
+                    analysis_task_repo,
+                ).await {
+                    log::error!("AI response background analysis failed: {}", e);
+                }
+            });
+        }
 
         // Update chat timestamp
         chat.updated_at = Utc::now();
@@ -603,4 +1200,214 @@ impl ChatService for ChatServiceImpl {
 
         Ok((ai_message, follow_ups))
     }
+
+    async fn compare_models(&self, request: ChatServiceRequest, targets: Vec<CompareTarget>) -> Result<CompareResult> {
+        if targets.len() < 2 {
+            return Err(anyhow!("Compare mode needs at least two models"));
+        }
+
+        let user_api_keys = self.user_api_key_repo.find_by_user_id(request.user_id).await?;
+
+        // Fail before saving anything if a target has no usable key
+        for target in &targets {
+            self.resolve_provider(&user_api_keys, &target.provider_name)?;
+        }
+
+        let mut chat = self.chat_repo.find_by_id(request.chat_id).await?
+            .ok_or_else(|| anyhow!("Chat not found"))?;
+
+        let user_message = Message {
+            id: Uuid::new_v4(),
+            chat_id: request.chat_id,
+            role: "user".to_string(),
+            content: request.prompt.clone(),
+            created_at: Utc::now(),
+            summary: None,
+            message_type: "chat".to_string(),
+            importance: 0,
+            reply_to_id: None,
+            is_alternative: false,
+            follow_ups: None,
+            tip: None,
+            citations: None,
+        };
+        self.message_repo.create(user_message.clone()).await?;
+
+        // The user message is analyzed once, with the first model
+        if !chat.is_incognito {
+            let (analysis_provider, analysis_api_key) = self.resolve_provider(&user_api_keys, &targets[0].provider_name)?;
+            let analysis_model = targets[0].model.clone();
+            let analysis_message = user_message.clone();
+            let analysis_repo = self.message_repo.clone();
+            let analysis_task_repo = self.task_repo.clone();
+            let analysis_user_id = request.user_id;
+
+            tokio::spawn(async move {
+                if let Err(e) = Self::analyze_message(
+                    analysis_provider,
+                    analysis_api_key,
+                    analysis_model,
+                    analysis_user_id,
+                    analysis_message,
+                    analysis_repo,
+                    analysis_task_repo,
+                ).await {
+                    log::error!("User message background analysis failed: {}", e);
+                }
+            });
+        }
+
+        // Every model sees exactly the same context
+        let (chat_messages, citations) = self.build_context(&request, &user_message, &chat, false).await?;
+
+        let results = futures_util::future::join_all(targets.iter().map(|target| {
+            self.generate_alternative(&user_api_keys, &request, &user_message, chat_messages.clone(), &citations, target)
+        })).await;
+
+        let candidates = targets.into_iter()
+            .zip(results)
+            .map(|(target, result)| match result {
+                Ok((message, generation)) => CompareCandidate {
+                    provider_name: target.provider_name,
+                    model: target.model,
+                    message: Some(message),
+                    generation,
+                    error: None,
+                },
+                Err(e) => {
+                    log::warn!("Compare mode: {} / {} failed: {}", target.provider_name, target.model, e);
+                    CompareCandidate {
+                        provider_name: target.provider_name,
+                        model: target.model,
+                        message: None,
+                        generation: None,
+                        error: Some(e.to_string()),
+                    }
+                }
+            })
+            .collect();
+
+        chat.updated_at = Utc::now();
+        if let Err(e) = self.chat_repo.update(chat).await {
+            log::warn!("Failed to update chat timestamp: {}", e);
+        }
+
+        Ok(CompareResult { user_message, candidates })
+    }
+
+    async fn approve_action(&self, user_id: Uuid, action_id: Uuid, arguments: Option<serde_json::Value>) -> Result<ActionOutcome> {
+        let mut action = self.action_repo.find_by_id(action_id).await?
+            .filter(|action| action.user_id == user_id)
+            .ok_or_else(|| anyhow!("Action not found"))?;
+
+        if action.status != ActionStatus::Pending {
+            return Err(anyhow!("Action was already {}", action.status.as_str()));
+        }
+
+        if let Some(arguments) = arguments {
+            Self::apply_edit(&mut action, arguments)?;
+        }
+
+        // Claim the action first so that approvals racing each other (or an
+        // auto-approval and a manual one) run it once
+        if !self.action_repo.claim_pending(action.id, ActionStatus::Approved).await? {
+            return Err(anyhow!("Action was already resolved"));
+        }
+
+        self.run_action(&mut action).await;
+        self.action_repo.update(&action).await?;
+        self.action_notifier.notify(&action);
+
+        // The model only sees the MCP tool output through a follow-up reply
+        let follow_up = if action.tool == MCP_TOOL {
+            match self.tool_follow_up(&action).await {
+                Ok(message) => Some(message),
+                Err(e) => {
+                    log::warn!("Failed to generate follow-up for '{}': {}", action.title, e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        Ok(ActionOutcome { action, follow_up })
+    }
+
+    async fn extract_tasks(&self, user_id: Uuid, chat_id: Uuid, provider_name: &str, model: &str) -> Result<Vec<Task>> {
+        let chat = self.chat_repo.find_by_id(chat_id).await?
+            .filter(|chat| chat.user_id == user_id)
+            .ok_or_else(|| anyhow!("Chat not found"))?;
+        if chat.is_incognito {
+            return Err(anyhow!("Tasks can't be extracted from incognito chats"));
+        }
+
+        let user_api_keys = self.user_api_key_repo.find_by_user_id(user_id).await?;
+        let (ai_provider, api_key) = self.resolve_provider(&user_api_keys, provider_name)?;
+
+        let messages = self.message_repo.find_recent_by_chat_id(chat_id, TASK_EXTRACTION_MESSAGES).await?;
+        if messages.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Messages are numbered so the model can point at the one each task came from
+        let transcript = messages.iter()
+            .enumerate()
+            .map(|(i, m)| {
+                let content: String = m.content.chars().take(2_000).collect();
+                format!("[{}] {}: {}", i, m.role, content)
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let message_ids: Vec<Uuid> = messages.iter().map(|m| m.id).collect();
+
+        let system_prompt = format!(
+            r#"
+            You extract action items from a conversation between a user and an assistant.
+            List the todos the user committed to, was asked to do, or asked to be reminded of.
+            Ignore suggestions the user didn't take up and tasks that are clearly already done.
+
+            Return JSON: {{"tasks": [{{"title": short imperative sentence in the conversation's language,
+            "due_date": "YYYY-MM-DD" or "YYYY-MM-DDTHH:MM" or null, "notes": string or null,
+            "message": number of the message the task comes from}}]}}
+            Return {{"tasks": []}} when there are none.
+            Today is {}.
+            "#,
+            chrono::Local::now().format("%Y-%m-%d (%A)")
+        );
+
+        let request = ChatCompletionRequest {
+            model: model.to_string(),
+            messages: vec![
+                ChatMessage {
+                    role: "system".to_string(),
+                    content: system_prompt,
+                    image: None,
+                },
+                ChatMessage {
+                    role: "user".to_string(),
+                    content: transcript,
+                    image: None,
+                }
+            ],
+            temperature: Some(0.1),
+            max_tokens: Some(1500),
+        };
+
+        let response = ai_provider.chat_completion(&api_key, request).await?;
+        let content = response.choices.first()
+            .map(|choice| choice.message.content.clone())
+            .unwrap_or_default();
+        let clean_content = match (content.find('{'), content.rfind('}')) {
+            (Some(start), Some(end)) if start <= end => &content[start..=end],
+            _ => content.trim(),
+        };
+
+        let extraction: TaskExtraction = serde_json::from_str(clean_content)
+            .map_err(|e| anyhow!("Failed to parse extracted tasks: {}", e))?;
+
+        RecordExtractedTasksUseCase::new(self.task_repo.clone())
+            .execute(user_id, chat_id, None, &message_ids, extraction.tasks)
+            .await
+    }
 }
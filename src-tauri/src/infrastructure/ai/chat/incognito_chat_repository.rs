@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::domain::ai::chat::entity::{chat::Chat, page::PageCursor};
+use crate::domain::ai::chat::repository::chat_repository::ChatRepository;
+use super::incognito_store::IncognitoStore;
+
+/// Keeps incognito chats in memory and passes every other chat through to `inner`.
+pub struct IncognitoChatRepository {
+    inner: Arc<dyn ChatRepository>,
+    store: Arc<IncognitoStore>,
+}
+
+impl IncognitoChatRepository {
+    pub fn new(inner: Arc<dyn ChatRepository>, store: Arc<IncognitoStore>) -> Self {
+        Self { inner, store }
+    }
+}
+
+#[async_trait]
+impl ChatRepository for IncognitoChatRepository {
+    async fn create(&self, chat: Chat) -> Result<Chat> {
+        if chat.is_incognito {
+            self.store.insert_chat(chat.clone());
+            return Ok(chat);
+        }
+        self.inner.create(chat).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Chat>> {
+        match self.store.chat(id) {
+            Some(chat) => Ok(Some(chat)),
+            None => self.inner.find_by_id(id).await,
+        }
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Chat>> {
+        let mut chats = self.inner.find_by_user_id(user_id).await?;
+        chats.extend(self.store.chats_for_user(user_id));
+        chats.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(chats)
+    }
+
+    async fn find_page_by_user_id(&self, user_id: Uuid, before: Option<PageCursor>, limit: i64) -> Result<Vec<Chat>> {
+        let mut chats = self.inner.find_page_by_user_id(user_id, before.clone(), limit).await?;
+        chats.extend(
+            self.store.chats_for_user(user_id)
+                .into_iter()
+                .filter(|c| before.as_ref().map_or(true, |cursor| cursor.precedes(c.created_at, c.id)))
+        );
+        chats.sort_by(|a, b| (b.created_at, b.id).cmp(&(a.created_at, a.id)));
+        chats.truncate(limit.max(0) as usize);
+        Ok(chats)
+    }
+
+    async fn update(&self, chat: Chat) -> Result<Chat> {
+        if self.store.update_chat(&chat) {
+            return Ok(chat);
+        }
+        self.inner.update(chat).await
+    }
+
+    async fn set_pinned(&self, id: Uuid, pinned: bool) -> Result<()> {
+        if let Some(mut chat) = self.store.chat(id) {
+            chat.is_pinned = pinned;
+            self.store.update_chat(&chat);
+            return Ok(());
+        }
+        self.inner.set_pinned(id, pinned).await
+    }
+
+    async fn set_tags(&self, id: Uuid, tags: Vec<String>) -> Result<()> {
+        if let Some(mut chat) = self.store.chat(id) {
+            chat.tags = tags;
+            self.store.update_chat(&chat);
+            return Ok(());
+        }
+        self.inner.set_tags(id, tags).await
+    }
+
+    async fn set_archived(&self, id: Uuid, archived_at: Option<DateTime<Utc>>) -> Result<()> {
+        if let Some(mut chat) = self.store.chat(id) {
+            chat.archived_at = archived_at;
+            self.store.update_chat(&chat);
+            return Ok(());
+        }
+        self.inner.set_archived(id, archived_at).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        if self.store.close(id) {
+            return Ok(());
+        }
+        self.inner.delete(id).await
+    }
+
+    async fn delete_all_by_user_id(&self, user_id: Uuid) -> Result<()> {
+        self.store.remove_user_chats(user_id);
+        self.inner.delete_all_by_user_id(user_id).await
+    }
+}
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use anyhow::Result;
+use uuid::Uuid;
+use crate::domain::ai::chat::entity::{generation::MessageGeneration, message::Message, page::PageCursor};
+use crate::domain::ai::chat::repository::message_repository::MessageRepository;
+use super::incognito_store::IncognitoStore;
+
+/// Keeps messages of incognito chats in memory and passes everything else to `inner`.
+/// Memory lookups (Smart RAG) only ever see `inner`, so incognito messages never
+/// reach other chats.
+pub struct IncognitoMessageRepository {
+    inner: Arc<dyn MessageRepository>,
+    store: Arc<IncognitoStore>,
+}
+
+impl IncognitoMessageRepository {
+    pub fn new(inner: Arc<dyn MessageRepository>, store: Arc<IncognitoStore>) -> Self {
+        Self { inner, store }
+    }
+}
+
+#[async_trait]
+impl MessageRepository for IncognitoMessageRepository {
+    async fn create(&self, message: Message) -> Result<Message> {
+        if self.store.insert_message(&message) {
+            return Ok(message);
+        }
+        self.inner.create(message).await
+    }
+
+    async fn find_by_chat_id(&self, chat_id: Uuid) -> Result<Vec<Message>> {
+        match self.store.messages(chat_id) {
+            Some(messages) => Ok(messages.into_iter().filter(|m| !m.is_alternative).collect()),
+            None => self.inner.find_by_chat_id(chat_id).await,
+        }
+    }
+
+    async fn find_page_by_chat_id(&self, chat_id: Uuid, before: Option<PageCursor>, limit: i64) -> Result<Vec<Message>> {
+        let messages = match self.store.messages(chat_id) {
+            Some(messages) => messages,
+            None => return self.inner.find_page_by_chat_id(chat_id, before, limit).await,
+        };
+        let mut page: Vec<Message> = messages.into_iter()
+            .filter(|m| !m.is_alternative)
+            .filter(|m| before.as_ref().map_or(true, |cursor| cursor.precedes(m.created_at, m.id)))
+            .collect();
+        let skip = page.len().saturating_sub(limit.max(0) as usize);
+        Ok(page.split_off(skip))
+    }
+
+    async fn find_recent_by_chat_id(&self, chat_id: Uuid, limit: i64) -> Result<Vec<Message>> {
+        self.find_page_by_chat_id(chat_id, None, limit).await
+    }
+
+    async fn find_highlights_by_chat_id(&self, chat_id: Uuid, min_importance: i32, skip_recent: i64, limit: i64) -> Result<Vec<Message>> {
+        let messages = match self.store.messages(chat_id) {
+            Some(messages) => messages,
+            None => return self.inner.find_highlights_by_chat_id(chat_id, min_importance, skip_recent, limit).await,
+        };
+        let mut past: Vec<Message> = messages.into_iter().filter(|m| !m.is_alternative).collect();
+        past.truncate(past.len().saturating_sub(skip_recent.max(0) as usize));
+        let mut highlights: Vec<Message> = past.into_iter()
+            .filter(|m| m.summary.is_some() && m.importance > min_importance)
+            .collect();
+        highlights.sort_by(|a, b| b.importance.cmp(&a.importance).then(b.created_at.cmp(&a.created_at)));
+        highlights.truncate(limit.max(0) as usize);
+        Ok(highlights)
+    }
+
+    async fn count_by_chat_id(&self, chat_id: Uuid) -> Result<i64> {
+        match self.store.messages(chat_id) {
+            Some(messages) => Ok(messages.iter().filter(|m| !m.is_alternative).count() as i64),
+            None => self.inner.count_by_chat_id(chat_id).await,
+        }
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Message>> {
+        match self.store.message(id) {
+            Some(message) => Ok(Some(message)),
+            None => self.inner.find_by_id(id).await,
+        }
+    }
+
+    async fn find_alternatives(&self, reply_to_id: Uuid) -> Result<Vec<Message>> {
+        let message = match self.store.message(reply_to_id) {
+            Some(message) => message,
+            None => return self.inner.find_alternatives(reply_to_id).await,
+        };
+        let messages = self.store.messages(message.chat_id).unwrap_or_default();
+        Ok(messages.into_iter().filter(|m| m.reply_to_id == Some(reply_to_id)).collect())
+    }
+
+    async fn set_canonical_reply(&self, reply_to_id: Uuid, message_id: Uuid) -> Result<()> {
+        let updated = self.store.update_messages(
+            |m| m.reply_to_id == Some(reply_to_id),
+            |m| m.is_alternative = m.id != message_id,
+        );
+        if updated {
+            return Ok(());
+        }
+        self.inner.set_canonical_reply(reply_to_id, message_id).await
+    }
+
+    async fn save_generation(&self, generation: &MessageGeneration) -> Result<()> {
+        if self.store.save_generation(generation) {
+            return Ok(());
+        }
+        self.inner.save_generation(generation).await
+    }
+
+    async fn find_generation(&self, message_id: Uuid) -> Result<Option<MessageGeneration>> {
+        if self.store.contains_message(message_id) {
+            return Ok(self.store.generation(message_id));
+        }
+        self.inner.find_generation(message_id).await
+    }
+
+    async fn update(&self, message: Message) -> Result<Message> {
+        let id = message.id;
+        let updated = message.clone();
+        if self.store.update_messages(|m| m.id == id, |m| *m = updated.clone()) {
+            return Ok(message);
+        }
+        self.inner.update(message).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        if self.store.remove_message(id) {
+            return Ok(());
+        }
+        self.inner.delete(id).await
+    }
+
+    async fn find_high_importance_summaries(&self, user_id: Uuid, limit_chats: i32, top_k: i32) -> Result<Vec<Message>> {
+        self.inner.find_high_importance_summaries(user_id, limit_chats, top_k).await
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+use uuid::Uuid;
+use crate::domain::ai::chat::entity::{chat::Chat, generation::MessageGeneration, message::Message};
+
+struct IncognitoChat {
+    chat: Chat,
+    messages: Vec<Message>,
+    generations: HashMap<Uuid, MessageGeneration>,
+}
+
+/// Incognito chats and their messages. They only live here, for the lifetime of
+/// the process, and are dropped as soon as the chat is closed or deleted.
+pub struct IncognitoStore {
+    chats: RwLock<HashMap<Uuid, IncognitoChat>>,
+}
+
+impl Default for IncognitoStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IncognitoStore {
+    pub fn new() -> Self {
+        Self { chats: RwLock::new(HashMap::new()) }
+    }
+
+    pub fn contains_chat(&self, chat_id: Uuid) -> bool {
+        self.chats.read().unwrap().contains_key(&chat_id)
+    }
+
+    pub fn contains_message(&self, message_id: Uuid) -> bool {
+        self.chats.read().unwrap().values()
+            .any(|c| c.messages.iter().any(|m| m.id == message_id))
+    }
+
+    /// Wipes a chat and everything said in it. Returns whether it existed.
+    pub fn close(&self, chat_id: Uuid) -> bool {
+        self.chats.write().unwrap().remove(&chat_id).is_some()
+    }
+
+    pub fn clear(&self) {
+        self.chats.write().unwrap().clear();
+    }
+
+    pub(crate) fn insert_chat(&self, chat: Chat) {
+        self.chats.write().unwrap().insert(chat.id, IncognitoChat {
+            chat,
+            messages: Vec::new(),
+            generations: HashMap::new(),
+        });
+    }
+
+    pub(crate) fn chat(&self, chat_id: Uuid) -> Option<Chat> {
+        self.chats.read().unwrap().get(&chat_id).map(|c| c.chat.clone())
+    }
+
+    pub(crate) fn chats_for_user(&self, user_id: Uuid) -> Vec<Chat> {
+        self.chats.read().unwrap().values()
+            .filter(|c| c.chat.user_id == user_id)
+            .map(|c| c.chat.clone())
+            .collect()
+    }
+
+    /// Returns false when the chat is not incognito.
+    pub(crate) fn update_chat(&self, chat: &Chat) -> bool {
+        match self.chats.write().unwrap().get_mut(&chat.id) {
+            Some(entry) => {
+                entry.chat = chat.clone();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn remove_user_chats(&self, user_id: Uuid) {
+        self.chats.write().unwrap().retain(|_, c| c.chat.user_id != user_id);
+    }
+
+    /// Returns false when the message's chat is not incognito.
+    pub(crate) fn insert_message(&self, message: &Message) -> bool {
+        match self.chats.write().unwrap().get_mut(&message.chat_id) {
+            Some(entry) => {
+                entry.messages.push(message.clone());
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn messages(&self, chat_id: Uuid) -> Option<Vec<Message>> {
+        self.chats.read().unwrap().get(&chat_id).map(|c| c.messages.clone())
+    }
+
+    pub(crate) fn message(&self, message_id: Uuid) -> Option<Message> {
+        self.chats.read().unwrap().values()
+            .flat_map(|c| c.messages.iter())
+            .find(|m| m.id == message_id)
+            .cloned()
+    }
+
+    /// Applies `f` to every message matching `predicate`. Returns false when none matched.
+    pub(crate) fn update_messages(&self, predicate: impl Fn(&Message) -> bool, f: impl Fn(&mut Message)) -> bool {
+        let mut chats = self.chats.write().unwrap();
+        let mut found = false;
+        for message in chats.values_mut().flat_map(|c| c.messages.iter_mut()) {
+            if predicate(message) {
+                f(message);
+                found = true;
+            }
+        }
+        found
+    }
+
+    pub(crate) fn remove_message(&self, message_id: Uuid) -> bool {
+        let mut chats = self.chats.write().unwrap();
+        for entry in chats.values_mut() {
+            if let Some(pos) = entry.messages.iter().position(|m| m.id == message_id) {
+                entry.messages.remove(pos);
+                entry.generations.remove(&message_id);
+                return true;
+            }
+        }
+        false
+    }
+
+    pub(crate) fn save_generation(&self, generation: &MessageGeneration) -> bool {
+        let mut chats = self.chats.write().unwrap();
+        match chats.values_mut().find(|c| c.messages.iter().any(|m| m.id == generation.message_id)) {
+            Some(entry) => {
+                entry.generations.insert(generation.message_id, generation.clone());
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn generation(&self, message_id: Uuid) -> Option<MessageGeneration> {
+        self.chats.read().unwrap().values()
+            .find_map(|c| c.generations.get(&message_id).cloned())
+    }
+}
@@ -4,3 +4,6 @@ pub mod chat_service_impl;
 pub mod message_repository_impl;
 pub mod sqlite_chat_repository;
 pub mod sqlite_message_repository;
+pub mod incognito_store;
+pub mod incognito_chat_repository;
+pub mod incognito_message_repository;
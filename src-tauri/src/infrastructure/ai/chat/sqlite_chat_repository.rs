@@ -3,7 +3,8 @@ use sqlx::SqlitePool;
 use anyhow::Result;
 use sqlx::Row;
 use uuid::Uuid;
-use crate::domain::ai::chat::entity::chat::Chat;
+use chrono::{DateTime, Utc};
+use crate::domain::ai::chat::entity::{chat::Chat, page::PageCursor};
 use crate::domain::ai::chat::repository::chat_repository::ChatRepository;
 
 pub struct SqliteChatRepository {
@@ -14,6 +15,26 @@ impl SqliteChatRepository {
     pub fn new(pool: SqlitePool) -> Self {
         Self { pool }
     }
+
+    fn map_chat(row: sqlx::sqlite::SqliteRow) -> Result<Chat, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let user_id_str: String = row.get("user_id");
+        let tags_str: String = row.get("tags");
+
+        Ok(Chat {
+            id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            user_id: Uuid::parse_str(&user_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            title: row.get("title"),
+            prompt_preset_id: row.get("prompt_preset_id"),
+            model: row.get("model"),
+            is_incognito: false,
+            is_pinned: row.get("pinned"),
+            tags: serde_json::from_str(&tags_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            archived_at: row.get("archived_at"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
 }
 
 #[async_trait]
@@ -21,8 +42,8 @@ impl ChatRepository for SqliteChatRepository {
     async fn create(&self, chat: Chat) -> Result<Chat> {
         sqlx::query(
             r#"
-            INSERT INTO chats (id, user_id, title, prompt_preset_id, model, created_at, updated_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            INSERT INTO chats (id, user_id, title, prompt_preset_id, model, pinned, tags, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
             "#
         )
         .bind(chat.id.to_string())
@@ -30,6 +51,8 @@ impl ChatRepository for SqliteChatRepository {
         .bind(chat.title.clone())
         .bind(chat.prompt_preset_id.clone())
         .bind(chat.model.clone())
+        .bind(chat.is_pinned)
+        .bind(serde_json::to_string(&chat.tags)?)
         .bind(chat.created_at)
         .bind(chat.updated_at)
         .execute(&self.pool)
@@ -41,26 +64,13 @@ impl ChatRepository for SqliteChatRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Chat>> {
         let record = sqlx::query(
             r#"
-            SELECT id, user_id, title, prompt_preset_id, model, created_at, updated_at
+            SELECT id, user_id, title, prompt_preset_id, model, pinned, tags, archived_at, created_at, updated_at
             FROM chats
             WHERE id = ?1
             "#
         )
         .bind(id.to_string())
-        .try_map(|row: sqlx::sqlite::SqliteRow| {
-            let id_str: String = row.get("id");
-            let user_id_str: String = row.get("user_id");
-
-            Ok(Chat {
-                id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
-                user_id: Uuid::parse_str(&user_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
-                title: row.get("title"),
-                prompt_preset_id: row.get("prompt_preset_id"),
-                model: row.get("model"),
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            })
-        })
+        .try_map(Self::map_chat)
         .fetch_optional(&self.pool)
         .await?;
 
@@ -70,27 +80,36 @@ impl ChatRepository for SqliteChatRepository {
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Chat>> {
         let records = sqlx::query(
             r#"
-            SELECT id, user_id, title, prompt_preset_id, model, created_at, updated_at
+            SELECT id, user_id, title, prompt_preset_id, model, pinned, tags, archived_at, created_at, updated_at
             FROM chats
             WHERE user_id = ?1
             ORDER BY created_at DESC
             "#
         )
         .bind(user_id.to_string())
-        .try_map(|row: sqlx::sqlite::SqliteRow| {
-            let id_str: String = row.get("id");
-            let user_id_str: String = row.get("user_id");
-
-            Ok(Chat {
-                id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
-                user_id: Uuid::parse_str(&user_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
-                title: row.get("title"),
-                prompt_preset_id: row.get("prompt_preset_id"),
-                model: row.get("model"),
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            })
-        })
+        .try_map(Self::map_chat)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn find_page_by_user_id(&self, user_id: Uuid, before: Option<PageCursor>, limit: i64) -> Result<Vec<Chat>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, user_id, title, prompt_preset_id, model, pinned, tags, archived_at, created_at, updated_at
+            FROM chats
+            WHERE user_id = ?1
+              AND (?2 IS NULL OR created_at < ?2 OR (created_at = ?2 AND id < ?3))
+            ORDER BY created_at DESC, id DESC
+            LIMIT ?4
+            "#
+        )
+        .bind(user_id.to_string())
+        .bind(before.as_ref().map(|c| c.created_at))
+        .bind(before.as_ref().map(|c| c.id.to_string()))
+        .bind(limit)
+        .try_map(Self::map_chat)
         .fetch_all(&self.pool)
         .await?;
 
@@ -115,6 +134,36 @@ impl ChatRepository for SqliteChatRepository {
         Ok(chat)
     }
 
+    async fn set_pinned(&self, id: Uuid, pinned: bool) -> Result<()> {
+        sqlx::query("UPDATE chats SET pinned = ?1 WHERE id = ?2")
+            .bind(pinned)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_tags(&self, id: Uuid, tags: Vec<String>) -> Result<()> {
+        sqlx::query("UPDATE chats SET tags = ?1 WHERE id = ?2")
+            .bind(serde_json::to_string(&tags)?)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_archived(&self, id: Uuid, archived_at: Option<DateTime<Utc>>) -> Result<()> {
+        sqlx::query("UPDATE chats SET archived_at = ?1 WHERE id = ?2")
+            .bind(archived_at)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
     async fn delete(&self, id: Uuid) -> Result<()> {
         sqlx::query(
             r#"
@@ -3,8 +3,9 @@ use sqlx::SqlitePool;
 use anyhow::Result;
 use sqlx::Row;
 use uuid::Uuid;
-use crate::domain::ai::chat::entity::message::Message;
+use crate::domain::ai::chat::entity::{generation::MessageGeneration, message::Message, page::PageCursor};
 use crate::domain::ai::chat::repository::message_repository::MessageRepository;
+use crate::domain::document::entity::DocumentCitation;
 
 pub struct SqliteMessageRepository {
     pool: SqlitePool,
@@ -14,6 +15,41 @@ impl SqliteMessageRepository {
     pub fn new(pool: SqlitePool) -> Self {
         Self { pool }
     }
+
+    fn map_message(row: sqlx::sqlite::SqliteRow) -> Result<Message, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let chat_id_str: String = row.get("chat_id");
+        let follow_ups_json: Option<String> = row.get("follow_ups");
+        let reply_to_id_str: Option<String> = row.get("reply_to_id");
+        let citations_json: Option<String> = row.get("citations");
+
+        // Deserialize follow_ups from JSON
+        let follow_ups = follow_ups_json.and_then(|json| {
+            serde_json::from_str::<Vec<String>>(&json).ok()
+        });
+        let citations = citations_json.and_then(|json| {
+            serde_json::from_str::<Vec<DocumentCitation>>(&json).ok()
+        });
+
+        Ok(Message {
+            id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            chat_id: Uuid::parse_str(&chat_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            role: row.get("role"),
+            content: row.get("content"),
+            created_at: row.get("created_at"),
+            summary: row.get("summary"),
+            message_type: row.get("message_type"),
+            importance: row.get("importance"),
+            reply_to_id: reply_to_id_str
+                .map(|s| Uuid::parse_str(&s))
+                .transpose()
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            is_alternative: row.get("is_alternative"),
+            follow_ups,
+            tip: None, // Tips are not persisted, recalculated per-request
+            citations,
+        })
+    }
 }
 
 #[async_trait]
@@ -22,11 +58,13 @@ impl MessageRepository for SqliteMessageRepository {
         // Serialize follow_ups to JSON string
         let follow_ups_json = message.follow_ups.as_ref()
             .map(|f| serde_json::to_string(f).unwrap_or_default());
+        let citations_json = message.citations.as_ref()
+            .map(|c| serde_json::to_string(c).unwrap_or_default());
 
         sqlx::query(
             r#"
-            INSERT INTO messages (id, chat_id, role, content, created_at, summary, message_type, importance, follow_ups)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            INSERT INTO messages (id, chat_id, role, content, created_at, summary, message_type, importance, follow_ups, reply_to_id, is_alternative, citations)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
             "#
         )
         .bind(message.id.to_string())
@@ -38,6 +76,9 @@ impl MessageRepository for SqliteMessageRepository {
         .bind(message.message_type.clone())
         .bind(message.importance)
         .bind(follow_ups_json)
+        .bind(message.reply_to_id.map(|id| id.to_string()))
+        .bind(message.is_alternative)
+        .bind(citations_json)
         .execute(&self.pool)
         .await?;
 
@@ -47,40 +88,182 @@ impl MessageRepository for SqliteMessageRepository {
     async fn find_by_chat_id(&self, chat_id: Uuid) -> Result<Vec<Message>> {
         let records = sqlx::query(
             r#"
-            SELECT id, chat_id, role, content, created_at, summary, message_type, importance, follow_ups
+            SELECT id, chat_id, role, content, created_at, summary, message_type, importance, follow_ups, reply_to_id, is_alternative, citations
             FROM messages
-            WHERE chat_id = ?1
+            WHERE chat_id = ?1 AND is_alternative = 0
             ORDER BY created_at ASC
             "#
         )
         .bind(chat_id.to_string())
+        .try_map(Self::map_message)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn find_page_by_chat_id(&self, chat_id: Uuid, before: Option<PageCursor>, limit: i64) -> Result<Vec<Message>> {
+        let mut records = sqlx::query(
+            r#"
+            SELECT id, chat_id, role, content, created_at, summary, message_type, importance, follow_ups, reply_to_id, is_alternative, citations
+            FROM messages
+            WHERE chat_id = ?1 AND is_alternative = 0
+              AND (?2 IS NULL OR created_at < ?2 OR (created_at = ?2 AND id < ?3))
+            ORDER BY created_at DESC, id DESC
+            LIMIT ?4
+            "#
+        )
+        .bind(chat_id.to_string())
+        .bind(before.as_ref().map(|c| c.created_at))
+        .bind(before.as_ref().map(|c| c.id.to_string()))
+        .bind(limit)
+        .try_map(Self::map_message)
+        .fetch_all(&self.pool)
+        .await?;
+
+        records.reverse();
+        Ok(records)
+    }
+
+    async fn find_recent_by_chat_id(&self, chat_id: Uuid, limit: i64) -> Result<Vec<Message>> {
+        self.find_page_by_chat_id(chat_id, None, limit).await
+    }
+
+    async fn find_highlights_by_chat_id(&self, chat_id: Uuid, min_importance: i32, skip_recent: i64, limit: i64) -> Result<Vec<Message>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, chat_id, role, content, created_at, summary, message_type, importance, follow_ups, reply_to_id, is_alternative, citations
+            FROM messages
+            WHERE chat_id = ?1 AND is_alternative = 0
+              AND summary IS NOT NULL
+              AND importance > ?2
+              AND id NOT IN (
+                  SELECT id FROM messages
+                  WHERE chat_id = ?1 AND is_alternative = 0
+                  ORDER BY created_at DESC, id DESC
+                  LIMIT ?3
+              )
+            ORDER BY importance DESC, created_at DESC
+            LIMIT ?4
+            "#
+        )
+        .bind(chat_id.to_string())
+        .bind(min_importance)
+        .bind(skip_recent)
+        .bind(limit)
+        .try_map(Self::map_message)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn count_by_chat_id(&self, chat_id: Uuid) -> Result<i64> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM messages WHERE chat_id = ?1 AND is_alternative = 0")
+            .bind(chat_id.to_string())
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(count)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Message>> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, chat_id, role, content, created_at, summary, message_type, importance, follow_ups, reply_to_id, is_alternative, citations
+            FROM messages
+            WHERE id = ?1
+            "#
+        )
+        .bind(id.to_string())
+        .try_map(Self::map_message)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    async fn find_alternatives(&self, reply_to_id: Uuid) -> Result<Vec<Message>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, chat_id, role, content, created_at, summary, message_type, importance, follow_ups, reply_to_id, is_alternative, citations
+            FROM messages
+            WHERE reply_to_id = ?1
+            ORDER BY created_at ASC
+            "#
+        )
+        .bind(reply_to_id.to_string())
+        .try_map(Self::map_message)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn set_canonical_reply(&self, reply_to_id: Uuid, message_id: Uuid) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE messages
+            SET is_alternative = CASE WHEN id = ?2 THEN 0 ELSE 1 END
+            WHERE reply_to_id = ?1
+            "#
+        )
+        .bind(reply_to_id.to_string())
+        .bind(message_id.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn save_generation(&self, generation: &MessageGeneration) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO message_generations (message_id, provider, model, latency_ms, prompt_tokens, completion_tokens, total_tokens, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "#
+        )
+        .bind(generation.message_id.to_string())
+        .bind(generation.provider.clone())
+        .bind(generation.model.clone())
+        .bind(generation.latency_ms)
+        .bind(generation.prompt_tokens)
+        .bind(generation.completion_tokens)
+        .bind(generation.total_tokens)
+        .bind(generation.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_generation(&self, message_id: Uuid) -> Result<Option<MessageGeneration>> {
+        let record = sqlx::query(
+            r#"
+            SELECT message_id, provider, model, latency_ms, prompt_tokens, completion_tokens, total_tokens, created_at
+            FROM message_generations
+            WHERE message_id = ?1
+            "#
+        )
+        .bind(message_id.to_string())
         .try_map(|row: sqlx::sqlite::SqliteRow| {
-            let id_str: String = row.get("id");
-            let chat_id_str: String = row.get("chat_id");
-            let follow_ups_json: Option<String> = row.get("follow_ups");
-            
-            // Deserialize follow_ups from JSON
-            let follow_ups = follow_ups_json.and_then(|json| {
-                serde_json::from_str::<Vec<String>>(&json).ok()
-            });
-
-            Ok(Message {
-                id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
-                chat_id: Uuid::parse_str(&chat_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
-                role: row.get("role"),
-                content: row.get("content"),
+            let message_id_str: String = row.get("message_id");
+
+            Ok(MessageGeneration {
+                message_id: Uuid::parse_str(&message_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                provider: row.get("provider"),
+                model: row.get("model"),
+                latency_ms: row.get("latency_ms"),
+                prompt_tokens: row.get("prompt_tokens"),
+                completion_tokens: row.get("completion_tokens"),
+                total_tokens: row.get("total_tokens"),
                 created_at: row.get("created_at"),
-                summary: row.get("summary"),
-                message_type: row.get("message_type"),
-                importance: row.get("importance"),
-                follow_ups,
-                tip: None, // Tips are not persisted, recalculated per-request
             })
         })
-        .fetch_all(&self.pool)
+        .fetch_optional(&self.pool)
         .await?;
 
-        Ok(records)
+        Ok(record)
     }
 
     async fn update(&self, message: Message) -> Result<Message> {
@@ -119,12 +302,13 @@ impl MessageRepository for SqliteMessageRepository {
         // This query finds the top K most important summaries from the user's recent chats
         let records = sqlx::query(
             r#"
-            SELECT m.id, m.chat_id, m.role, m.content, m.created_at, m.summary, m.message_type, m.importance, m.follow_ups
+            SELECT m.id, m.chat_id, m.role, m.content, m.created_at, m.summary, m.message_type, m.importance, m.follow_ups, m.reply_to_id, m.is_alternative, m.citations
             FROM messages m
             JOIN chats c ON m.chat_id = c.id
             WHERE c.user_id = ?1
               AND m.summary IS NOT NULL
               AND m.importance > 0
+              AND m.is_alternative = 0
               AND m.chat_id IN (
                   SELECT id FROM chats WHERE user_id = ?1 ORDER BY created_at DESC LIMIT ?2
               )
@@ -135,28 +319,7 @@ impl MessageRepository for SqliteMessageRepository {
         .bind(user_id.to_string())
         .bind(limit_chats)
         .bind(top_k)
-        .try_map(|row: sqlx::sqlite::SqliteRow| {
-            let id_str: String = row.get("id");
-            let chat_id_str: String = row.get("chat_id");
-            let follow_ups_json: Option<String> = row.get("follow_ups");
-            
-            let follow_ups = follow_ups_json.and_then(|json| {
-                serde_json::from_str::<Vec<String>>(&json).ok()
-            });
-
-            Ok(Message {
-                id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
-                chat_id: Uuid::parse_str(&chat_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
-                role: row.get("role"),
-                content: row.get("content"),
-                created_at: row.get("created_at"),
-                summary: row.get("summary"),
-                message_type: row.get("message_type"),
-                importance: row.get("importance"),
-                follow_ups,
-                tip: None, // Tips are not persisted
-            })
-        })
+        .try_map(Self::map_message)
         .fetch_all(&self.pool)
         .await?;
 
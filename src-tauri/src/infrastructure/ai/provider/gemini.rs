@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::Utc;
 
-const GEMINI_API_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta/models";
+pub const GEMINI_API_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta/models";
 
 // Intermediate struct to match Gemini's request format
 #[derive(Debug, Serialize)]
@@ -119,15 +119,24 @@ impl AiProvider for GeminiClient {
             None
         };
 
-        // Extract system prompt if present
-        let system_message = request.messages.iter().find(|msg| msg.role == "system");
-        let system_instruction = system_message.map(|msg| GeminiContent {
-            role: None, // Role is optional/ignored for system instruction
-            parts: vec![GeminiPart {
-                text: Some(msg.content.clone()),
-                inline_data: None,
-            }],
-        });
+        // Gemini takes a single system instruction, so merge every system message
+        // (preset prompt, history highlights, retrieved context) into one.
+        let system_text = request.messages.iter()
+            .filter(|msg| msg.role == "system")
+            .map(|msg| msg.content.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let system_instruction = if system_text.is_empty() {
+            None
+        } else {
+            Some(GeminiContent {
+                role: None, // Role is optional/ignored for system instruction
+                parts: vec![GeminiPart {
+                    text: Some(system_text),
+                    inline_data: None,
+                }],
+            })
+        };
 
         let gemini_request = GeminiChatRequest {
             system_instruction,
@@ -16,7 +16,7 @@ impl SqliteConfigRepository {
 #[async_trait]
 impl ConfigRepository for SqliteConfigRepository {
     async fn get(&self) -> Result<AppConfig> {
-        let rec = sqlx::query("SELECT language, enable_smart_rag FROM app_config WHERE id = 1")
+        let rec = sqlx::query("SELECT language, enable_smart_rag, auto_approve_tools, input_device FROM app_config WHERE id = 1")
             .fetch_optional(&self.pool)
             .await?;
 
@@ -24,6 +24,11 @@ impl ConfigRepository for SqliteConfigRepository {
             Some(row) => Ok(AppConfig {
                 language: row.try_get("language")?,
                 enable_smart_rag: row.try_get("enable_smart_rag").unwrap_or(false),
+                auto_approve_tools: row.try_get::<String, _>("auto_approve_tools")
+                    .ok()
+                    .and_then(|json| serde_json::from_str(&json).ok())
+                    .unwrap_or_default(),
+                input_device: row.try_get("input_device").unwrap_or(None),
             }),
             None => Ok(AppConfig::default()),
         }
@@ -46,4 +51,28 @@ impl ConfigRepository for SqliteConfigRepository {
 
         Ok(())
     }
+
+    async fn set_tool_auto_approve(&self, tool: &str, enabled: bool) -> Result<()> {
+        let mut tools = self.get().await?.auto_approve_tools;
+        tools.retain(|t| t != tool);
+        if enabled {
+            tools.push(tool.to_string());
+        }
+
+        sqlx::query("UPDATE app_config SET auto_approve_tools = ? WHERE id = 1")
+            .bind(serde_json::to_string(&tools)?)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_input_device(&self, device: Option<&str>) -> Result<()> {
+        sqlx::query("UPDATE app_config SET input_device = ? WHERE id = 1")
+            .bind(device)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
 }
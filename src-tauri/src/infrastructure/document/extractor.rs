@@ -0,0 +1,114 @@
+use anyhow::{Result, anyhow};
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+// Keep ingestion bounded; larger files are almost always binaries or dumps
+const MAX_FILE_SIZE: u64 = 20 * 1024 * 1024;
+
+const CODE_EXTENSIONS: &[&str] = &[
+    "rs", "ts", "tsx", "js", "jsx", "mjs", "cjs", "py", "go", "java", "kt", "swift",
+    "c", "h", "cc", "cpp", "hpp", "cs", "rb", "php", "scala", "sql", "sh", "bash",
+    "zsh", "ps1", "css", "scss", "html", "vue", "svelte", "json", "toml", "yaml", "yml",
+    "xml", "gradle", "lua", "dart",
+];
+
+pub struct ExtractedDocument {
+    pub file_type: String,
+    pub text: String,
+}
+
+/// Reads a local file and returns its plain text along with a coarse file type.
+pub fn extract_text(path: &Path) -> Result<ExtractedDocument> {
+    let metadata = fs::metadata(path)
+        .map_err(|e| anyhow!("Failed to read file {:?}: {}", path, e))?;
+
+    if !metadata.is_file() {
+        return Err(anyhow!("Not a file: {:?}", path));
+    }
+    if metadata.len() > MAX_FILE_SIZE {
+        return Err(anyhow!("File is too large to ingest ({} bytes, max {})", metadata.len(), MAX_FILE_SIZE));
+    }
+
+    let extension = path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+
+    let (file_type, text) = match extension.as_str() {
+        "pdf" => ("pdf", extract_pdf(path)?),
+        "docx" => ("docx", extract_docx(path)?),
+        "md" | "markdown" | "mdx" => ("markdown", read_utf8(path)?),
+        ext if CODE_EXTENSIONS.contains(&ext) => ("code", read_utf8(path)?),
+        _ => ("text", read_utf8(path)?),
+    };
+
+    if text.trim().is_empty() {
+        return Err(anyhow!("No text could be extracted from {:?}", path));
+    }
+
+    Ok(ExtractedDocument {
+        file_type: file_type.to_string(),
+        text,
+    })
+}
+
+fn read_utf8(path: &Path) -> Result<String> {
+    let bytes = fs::read(path)?;
+    // A NUL byte in the first few KB is a good signal of a binary file
+    if bytes.iter().take(8000).any(|&b| b == 0) {
+        return Err(anyhow!("Unsupported binary file: {:?}", path));
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn extract_pdf(path: &Path) -> Result<String> {
+    pdf_extract::extract_text(path)
+        .map_err(|e| anyhow!("Failed to extract PDF text: {}", e))
+}
+
+fn extract_docx(path: &Path) -> Result<String> {
+    let file = fs::File::open(path)?;
+    let mut archive = zip::ZipArchive::new(file)
+        .map_err(|e| anyhow!("Invalid DOCX file: {}", e))?;
+
+    let mut xml = String::new();
+    archive.by_name("word/document.xml")
+        .map_err(|e| anyhow!("DOCX has no document body: {}", e))?
+        .read_to_string(&mut xml)?;
+
+    Ok(docx_xml_to_text(&xml))
+}
+
+/// Pulls the visible text out of WordprocessingML: `<w:t>` runs, with paragraph
+/// ends, breaks and tabs mapped to whitespace.
+fn docx_xml_to_text(xml: &str) -> String {
+    let mut text = String::new();
+    let mut rest = xml;
+
+    while let Some(tag_start) = rest.find('<') {
+        let Some(tag_len) = rest[tag_start..].find('>') else { break };
+        let tag = &rest[tag_start + 1..tag_start + tag_len];
+        rest = &rest[tag_start + tag_len + 1..];
+
+        if tag == "/w:p" || tag.starts_with("w:br") || tag.starts_with("w:cr") {
+            text.push('\n');
+        } else if tag.starts_with("w:tab") && tag.ends_with('/') {
+            text.push('\t');
+        } else if (tag == "w:t" || tag.starts_with("w:t ")) && !tag.ends_with('/') {
+            let end = rest.find("</w:t>").unwrap_or(rest.len());
+            text.push_str(&unescape_xml(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    text
+}
+
+fn unescape_xml(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
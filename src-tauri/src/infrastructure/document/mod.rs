@@ -0,0 +1,2 @@
+pub mod extractor;
+pub mod sqlite_repository;
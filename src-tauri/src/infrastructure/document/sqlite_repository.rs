@@ -0,0 +1,233 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use crate::domain::document::{
+    entity::{Document, DocumentChunk, DocumentChunkMatch},
+    repository::DocumentRepository,
+};
+use crate::shared::utils::fts_match_query;
+
+pub struct SqliteDocumentRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteDocumentRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn map_document(row: sqlx::sqlite::SqliteRow) -> Result<Document, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let user_id_str: String = row.get("user_id");
+        let chat_id_str: Option<String> = row.get("chat_id");
+
+        Ok(Document {
+            id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            user_id: Uuid::parse_str(&user_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            chat_id: chat_id_str
+                .map(|s| Uuid::parse_str(&s))
+                .transpose()
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            file_name: row.get("file_name"),
+            file_path: row.get("file_path"),
+            file_type: row.get("file_type"),
+            content_hash: row.get("content_hash"),
+            chunk_count: row.get("chunk_count"),
+            created_at: row.get("created_at"),
+        })
+    }
+
+    fn map_chunk(row: &sqlx::sqlite::SqliteRow) -> Result<DocumentChunk, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let document_id_str: String = row.get("document_id");
+
+        Ok(DocumentChunk {
+            id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            document_id: Uuid::parse_str(&document_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            chunk_index: row.get("chunk_index"),
+            content: row.get("content"),
+            start_line: row.get("start_line"),
+            end_line: row.get("end_line"),
+        })
+    }
+}
+
+#[async_trait]
+impl DocumentRepository for SqliteDocumentRepository {
+    async fn save(&self, document: &Document, chunks: &[DocumentChunk]) -> Result<Document> {
+        let mut tx = self.pool.begin().await.map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO documents (id, user_id, chat_id, file_name, file_path, file_type, content_hash, chunk_count, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            "#
+        )
+        .bind(document.id.to_string())
+        .bind(document.user_id.to_string())
+        .bind(document.chat_id.map(|id| id.to_string()))
+        .bind(document.file_name.clone())
+        .bind(document.file_path.clone())
+        .bind(document.file_type.clone())
+        .bind(document.content_hash.clone())
+        .bind(document.chunk_count)
+        .bind(document.created_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| anyhow!("Failed to save document: {}", e))?;
+
+        for chunk in chunks {
+            sqlx::query(
+                r#"
+                INSERT INTO document_chunks (id, document_id, chunk_index, content, start_line, end_line)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                "#
+            )
+            .bind(chunk.id.to_string())
+            .bind(chunk.document_id.to_string())
+            .bind(chunk.chunk_index)
+            .bind(chunk.content.clone())
+            .bind(chunk.start_line)
+            .bind(chunk.end_line)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to save document chunk: {}", e))?;
+        }
+
+        tx.commit().await.map_err(|e| anyhow!("Failed to commit transaction: {}", e))?;
+
+        Ok(document.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Document>> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, user_id, chat_id, file_name, file_path, file_type, content_hash, chunk_count, created_at
+            FROM documents
+            WHERE id = ?1
+            "#
+        )
+        .bind(id.to_string())
+        .try_map(Self::map_document)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid, chat_id: Option<Uuid>) -> Result<Vec<Document>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, user_id, chat_id, file_name, file_path, file_type, content_hash, chunk_count, created_at
+            FROM documents
+            WHERE user_id = ?1 AND (chat_id IS NULL OR chat_id = ?2)
+            ORDER BY created_at DESC
+            "#
+        )
+        .bind(user_id.to_string())
+        .bind(chat_id.map(|id| id.to_string()))
+        .try_map(Self::map_document)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn find_by_hash(&self, user_id: Uuid, chat_id: Option<Uuid>, content_hash: &str) -> Result<Option<Document>> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, user_id, chat_id, file_name, file_path, file_type, content_hash, chunk_count, created_at
+            FROM documents
+            WHERE user_id = ?1 AND chat_id IS ?2 AND content_hash = ?3
+            LIMIT 1
+            "#
+        )
+        .bind(user_id.to_string())
+        .bind(chat_id.map(|id| id.to_string()))
+        .bind(content_hash)
+        .try_map(Self::map_document)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    async fn find_chunks(&self, document_id: Uuid) -> Result<Vec<DocumentChunk>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, document_id, chunk_index, content, start_line, end_line
+            FROM document_chunks
+            WHERE document_id = ?1
+            ORDER BY chunk_index ASC
+            "#
+        )
+        .bind(document_id.to_string())
+        .try_map(|row: sqlx::sqlite::SqliteRow| Self::map_chunk(&row))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn set_chat(&self, user_id: Uuid, id: Uuid, chat_id: Option<Uuid>) -> Result<bool> {
+        let updated = sqlx::query("UPDATE documents SET chat_id = ?1 WHERE id = ?2 AND user_id = ?3")
+            .bind(chat_id.map(|id| id.to_string()))
+            .bind(id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to attach document: {}", e))?
+            .rows_affected();
+
+        Ok(updated == 1)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool> {
+        let deleted = sqlx::query("DELETE FROM documents WHERE id = ?1 AND user_id = ?2")
+            .bind(id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete document: {}", e))?
+            .rows_affected();
+
+        Ok(deleted == 1)
+    }
+
+    async fn search_chunks(&self, user_id: Uuid, chat_id: Uuid, query: &str, limit: i64) -> Result<Vec<DocumentChunkMatch>> {
+        let Some(match_query) = fts_match_query(query) else {
+            return Ok(Vec::new());
+        };
+
+        let records = sqlx::query(
+            r#"
+            SELECT c.id, c.document_id, c.chunk_index, c.content, c.start_line, c.end_line,
+                   d.file_name, d.file_path
+            FROM document_chunks_fts f
+            JOIN document_chunks c ON c.id = f.chunk_id
+            JOIN documents d ON d.id = c.document_id
+            WHERE document_chunks_fts MATCH ?1
+              AND d.user_id = ?2
+              AND (d.chat_id IS NULL OR d.chat_id = ?3)
+            ORDER BY bm25(document_chunks_fts)
+            LIMIT ?4
+            "#
+        )
+        .bind(match_query)
+        .bind(user_id.to_string())
+        .bind(chat_id.to_string())
+        .bind(limit)
+        .try_map(|row: sqlx::sqlite::SqliteRow| {
+            Ok(DocumentChunkMatch {
+                chunk: Self::map_chunk(&row)?,
+                file_name: row.get("file_name"),
+                file_path: row.get("file_path"),
+            })
+        })
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
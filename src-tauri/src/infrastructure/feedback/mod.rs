@@ -0,0 +1 @@
+pub mod sqlite_repository;
@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use crate::domain::feedback::{
+    entity::{FeedbackStats, MessageFeedback},
+    repository::FeedbackRepository,
+};
+
+pub struct SqliteFeedbackRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteFeedbackRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn map_feedback(row: sqlx::sqlite::SqliteRow) -> Result<MessageFeedback, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let message_id_str: String = row.get("message_id");
+        let chat_id_str: String = row.get("chat_id");
+        let user_id_str: String = row.get("user_id");
+
+        Ok(MessageFeedback {
+            id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            message_id: Uuid::parse_str(&message_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            chat_id: Uuid::parse_str(&chat_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            user_id: Uuid::parse_str(&user_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            rating: row.get("rating"),
+            reason: row.get("reason"),
+            comment: row.get("comment"),
+            prompt_preset_id: row.get("prompt_preset_id"),
+            model: row.get("model"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl FeedbackRepository for SqliteFeedbackRepository {
+    async fn upsert(&self, feedback: &MessageFeedback) -> Result<MessageFeedback> {
+        sqlx::query(
+            r#"
+            INSERT INTO message_feedback (id, message_id, chat_id, user_id, rating, reason, comment, prompt_preset_id, model, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            ON CONFLICT(message_id) DO UPDATE SET
+                rating = excluded.rating,
+                reason = excluded.reason,
+                comment = excluded.comment,
+                created_at = excluded.created_at
+            "#
+        )
+        .bind(feedback.id.to_string())
+        .bind(feedback.message_id.to_string())
+        .bind(feedback.chat_id.to_string())
+        .bind(feedback.user_id.to_string())
+        .bind(feedback.rating)
+        .bind(feedback.reason.clone())
+        .bind(feedback.comment.clone())
+        .bind(feedback.prompt_preset_id.clone())
+        .bind(feedback.model.clone())
+        .bind(feedback.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save feedback: {}", e))?;
+
+        self.find_by_message_id(feedback.message_id).await?
+            .ok_or_else(|| anyhow!("Feedback not found after save"))
+    }
+
+    async fn find_by_message_id(&self, message_id: Uuid) -> Result<Option<MessageFeedback>> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, message_id, chat_id, user_id, rating, reason, comment, prompt_preset_id, model, created_at
+            FROM message_feedback
+            WHERE message_id = ?1
+            "#
+        )
+        .bind(message_id.to_string())
+        .try_map(Self::map_feedback)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    async fn find_by_chat_id(&self, user_id: Uuid, chat_id: Uuid) -> Result<Vec<MessageFeedback>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, message_id, chat_id, user_id, rating, reason, comment, prompt_preset_id, model, created_at
+            FROM message_feedback
+            WHERE chat_id = ?1 AND user_id = ?2
+            "#
+        )
+        .bind(chat_id.to_string())
+        .bind(user_id.to_string())
+        .try_map(Self::map_feedback)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn delete_by_message_id(&self, user_id: Uuid, message_id: Uuid) -> Result<bool> {
+        let deleted = sqlx::query("DELETE FROM message_feedback WHERE message_id = ?1 AND user_id = ?2")
+            .bind(message_id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete feedback: {}", e))?
+            .rows_affected();
+
+        Ok(deleted == 1)
+    }
+
+    async fn get_stats(&self, user_id: Uuid) -> Result<Vec<FeedbackStats>> {
+        let records = sqlx::query(
+            r#"
+            SELECT f.prompt_preset_id, p.name AS preset_name, f.model,
+                   SUM(CASE WHEN f.rating > 0 THEN 1 ELSE 0 END) AS positive,
+                   SUM(CASE WHEN f.rating < 0 THEN 1 ELSE 0 END) AS negative,
+                   COUNT(*) AS total
+            FROM message_feedback f
+            LEFT JOIN prompt_presets p ON p.id = f.prompt_preset_id
+            WHERE f.user_id = ?1
+            GROUP BY f.prompt_preset_id, f.model
+            ORDER BY negative DESC, total DESC
+            "#
+        )
+        .bind(user_id.to_string())
+        .try_map(|row: sqlx::sqlite::SqliteRow| {
+            let positive: i64 = row.get("positive");
+            let total: i64 = row.get("total");
+
+            Ok(FeedbackStats {
+                prompt_preset_id: row.get("prompt_preset_id"),
+                preset_name: row.get("preset_name"),
+                model: row.get("model"),
+                positive,
+                negative: row.get("negative"),
+                total,
+                approval_rate: if total > 0 { positive as f64 / total as f64 } else { 0.0 },
+            })
+        })
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
@@ -58,6 +58,66 @@ impl MaintenanceRepository for SqliteMaintenanceRepository {
             .await
             .map_err(|e| anyhow!("Failed to delete chats: {}", e))?;
 
+        // 2.1 Delete ingested documents (chunks cascade), including the global library
+        sqlx::query("DELETE FROM documents")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete documents: {}", e))?;
+
+        // 2.2 Delete indexed workspaces (files and chunks cascade)
+        sqlx::query("DELETE FROM workspaces")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete workspaces: {}", e))?;
+
+        // 2.3 Delete configured MCP servers
+        sqlx::query("DELETE FROM mcp_servers")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete MCP servers: {}", e))?;
+
+        // 2.4 Delete the audit trail of AI-executed actions
+        sqlx::query("DELETE FROM action_log")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete action log: {}", e))?;
+
+        // 2.5 Delete retention rules
+        sqlx::query("DELETE FROM retention_rules")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete retention rules: {}", e))?;
+
+        // 2.6 Delete scheduled prompts
+        sqlx::query("DELETE FROM scheduled_prompts")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete scheduled prompts: {}", e))?;
+
+        // 2.7 Delete tasks
+        sqlx::query("DELETE FROM tasks")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete tasks: {}", e))?;
+
+        // 2.8 Delete voice activity settings
+        sqlx::query("DELETE FROM vad_settings")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete VAD settings: {}", e))?;
+
+        // 2.9 Delete transcription provider settings
+        sqlx::query("DELETE FROM transcription_settings")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete transcription settings: {}", e))?;
+
+        // 2.10 Delete meetings (their recordings are removed by the caller)
+        sqlx::query("DELETE FROM meetings")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| anyhow!("Failed to delete meetings: {}", e))?;
+
         // 3. Delete all API keys
         sqlx::query("DELETE FROM user_api_keys")
             .execute(&mut *tx)
@@ -0,0 +1,186 @@
+use async_trait::async_trait;
+use anyhow::{Result, anyhow};
+use futures_util::StreamExt;
+use serde_json::Value;
+use std::sync::Mutex as StdMutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use super::transport::{self, McpTransport, REQUEST_TIMEOUT};
+
+const SESSION_HEADER: &str = "Mcp-Session-Id";
+
+/// MCP Streamable HTTP transport: every message is POSTed to one endpoint and
+/// the server answers with plain JSON or with an SSE stream carrying the response.
+pub struct HttpTransport {
+    client: reqwest::Client,
+    url: String,
+    session_id: StdMutex<Option<String>>,
+    next_id: AtomicU64,
+    tools_changed: AtomicBool,
+}
+
+impl HttpTransport {
+    pub fn new(url: &str) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| anyhow!("Failed to build HTTP client: {}", e))?;
+
+        Ok(Self {
+            client,
+            url: url.to_string(),
+            session_id: StdMutex::new(None),
+            next_id: AtomicU64::new(1),
+            tools_changed: AtomicBool::new(false),
+        })
+    }
+
+    async fn post(&self, message: &Value) -> Result<reqwest::Response> {
+        let mut builder = self.client.post(&self.url)
+            .header("Accept", "application/json, text/event-stream")
+            .json(message);
+
+        let session_id = self.session_id.lock().unwrap().clone();
+        if let Some(session_id) = session_id {
+            builder = builder.header(SESSION_HEADER, session_id);
+        }
+
+        let response = builder.send().await
+            .map_err(|e| anyhow!("Failed to reach MCP server at {}: {}", self.url, e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow!("MCP server returned {}: {}", status, body));
+        }
+
+        // The server assigns the session on initialize; every later request must carry it
+        if let Some(session_id) = response.headers().get(SESSION_HEADER).and_then(|v| v.to_str().ok()) {
+            *self.session_id.lock().unwrap() = Some(session_id.to_string());
+        }
+
+        Ok(response)
+    }
+
+    /// Reads an SSE body event by event until the response with `id` arrives;
+    /// the server may keep the stream open after it, or send notifications first.
+    async fn find_in_event_stream(&self, response: reqwest::Response, id: u64) -> Result<Option<Value>> {
+        let mut body = response.bytes_stream();
+        let mut events = EventStreamParser::default();
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(|e| anyhow!("Failed to read MCP response: {}", e))?;
+            for data in events.push(&chunk) {
+                if let Some(found) = self.handle_event(&data, id) {
+                    return Ok(Some(found));
+                }
+            }
+        }
+        Ok(events.finish().and_then(|data| self.handle_event(&data, id)))
+    }
+
+    /// The response with `id` if the event carries it; notes tool changes otherwise.
+    fn handle_event(&self, data: &str, id: u64) -> Option<Value> {
+        let message = serde_json::from_str::<Value>(data).ok()?;
+        if transport::is_notification(&message, transport::TOOLS_CHANGED) {
+            self.tools_changed.store(true, Ordering::SeqCst);
+            return None;
+        }
+        Self::find_in_json(message, id)
+    }
+
+    /// Matches a single response or a batch against `id`.
+    fn find_in_json(message: Value, id: u64) -> Option<Value> {
+        match message {
+            Value::Array(items) => items.into_iter().find(|m| transport::response_id(m) == Some(id)),
+            single if transport::response_id(&single) == Some(id) => Some(single),
+            _ => None,
+        }
+    }
+}
+
+#[async_trait]
+impl McpTransport for HttpTransport {
+    async fn request(&self, method: &str, params: Value) -> Result<Value> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let response = self.post(&transport::request_message(id, method, params)).await?;
+
+        let is_event_stream = response.headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.starts_with("text/event-stream"))
+            .unwrap_or(false);
+
+        let message = if is_event_stream {
+            self.find_in_event_stream(response, id).await?
+        } else {
+            let body = response.text().await
+                .map_err(|e| anyhow!("Failed to read MCP response: {}", e))?;
+            serde_json::from_str::<Value>(&body).ok().and_then(|v| Self::find_in_json(v, id))
+        };
+
+        let message = message.ok_or_else(|| anyhow!("MCP server sent no response to '{}'", method))?;
+        transport::into_result(message)
+    }
+
+    async fn notify(&self, method: &str, params: Value) -> Result<()> {
+        self.post(&transport::notification_message(method, params)).await?;
+        Ok(())
+    }
+
+    fn take_tools_changed(&self) -> bool {
+        self.tools_changed.swap(false, Ordering::SeqCst)
+    }
+
+    async fn close(&self) {
+        let session_id = self.session_id.lock().unwrap().take();
+        if let Some(session_id) = session_id {
+            if let Err(e) = self.client.delete(&self.url).header(SESSION_HEADER, session_id).send().await {
+                log::debug!("Failed to end MCP HTTP session: {}", e);
+            }
+        }
+    }
+}
+
+/// Splits a `text/event-stream` body into the data of its events as it arrives
+/// (`data:` lines, an event ends at a blank line).
+#[derive(Default)]
+struct EventStreamParser {
+    /// Bytes of a line whose end hasn't arrived yet
+    line: Vec<u8>,
+    data: String,
+}
+
+impl EventStreamParser {
+    /// Takes the next bytes of the body and returns the data of every event they complete.
+    fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.line.extend_from_slice(bytes);
+        let mut events = Vec::new();
+        while let Some(end) = self.line.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.line.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line[..end]);
+            if let Some(data) = self.feed_line(line.trim_end_matches('\r')) {
+                events.push(data);
+            }
+        }
+        events
+    }
+
+    /// The data of an event the body ended without closing.
+    fn finish(&mut self) -> Option<String> {
+        let line = std::mem::take(&mut self.line);
+        let line = String::from_utf8_lossy(&line);
+        self.feed_line(line.trim_end_matches('\r'))
+            .or_else(|| self.feed_line(""))
+    }
+
+    fn feed_line(&mut self, line: &str) -> Option<String> {
+        if let Some(chunk) = line.strip_prefix("data:") {
+            self.data.push_str(chunk.trim_start());
+            self.data.push('\n');
+            None
+        } else if line.trim().is_empty() && !self.data.is_empty() {
+            Some(std::mem::take(&mut self.data))
+        } else {
+            None
+        }
+    }
+}
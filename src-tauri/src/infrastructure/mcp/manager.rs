@@ -0,0 +1,159 @@
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::domain::mcp::{
+    client::McpClient,
+    entity::{McpCapabilities, McpServer, McpTool, McpToolResult},
+    repository::McpServerRepository,
+};
+use super::session::McpSession;
+
+struct ConnectedServer {
+    client: Arc<dyn McpClient>,
+    tools: Vec<McpTool>,
+}
+
+/// Keeps one live session per enabled MCP server, connected on first use.
+pub struct McpManager {
+    server_repo: Arc<dyn McpServerRepository>,
+    sessions: Mutex<HashMap<Uuid, ConnectedServer>>,
+}
+
+impl McpManager {
+    pub fn new(server_repo: Arc<dyn McpServerRepository>) -> Self {
+        Self {
+            server_repo,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn connected(&self, server: &McpServer) -> Result<(Arc<dyn McpClient>, Vec<McpTool>)> {
+        let existing = self.sessions.lock().await.get(&server.id)
+            .map(|existing| (existing.client.clone(), existing.tools.clone()));
+        if let Some((client, cached)) = existing {
+            if !client.take_tools_changed() {
+                return Ok((client, cached));
+            }
+            // The server announced new tools; list them again without holding the lock
+            let tools = match client.list_tools().await {
+                Ok(tools) => tools,
+                Err(e) => {
+                    log::warn!("MCP server '{}' failed to list tools: {}", server.name, e);
+                    return Ok((client, cached));
+                }
+            };
+            if let Some(connected) = self.sessions.lock().await.get_mut(&server.id) {
+                if Arc::ptr_eq(&connected.client, &client) {
+                    connected.tools = tools.clone();
+                }
+            }
+            return Ok((client, tools));
+        }
+
+        // Connect without holding the lock so one slow server doesn't block the others
+        let client: Arc<dyn McpClient> = Arc::new(McpSession::connect(server).await?);
+        let tools = client.list_tools().await.unwrap_or_else(|e| {
+            log::warn!("MCP server '{}' failed to list tools: {}", server.name, e);
+            Vec::new()
+        });
+
+        let mut sessions = self.sessions.lock().await;
+        if let Some(existing) = sessions.get(&server.id) {
+            // Lost a race with another caller; keep the session that is already in use
+            client.close().await;
+            return Ok((existing.client.clone(), existing.tools.clone()));
+        }
+        sessions.insert(server.id, ConnectedServer { client: client.clone(), tools: tools.clone() });
+
+        Ok((client, tools))
+    }
+
+    async fn enabled_server(&self, server_id: Uuid) -> Result<McpServer> {
+        let server = self.server_repo.find_by_id(server_id).await?
+            .ok_or_else(|| anyhow!("MCP server not found"))?;
+        if !server.enabled {
+            return Err(anyhow!("MCP server '{}' is disabled", server.name));
+        }
+        Ok(server)
+    }
+
+    pub async fn disconnect(&self, server_id: Uuid) {
+        let removed = self.sessions.lock().await.remove(&server_id);
+        if let Some(connected) = removed {
+            connected.client.close().await;
+        }
+    }
+
+    /// Tools of every enabled server of the user. Servers that fail to start are skipped.
+    pub async fn tools_for_user(&self, user_id: Uuid) -> Vec<McpTool> {
+        let servers = match self.server_repo.find_by_user_id(user_id).await {
+            Ok(servers) => servers,
+            Err(e) => {
+                log::warn!("Failed to load MCP servers: {}", e);
+                return Vec::new();
+            }
+        };
+
+        let mut tools = Vec::new();
+        for server in servers.iter().filter(|s| s.enabled) {
+            match self.connected(server).await {
+                Ok((_, server_tools)) => tools.extend(server_tools),
+                Err(e) => log::warn!("MCP server '{}' unavailable: {}", server.name, e),
+            }
+        }
+        tools
+    }
+
+    /// Connects with a fresh session and lists everything the server offers.
+    /// Works for disabled servers too, so a configuration can be checked before enabling it.
+    pub async fn test(&self, server_id: Uuid) -> Result<McpCapabilities> {
+        let server = self.server_repo.find_by_id(server_id).await?
+            .ok_or_else(|| anyhow!("MCP server not found"))?;
+
+        // Drop any cached session so the test reflects the current configuration
+        self.disconnect(server_id).await;
+
+        let session = McpSession::connect(&server).await?;
+        let capabilities = async {
+            Ok::<_, anyhow::Error>(McpCapabilities {
+                server_id: server.id,
+                server_name: server.name.clone(),
+                server_version: session.server_version(),
+                tools: session.list_tools().await?,
+                // Resources and prompts are optional capabilities
+                resources: session.list_resources().await.unwrap_or_default(),
+                prompts: session.list_prompts().await.unwrap_or_default(),
+            })
+        }.await;
+        session.close().await;
+
+        capabilities
+    }
+
+    pub async fn call_tool(&self, server_id: Uuid, tool: &str, arguments: Value) -> Result<McpToolResult> {
+        let server = self.enabled_server(server_id).await?;
+        let (client, _) = self.connected(&server).await?;
+
+        let result = client.call_tool(tool, arguments).await;
+        if result.is_err() {
+            // The process may have died; reconnect on next use
+            self.disconnect(server_id).await;
+        }
+        result
+    }
+
+    pub async fn read_resource(&self, server_id: Uuid, uri: &str) -> Result<String> {
+        let server = self.enabled_server(server_id).await?;
+        let (client, _) = self.connected(&server).await?;
+        client.read_resource(uri).await
+    }
+
+    pub async fn get_prompt(&self, server_id: Uuid, name: &str, arguments: HashMap<String, String>) -> Result<String> {
+        let server = self.enabled_server(server_id).await?;
+        let (client, _) = self.connected(&server).await?;
+        client.get_prompt(name, arguments).await
+    }
+}
@@ -0,0 +1,6 @@
+pub mod sqlite_repository;
+pub mod transport;
+pub mod stdio;
+pub mod http;
+pub mod session;
+pub mod manager;
@@ -0,0 +1,230 @@
+use async_trait::async_trait;
+use anyhow::{Result, anyhow};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::domain::mcp::{
+    client::McpClient,
+    entity::{McpPrompt, McpPromptArgument, McpResource, McpServer, McpTool, McpToolResult, McpTransport as TransportKind},
+};
+use super::{http::HttpTransport, stdio::StdioTransport, transport::McpTransport};
+
+const PROTOCOL_VERSION: &str = "2025-03-26";
+
+/// Cap on pages fetched from paginated `*/list` calls.
+const MAX_LIST_PAGES: usize = 20;
+
+pub struct McpSession {
+    server_id: Uuid,
+    server_name: String,
+    server_version: Option<String>,
+    transport: Box<dyn McpTransport>,
+}
+
+impl McpSession {
+    /// Opens the transport and performs the `initialize` handshake.
+    pub async fn connect(server: &McpServer) -> Result<Self> {
+        let transport: Box<dyn McpTransport> = match server.transport {
+            TransportKind::Stdio => {
+                let command = server.command.as_deref()
+                    .ok_or_else(|| anyhow!("MCP server '{}' has no command", server.name))?;
+                Box::new(StdioTransport::spawn(&server.name, command, &server.args, &server.env)?)
+            }
+            TransportKind::Http => {
+                let url = server.url.as_deref()
+                    .ok_or_else(|| anyhow!("MCP server '{}' has no URL", server.name))?;
+                Box::new(HttpTransport::new(url)?)
+            }
+        };
+
+        let init = transport.request("initialize", json!({
+            "protocolVersion": PROTOCOL_VERSION,
+            "capabilities": {},
+            "clientInfo": { "name": "primer", "version": env!("CARGO_PKG_VERSION") }
+        })).await;
+
+        let init = match init {
+            Ok(init) => init,
+            Err(e) => {
+                transport.close().await;
+                return Err(anyhow!("MCP server '{}' failed to initialize: {}", server.name, e));
+            }
+        };
+
+        transport.notify("notifications/initialized", json!({})).await?;
+
+        let server_version = init.pointer("/serverInfo/version")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        log::info!(
+            "Connected to MCP server '{}' ({} {})",
+            server.name,
+            init.pointer("/serverInfo/name").and_then(Value::as_str).unwrap_or("unknown"),
+            server_version.as_deref().unwrap_or("")
+        );
+
+        Ok(Self {
+            server_id: server.id,
+            server_name: server.name.clone(),
+            server_version,
+            transport,
+        })
+    }
+
+    /// Calls a paginated list method and concatenates the `field` arrays of every page.
+    async fn list_all(&self, method: &str, field: &str) -> Result<Vec<Value>> {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        for _ in 0..MAX_LIST_PAGES {
+            let params = match &cursor {
+                Some(cursor) => json!({ "cursor": cursor }),
+                None => json!({}),
+            };
+            let result = self.transport.request(method, params).await?;
+
+            if let Some(page) = result.get(field).and_then(Value::as_array) {
+                items.extend(page.iter().cloned());
+            }
+
+            cursor = result.get("nextCursor").and_then(Value::as_str).map(str::to_string);
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(items)
+    }
+
+    fn text(value: &Value, key: &str) -> Option<String> {
+        value.get(key).and_then(Value::as_str).map(str::to_string)
+    }
+
+    /// Flattens MCP content parts to text; binary parts become a short placeholder.
+    fn content_to_text(content: &Value) -> String {
+        let parts: Vec<String> = match content {
+            Value::Array(parts) => parts.iter().map(Self::content_part_to_text).collect(),
+            part => vec![Self::content_part_to_text(part)],
+        };
+        parts.into_iter().filter(|p| !p.is_empty()).collect::<Vec<_>>().join("\n")
+    }
+
+    fn content_part_to_text(part: &Value) -> String {
+        match part.get("type").and_then(Value::as_str) {
+            Some("text") => Self::text(part, "text").unwrap_or_default(),
+            Some("resource") => part.get("resource")
+                .map(|r| Self::text(r, "text").unwrap_or_else(|| format!("[resource {}]", Self::text(r, "uri").unwrap_or_default())))
+                .unwrap_or_default(),
+            Some(kind) => format!("[{} {}]", kind, Self::text(part, "mimeType").unwrap_or_default()),
+            None => String::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl McpClient for McpSession {
+    fn server_version(&self) -> Option<String> {
+        self.server_version.clone()
+    }
+
+    fn take_tools_changed(&self) -> bool {
+        self.transport.take_tools_changed()
+    }
+
+    async fn list_tools(&self) -> Result<Vec<McpTool>> {
+        Ok(self.list_all("tools/list", "tools").await?
+            .into_iter()
+            .filter_map(|tool| Some(McpTool {
+                server_id: self.server_id,
+                server_name: self.server_name.clone(),
+                name: Self::text(&tool, "name")?,
+                description: Self::text(&tool, "description"),
+                input_schema: tool.get("inputSchema").cloned().unwrap_or_else(|| json!({ "type": "object" })),
+            }))
+            .collect())
+    }
+
+    async fn list_resources(&self) -> Result<Vec<McpResource>> {
+        Ok(self.list_all("resources/list", "resources").await?
+            .into_iter()
+            .filter_map(|resource| {
+                let uri = Self::text(&resource, "uri")?;
+                Some(McpResource {
+                    server_id: self.server_id,
+                    name: Self::text(&resource, "name").unwrap_or_else(|| uri.clone()),
+                    uri,
+                    description: Self::text(&resource, "description"),
+                    mime_type: Self::text(&resource, "mimeType"),
+                })
+            })
+            .collect())
+    }
+
+    async fn list_prompts(&self) -> Result<Vec<McpPrompt>> {
+        Ok(self.list_all("prompts/list", "prompts").await?
+            .into_iter()
+            .filter_map(|prompt| Some(McpPrompt {
+                server_id: self.server_id,
+                name: Self::text(&prompt, "name")?,
+                description: Self::text(&prompt, "description"),
+                arguments: prompt.get("arguments")
+                    .and_then(Value::as_array)
+                    .map(|args| args.iter().filter_map(|arg| Some(McpPromptArgument {
+                        name: Self::text(arg, "name")?,
+                        description: Self::text(arg, "description"),
+                        required: arg.get("required").and_then(Value::as_bool).unwrap_or(false),
+                    })).collect())
+                    .unwrap_or_default(),
+            }))
+            .collect())
+    }
+
+    async fn call_tool(&self, name: &str, arguments: Value) -> Result<McpToolResult> {
+        let arguments = if arguments.is_null() { json!({}) } else { arguments };
+        let result = self.transport.request("tools/call", json!({ "name": name, "arguments": arguments })).await?;
+
+        let mut content = result.get("content").map(Self::content_to_text).unwrap_or_default();
+        if content.is_empty() {
+            if let Some(structured) = result.get("structuredContent") {
+                content = structured.to_string();
+            }
+        }
+
+        Ok(McpToolResult {
+            content,
+            is_error: result.get("isError").and_then(Value::as_bool).unwrap_or(false),
+        })
+    }
+
+    async fn read_resource(&self, uri: &str) -> Result<String> {
+        let result = self.transport.request("resources/read", json!({ "uri": uri })).await?;
+
+        let contents = result.get("contents").and_then(Value::as_array).cloned().unwrap_or_default();
+        Ok(contents.iter()
+            .map(|c| Self::text(c, "text").unwrap_or_else(|| format!(
+                "[binary {} {}]",
+                Self::text(c, "uri").unwrap_or_default(),
+                Self::text(c, "mimeType").unwrap_or_default()
+            )))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    async fn get_prompt(&self, name: &str, arguments: HashMap<String, String>) -> Result<String> {
+        let result = self.transport.request("prompts/get", json!({ "name": name, "arguments": arguments })).await?;
+
+        let messages = result.get("messages").and_then(Value::as_array).cloned().unwrap_or_default();
+        Ok(messages.iter()
+            .map(|m| format!(
+                "{}: {}",
+                Self::text(m, "role").unwrap_or_else(|| "user".to_string()),
+                m.get("content").map(Self::content_to_text).unwrap_or_default()
+            ))
+            .collect::<Vec<_>>()
+            .join("\n\n"))
+    }
+
+    async fn close(&self) {
+        self.transport.close().await;
+    }
+}
@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use crate::domain::mcp::{
+    entity::McpServer,
+    repository::McpServerRepository,
+};
+
+pub struct SqliteMcpServerRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMcpServerRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn map_server(row: sqlx::sqlite::SqliteRow) -> Result<McpServer, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let user_id_str: String = row.get("user_id");
+        let transport_str: String = row.get("transport");
+        let args_str: String = row.get("args");
+        let env_str: String = row.get("env");
+
+        Ok(McpServer {
+            id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            user_id: Uuid::parse_str(&user_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            name: row.get("name"),
+            transport: transport_str.parse().map_err(|e: anyhow::Error| sqlx::Error::Decode(e.into()))?,
+            command: row.get("command"),
+            args: serde_json::from_str(&args_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            env: serde_json::from_str(&env_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            url: row.get("url"),
+            enabled: row.get("enabled"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl McpServerRepository for SqliteMcpServerRepository {
+    async fn create(&self, server: &McpServer) -> Result<McpServer> {
+        sqlx::query(
+            r#"
+            INSERT INTO mcp_servers (id, user_id, name, transport, command, args, env, url, enabled, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            "#
+        )
+        .bind(server.id.to_string())
+        .bind(server.user_id.to_string())
+        .bind(server.name.clone())
+        .bind(server.transport.as_str())
+        .bind(server.command.clone())
+        .bind(serde_json::to_string(&server.args)?)
+        .bind(serde_json::to_string(&server.env)?)
+        .bind(server.url.clone())
+        .bind(server.enabled)
+        .bind(server.created_at)
+        .bind(server.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save MCP server: {}", e))?;
+
+        Ok(server.clone())
+    }
+
+    async fn update(&self, server: &McpServer) -> Result<McpServer> {
+        sqlx::query(
+            r#"
+            UPDATE mcp_servers
+            SET name = ?2, transport = ?3, command = ?4, args = ?5, env = ?6, url = ?7, enabled = ?8, updated_at = ?9
+            WHERE id = ?1
+            "#
+        )
+        .bind(server.id.to_string())
+        .bind(server.name.clone())
+        .bind(server.transport.as_str())
+        .bind(server.command.clone())
+        .bind(serde_json::to_string(&server.args)?)
+        .bind(serde_json::to_string(&server.env)?)
+        .bind(server.url.clone())
+        .bind(server.enabled)
+        .bind(server.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to update MCP server: {}", e))?;
+
+        Ok(server.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<McpServer>> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, user_id, name, transport, command, args, env, url, enabled, created_at, updated_at
+            FROM mcp_servers
+            WHERE id = ?1
+            "#
+        )
+        .bind(id.to_string())
+        .try_map(Self::map_server)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<McpServer>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, user_id, name, transport, command, args, env, url, enabled, created_at, updated_at
+            FROM mcp_servers
+            WHERE user_id = ?1
+            ORDER BY name ASC
+            "#
+        )
+        .bind(user_id.to_string())
+        .try_map(Self::map_server)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM mcp_servers WHERE id = ?1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete MCP server: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use anyhow::{Result, anyhow};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, Command};
+use tokio::sync::{oneshot, Mutex};
+use super::transport::{self, McpTransport, REQUEST_TIMEOUT};
+
+type PendingMap = Arc<StdMutex<HashMap<u64, oneshot::Sender<Value>>>>;
+
+/// Runs an MCP server as a child process, exchanging newline-delimited JSON-RPC
+/// messages over its stdin/stdout. stderr is forwarded to the log.
+pub struct StdioTransport {
+    child: Mutex<Child>,
+    stdin: Arc<Mutex<ChildStdin>>,
+    pending: PendingMap,
+    next_id: AtomicU64,
+    tools_changed: Arc<AtomicBool>,
+}
+
+impl StdioTransport {
+    pub fn spawn(name: &str, command: &str, args: &[String], env: &HashMap<String, String>) -> Result<Self> {
+        let mut child = Command::new(command)
+            .args(args)
+            .envs(env)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| anyhow!("Failed to launch MCP server '{}' ({}): {}", name, command, e))?;
+
+        let stdin = Arc::new(Mutex::new(child.stdin.take().ok_or_else(|| anyhow!("MCP server stdin unavailable"))?));
+        let stdout = child.stdout.take().ok_or_else(|| anyhow!("MCP server stdout unavailable"))?;
+        let stderr = child.stderr.take().ok_or_else(|| anyhow!("MCP server stderr unavailable"))?;
+        let pending: PendingMap = Arc::new(StdMutex::new(HashMap::new()));
+        let tools_changed = Arc::new(AtomicBool::new(false));
+
+        // Reader: routes responses to their waiting request, answers server pings
+        // and notes when the tools change
+        let reader_pending = pending.clone();
+        let reader_tools_changed = tools_changed.clone();
+        let reader_stdin = stdin.clone();
+        let reader_name = name.to_string();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let message: Value = match serde_json::from_str(line) {
+                    Ok(message) => message,
+                    Err(e) => {
+                        log::warn!("MCP '{}' sent invalid JSON: {}", reader_name, e);
+                        continue;
+                    }
+                };
+
+                if let Some(id) = transport::response_id(&message) {
+                    if let Some(sender) = reader_pending.lock().unwrap().remove(&id) {
+                        let _ = sender.send(message);
+                    }
+                    continue;
+                }
+
+                if transport::is_notification(&message, transport::TOOLS_CHANGED) {
+                    reader_tools_changed.store(true, Ordering::SeqCst);
+                    continue;
+                }
+
+                // Server-to-client request: only ping is supported
+                if let (Some(id), Some(method)) = (message.get("id"), message.get("method").and_then(Value::as_str)) {
+                    let reply = if method == "ping" {
+                        json!({ "jsonrpc": "2.0", "id": id, "result": {} })
+                    } else {
+                        json!({ "jsonrpc": "2.0", "id": id, "error": { "code": -32601, "message": "Method not found" } })
+                    };
+                    let mut stdin = reader_stdin.lock().await;
+                    let _ = stdin.write_all(format!("{}\n", reply).as_bytes()).await;
+                    let _ = stdin.flush().await;
+                }
+            }
+
+            log::info!("MCP server '{}' closed its output", reader_name);
+            // Dropping the senders fails every request still waiting
+            reader_pending.lock().unwrap().clear();
+        });
+
+        let stderr_name = name.to_string();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stderr).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                log::debug!("[mcp:{}] {}", stderr_name, line);
+            }
+        });
+
+        Ok(Self {
+            child: Mutex::new(child),
+            stdin,
+            pending,
+            next_id: AtomicU64::new(1),
+            tools_changed,
+        })
+    }
+
+    async fn write(&self, message: &Value) -> Result<()> {
+        let mut stdin = self.stdin.lock().await;
+        stdin.write_all(format!("{}\n", message).as_bytes()).await
+            .map_err(|e| anyhow!("Failed to write to MCP server: {}", e))?;
+        stdin.flush().await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl McpTransport for StdioTransport {
+    async fn request(&self, method: &str, params: Value) -> Result<Value> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let (sender, receiver) = oneshot::channel();
+        self.pending.lock().unwrap().insert(id, sender);
+
+        if let Err(e) = self.write(&transport::request_message(id, method, params)).await {
+            self.pending.lock().unwrap().remove(&id);
+            return Err(e);
+        }
+
+        match tokio::time::timeout(REQUEST_TIMEOUT, receiver).await {
+            Ok(Ok(response)) => transport::into_result(response),
+            Ok(Err(_)) => Err(anyhow!("MCP server exited before answering '{}'", method)),
+            Err(_) => {
+                self.pending.lock().unwrap().remove(&id);
+                Err(anyhow!("MCP request '{}' timed out", method))
+            }
+        }
+    }
+
+    async fn notify(&self, method: &str, params: Value) -> Result<()> {
+        self.write(&transport::notification_message(method, params)).await
+    }
+
+    fn take_tools_changed(&self) -> bool {
+        self.tools_changed.swap(false, Ordering::SeqCst)
+    }
+
+    async fn close(&self) {
+        let mut child = self.child.lock().await;
+        if let Err(e) = child.kill().await {
+            log::warn!("Failed to stop MCP server process: {}", e);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use anyhow::{Result, anyhow};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// How long a single JSON-RPC request may take before it is abandoned.
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Notification a server sends when the tools it offers change.
+pub const TOOLS_CHANGED: &str = "notifications/tools/list_changed";
+
+/// JSON-RPC 2.0 channel to an MCP server.
+#[async_trait]
+pub trait McpTransport: Send + Sync {
+    async fn request(&self, method: &str, params: Value) -> Result<Value>;
+    async fn notify(&self, method: &str, params: Value) -> Result<()>;
+    /// Whether the server announced new tools since the last call.
+    fn take_tools_changed(&self) -> bool;
+    async fn close(&self);
+}
+
+pub fn request_message(id: u64, method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+pub fn notification_message(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+/// Extracts `result` from a JSON-RPC response, turning `error` into an `Err`.
+pub fn into_result(response: Value) -> Result<Value> {
+    if let Some(error) = response.get("error") {
+        let code = error.get("code").and_then(Value::as_i64).unwrap_or_default();
+        let message = error.get("message").and_then(Value::as_str).unwrap_or("Unknown error");
+        return Err(anyhow!("MCP error {}: {}", code, message));
+    }
+
+    Ok(response.get("result").cloned().unwrap_or(Value::Null))
+}
+
+/// Whether `message` is a notification (no id) of `method`.
+pub fn is_notification(message: &Value, method: &str) -> bool {
+    message.get("id").is_none() && message.get("method").and_then(Value::as_str) == Some(method)
+}
+
+/// Response id as u64; servers echo back the numeric ids we send.
+pub fn response_id(message: &Value) -> Option<u64> {
+    if message.get("result").is_none() && message.get("error").is_none() {
+        return None;
+    }
+    message.get("id").and_then(Value::as_u64)
+}
@@ -0,0 +1,199 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use crate::domain::ai::audio::transcript::Transcript;
+use crate::domain::meeting::{
+    entity::{Meeting, MeetingSummary},
+    repository::MeetingRepository,
+};
+
+const SELECT_COLUMNS: &str = r#"
+    SELECT id, user_id, title, audio_path, duration_ms, language, segments, calendar_event_id, chat_id,
+           started_at, ended_at, created_at, updated_at
+    FROM meetings
+"#;
+
+pub struct SqliteMeetingRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMeetingRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn parse_uuid(value: &str) -> Result<Uuid, sqlx::Error> {
+        Uuid::parse_str(value).map_err(|e| sqlx::Error::Decode(Box::new(e)))
+    }
+
+    fn parse_optional_uuid(value: Option<String>) -> Result<Option<Uuid>, sqlx::Error> {
+        value
+            .map(|s| Self::parse_uuid(&s))
+            .transpose()
+    }
+
+    fn map_meeting(row: sqlx::sqlite::SqliteRow) -> Result<Meeting, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let user_id_str: String = row.get("user_id");
+        let segments: String = row.get("segments");
+
+        Ok(Meeting {
+            id: Self::parse_uuid(&id_str)?,
+            user_id: Self::parse_uuid(&user_id_str)?,
+            title: row.get("title"),
+            audio_path: row.get("audio_path"),
+            duration_ms: row.get("duration_ms"),
+            language: row.get("language"),
+            segments: serde_json::from_str(&segments).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            calendar_event_id: Self::parse_optional_uuid(row.get("calendar_event_id"))?,
+            chat_id: Self::parse_optional_uuid(row.get("chat_id"))?,
+            started_at: row.get("started_at"),
+            ended_at: row.get("ended_at"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+
+    fn map_summary(row: sqlx::sqlite::SqliteRow) -> Result<MeetingSummary, sqlx::Error> {
+        let id_str: String = row.get("id");
+
+        Ok(MeetingSummary {
+            id: Self::parse_uuid(&id_str)?,
+            title: row.get("title"),
+            duration_ms: row.get("duration_ms"),
+            segment_count: row.get("segment_count"),
+            calendar_event_id: Self::parse_optional_uuid(row.get("calendar_event_id"))?,
+            chat_id: Self::parse_optional_uuid(row.get("chat_id"))?,
+            started_at: row.get("started_at"),
+            ended_at: row.get("ended_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl MeetingRepository for SqliteMeetingRepository {
+    async fn create(&self, meeting: &Meeting) -> Result<Meeting> {
+        sqlx::query(
+            r#"
+            INSERT INTO meetings (id, user_id, title, audio_path, duration_ms, language, segments, calendar_event_id,
+                                  chat_id, started_at, ended_at, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+            "#
+        )
+        .bind(meeting.id.to_string())
+        .bind(meeting.user_id.to_string())
+        .bind(meeting.title.clone())
+        .bind(meeting.audio_path.clone())
+        .bind(meeting.duration_ms)
+        .bind(meeting.language.clone())
+        .bind(serde_json::to_string(&meeting.segments)?)
+        .bind(meeting.calendar_event_id.map(|id| id.to_string()))
+        .bind(meeting.chat_id.map(|id| id.to_string()))
+        .bind(meeting.started_at)
+        .bind(meeting.ended_at)
+        .bind(meeting.created_at)
+        .bind(meeting.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save meeting: {}", e))?;
+
+        Ok(meeting.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Meeting>> {
+        let record = sqlx::query(&format!("{} WHERE id = ?1", SELECT_COLUMNS))
+            .bind(id.to_string())
+            .try_map(Self::map_meeting)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(record)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<MeetingSummary>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, title, duration_ms, json_array_length(segments) AS segment_count, calendar_event_id, chat_id,
+                   started_at, ended_at
+            FROM meetings
+            WHERE user_id = ?1
+            ORDER BY started_at DESC
+            "#
+        )
+        .bind(user_id.to_string())
+        .try_map(Self::map_summary)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn find_unfinished(&self, started_before: DateTime<Utc>) -> Result<Vec<Meeting>> {
+        let records = sqlx::query(&format!("{} WHERE ended_at IS NULL AND started_at < ?1", SELECT_COLUMNS))
+            .bind(started_before)
+            .try_map(Self::map_meeting)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(records)
+    }
+
+    async fn update_details(&self, id: Uuid, title: &str, calendar_event_id: Option<Uuid>) -> Result<()> {
+        sqlx::query("UPDATE meetings SET title = ?2, calendar_event_id = ?3, updated_at = CURRENT_TIMESTAMP WHERE id = ?1")
+            .bind(id.to_string())
+            .bind(title)
+            .bind(calendar_event_id.map(|id| id.to_string()))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to update meeting: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn finish_recording(&self, id: Uuid, duration_ms: i64, ended_at: DateTime<Utc>) -> Result<()> {
+        sqlx::query("UPDATE meetings SET duration_ms = ?2, ended_at = ?3, updated_at = CURRENT_TIMESTAMP WHERE id = ?1")
+            .bind(id.to_string())
+            .bind(duration_ms)
+            .bind(ended_at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to update meeting: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn save_transcript(&self, id: Uuid, transcript: &Transcript) -> Result<()> {
+        sqlx::query("UPDATE meetings SET language = ?2, segments = ?3, updated_at = CURRENT_TIMESTAMP WHERE id = ?1")
+            .bind(id.to_string())
+            .bind(Some(transcript.language.clone()).filter(|language| !language.is_empty()))
+            .bind(serde_json::to_string(&transcript.segments)?)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to save meeting transcript: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn set_chat(&self, id: Uuid, chat_id: Uuid) -> Result<()> {
+        sqlx::query("UPDATE meetings SET chat_id = ?2, updated_at = CURRENT_TIMESTAMP WHERE id = ?1")
+            .bind(id.to_string())
+            .bind(chat_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to update meeting: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM meetings WHERE id = ?1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete meeting: {}", e))?;
+
+        Ok(())
+    }
+}
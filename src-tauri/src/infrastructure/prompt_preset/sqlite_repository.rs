@@ -2,9 +2,11 @@ use async_trait::async_trait;
 use sqlx::SqlitePool;
 use anyhow::{Result, anyhow};
 use crate::domain::prompt_preset::{
-    entity::PromptPreset,
+    entity::{PromptPreset, PromptPresetVersion},
     repository::PromptPresetRepository,
 };
+use uuid::Uuid;
+use chrono::Utc;
 
 pub struct SqlitePromptPresetRepository {
     pool: SqlitePool,
@@ -21,7 +23,7 @@ impl PromptPresetRepository for SqlitePromptPresetRepository {
     async fn find_all(&self) -> Result<Vec<PromptPreset>> {
         let recs = sqlx::query_as::<_, PromptPreset>(
             r#"
-            SELECT id, name, description, prompt, is_built_in, preset_type, created_at, updated_at
+            SELECT id, name, description, prompt, is_built_in, preset_type, parameters, created_at, updated_at
             FROM prompt_presets
             ORDER BY created_at ASC
             "#
@@ -36,7 +38,7 @@ impl PromptPresetRepository for SqlitePromptPresetRepository {
     async fn find_by_type(&self, preset_type: &str) -> Result<Vec<PromptPreset>> {
         let recs = sqlx::query_as::<_, PromptPreset>(
             r#"
-            SELECT id, name, description, prompt, is_built_in, preset_type, created_at, updated_at
+            SELECT id, name, description, prompt, is_built_in, preset_type, parameters, created_at, updated_at
             FROM prompt_presets
             WHERE preset_type = ?1 OR (preset_type IS NULL AND ?2 = 'assistant')
             ORDER BY created_at ASC
@@ -54,7 +56,7 @@ impl PromptPresetRepository for SqlitePromptPresetRepository {
     async fn find_by_id(&self, id: &str) -> Result<Option<PromptPreset>> {
         let rec = sqlx::query_as::<_, PromptPreset>(
             r#"
-            SELECT id, name, description, prompt, is_built_in, preset_type, created_at, updated_at
+            SELECT id, name, description, prompt, is_built_in, preset_type, parameters, created_at, updated_at
             FROM prompt_presets
             WHERE id = ?1
             "#
@@ -70,8 +72,8 @@ impl PromptPresetRepository for SqlitePromptPresetRepository {
     async fn save(&self, preset: &PromptPreset) -> Result<PromptPreset> {
         sqlx::query(
             r#"
-            INSERT INTO prompt_presets (id, name, description, prompt, is_built_in, preset_type, created_at, updated_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            INSERT INTO prompt_presets (id, name, description, prompt, is_built_in, preset_type, parameters, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
             "#
         )
         .bind(preset.id.clone())
@@ -80,6 +82,7 @@ impl PromptPresetRepository for SqlitePromptPresetRepository {
         .bind(preset.prompt.clone())
         .bind(preset.is_built_in)
         .bind(preset.preset_type.clone())
+        .bind(serde_json::to_string(&preset.parameters)?)
         .bind(preset.created_at)
         .bind(preset.updated_at)
         .execute(&self.pool)
@@ -93,7 +96,7 @@ impl PromptPresetRepository for SqlitePromptPresetRepository {
         sqlx::query(
             r#"
             UPDATE prompt_presets
-            SET name = ?2, description = ?3, prompt = ?4, updated_at = ?5
+            SET name = ?2, description = ?3, prompt = ?4, parameters = ?5, updated_at = ?6
             WHERE id = ?1
             "#
         )
@@ -101,6 +104,7 @@ impl PromptPresetRepository for SqlitePromptPresetRepository {
         .bind(preset.name.clone())
         .bind(preset.description.clone())
         .bind(preset.prompt.clone())
+        .bind(serde_json::to_string(&preset.parameters)?)
         .bind(preset.updated_at)
         .execute(&self.pool)
         .await
@@ -137,4 +141,79 @@ impl PromptPresetRepository for SqlitePromptPresetRepository {
 
         Ok(())
     }
+
+    async fn save_version(&self, preset: &PromptPreset) -> Result<PromptPresetVersion> {
+        let next_version: i64 = sqlx::query_scalar(
+            "SELECT COALESCE(MAX(version), 0) + 1 FROM prompt_preset_versions WHERE preset_id = ?1"
+        )
+        .bind(preset.id.clone())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to read preset version: {}", e))?;
+
+        let version = PromptPresetVersion {
+            id: Uuid::new_v4().to_string(),
+            preset_id: preset.id.clone(),
+            version: next_version,
+            name: preset.name.clone(),
+            description: preset.description.clone(),
+            prompt: preset.prompt.clone(),
+            parameters: preset.parameters.clone(),
+            created_at: Utc::now(),
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO prompt_preset_versions (id, preset_id, version, name, description, prompt, parameters, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "#
+        )
+        .bind(version.id.clone())
+        .bind(version.preset_id.clone())
+        .bind(version.version)
+        .bind(version.name.clone())
+        .bind(version.description.clone())
+        .bind(version.prompt.clone())
+        .bind(serde_json::to_string(&version.parameters)?)
+        .bind(version.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save prompt preset version: {}", e))?;
+
+        Ok(version)
+    }
+
+    async fn find_versions(&self, preset_id: &str) -> Result<Vec<PromptPresetVersion>> {
+        let recs = sqlx::query_as::<_, PromptPresetVersion>(
+            r#"
+            SELECT id, preset_id, version, name, description, prompt, parameters, created_at
+            FROM prompt_preset_versions
+            WHERE preset_id = ?1
+            ORDER BY version DESC
+            "#
+        )
+        .bind(preset_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to fetch prompt preset versions: {}", e))?;
+
+        Ok(recs)
+    }
+
+    async fn find_version(&self, preset_id: &str, version: i64) -> Result<Option<PromptPresetVersion>> {
+        let rec = sqlx::query_as::<_, PromptPresetVersion>(
+            r#"
+            SELECT id, preset_id, version, name, description, prompt, parameters, created_at
+            FROM prompt_preset_versions
+            WHERE preset_id = ?1 AND version = ?2
+            "#
+        )
+        .bind(preset_id)
+        .bind(version)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to find prompt preset version: {}", e))?;
+
+        Ok(rec)
+    }
 }
@@ -0,0 +1,133 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use crate::domain::quick_action::{
+    entity::QuickAction,
+    repository::QuickActionRepository,
+};
+
+pub struct SqliteQuickActionRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteQuickActionRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn map_action(row: sqlx::sqlite::SqliteRow) -> Result<QuickAction, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let source_str: String = row.get("source");
+        let template_values_str: String = row.get("template_values");
+
+        Ok(QuickAction {
+            id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            name: row.get("name"),
+            shortcut: row.get("shortcut"),
+            prompt_preset_id: row.get("prompt_preset_id"),
+            source: source_str.parse().map_err(|e: anyhow::Error| sqlx::Error::Decode(e.into()))?,
+            template_values: serde_json::from_str(&template_values_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            provider_name: row.get("provider_name"),
+            model: row.get("model"),
+            enabled: row.get("enabled"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl QuickActionRepository for SqliteQuickActionRepository {
+    async fn create(&self, action: &QuickAction) -> Result<QuickAction> {
+        sqlx::query(
+            r#"
+            INSERT INTO quick_actions (id, name, shortcut, prompt_preset_id, source, template_values, provider_name, model, enabled, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            "#
+        )
+        .bind(action.id.to_string())
+        .bind(action.name.clone())
+        .bind(action.shortcut.clone())
+        .bind(action.prompt_preset_id.clone())
+        .bind(action.source.as_str())
+        .bind(serde_json::to_string(&action.template_values)?)
+        .bind(action.provider_name.clone())
+        .bind(action.model.clone())
+        .bind(action.enabled)
+        .bind(action.created_at)
+        .bind(action.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save quick action: {}", e))?;
+
+        Ok(action.clone())
+    }
+
+    async fn update(&self, action: &QuickAction) -> Result<QuickAction> {
+        sqlx::query(
+            r#"
+            UPDATE quick_actions
+            SET name = ?2, shortcut = ?3, prompt_preset_id = ?4, source = ?5, template_values = ?6,
+                provider_name = ?7, model = ?8, enabled = ?9, updated_at = ?10
+            WHERE id = ?1
+            "#
+        )
+        .bind(action.id.to_string())
+        .bind(action.name.clone())
+        .bind(action.shortcut.clone())
+        .bind(action.prompt_preset_id.clone())
+        .bind(action.source.as_str())
+        .bind(serde_json::to_string(&action.template_values)?)
+        .bind(action.provider_name.clone())
+        .bind(action.model.clone())
+        .bind(action.enabled)
+        .bind(action.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to update quick action: {}", e))?;
+
+        Ok(action.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<QuickAction>> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, name, shortcut, prompt_preset_id, source, template_values, provider_name, model, enabled, created_at, updated_at
+            FROM quick_actions
+            WHERE id = ?1
+            "#
+        )
+        .bind(id.to_string())
+        .try_map(Self::map_action)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    async fn find_all(&self) -> Result<Vec<QuickAction>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, name, shortcut, prompt_preset_id, source, template_values, provider_name, model, enabled, created_at, updated_at
+            FROM quick_actions
+            ORDER BY created_at ASC, name ASC
+            "#
+        )
+        .try_map(Self::map_action)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM quick_actions WHERE id = ?1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete quick action: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::retention::{
+    entity::{RetentionAction, RetentionCandidate, RetentionRule},
+    repository::RetentionRuleRepository,
+};
+
+pub struct SqliteRetentionRuleRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteRetentionRuleRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn map_rule(row: sqlx::sqlite::SqliteRow) -> Result<RetentionRule, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let user_id_str: String = row.get("user_id");
+        let action_str: String = row.get("action");
+
+        Ok(RetentionRule {
+            id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            user_id: Uuid::parse_str(&user_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            name: row.get("name"),
+            action: action_str.parse().map_err(|e: anyhow::Error| sqlx::Error::Decode(e.into()))?,
+            older_than_days: row.get("older_than_days"),
+            keep_pinned: row.get("keep_pinned"),
+            keep_tagged: row.get("keep_tagged"),
+            enabled: row.get("enabled"),
+            last_run_at: row.get("last_run_at"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+
+    fn map_candidate(row: sqlx::sqlite::SqliteRow) -> Result<RetentionCandidate, sqlx::Error> {
+        let id_str: String = row.get("id");
+
+        Ok(RetentionCandidate {
+            chat_id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            title: row.get("title"),
+            last_activity_at: row.get("updated_at"),
+            message_count: row.get("message_count"),
+        })
+    }
+}
+
+#[async_trait]
+impl RetentionRuleRepository for SqliteRetentionRuleRepository {
+    async fn create(&self, rule: &RetentionRule) -> Result<RetentionRule> {
+        sqlx::query(
+            r#"
+            INSERT INTO retention_rules (id, user_id, name, action, older_than_days, keep_pinned, keep_tagged, enabled, last_run_at, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            "#
+        )
+        .bind(rule.id.to_string())
+        .bind(rule.user_id.to_string())
+        .bind(rule.name.clone())
+        .bind(rule.action.as_str())
+        .bind(rule.older_than_days)
+        .bind(rule.keep_pinned)
+        .bind(rule.keep_tagged)
+        .bind(rule.enabled)
+        .bind(rule.last_run_at)
+        .bind(rule.created_at)
+        .bind(rule.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save retention rule: {}", e))?;
+
+        Ok(rule.clone())
+    }
+
+    async fn update(&self, rule: &RetentionRule) -> Result<RetentionRule> {
+        sqlx::query(
+            r#"
+            UPDATE retention_rules
+            SET name = ?2, action = ?3, older_than_days = ?4, keep_pinned = ?5, keep_tagged = ?6, enabled = ?7, updated_at = ?8
+            WHERE id = ?1 AND user_id = ?9
+            "#
+        )
+        .bind(rule.id.to_string())
+        .bind(rule.name.clone())
+        .bind(rule.action.as_str())
+        .bind(rule.older_than_days)
+        .bind(rule.keep_pinned)
+        .bind(rule.keep_tagged)
+        .bind(rule.enabled)
+        .bind(rule.updated_at)
+        .bind(rule.user_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to update retention rule: {}", e))?;
+
+        Ok(rule.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<RetentionRule>> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, user_id, name, action, older_than_days, keep_pinned, keep_tagged, enabled, last_run_at, created_at, updated_at
+            FROM retention_rules
+            WHERE id = ?1
+            "#
+        )
+        .bind(id.to_string())
+        .try_map(Self::map_rule)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<RetentionRule>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, user_id, name, action, older_than_days, keep_pinned, keep_tagged, enabled, last_run_at, created_at, updated_at
+            FROM retention_rules
+            WHERE user_id = ?1
+            ORDER BY created_at ASC
+            "#
+        )
+        .bind(user_id.to_string())
+        .try_map(Self::map_rule)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn find_enabled(&self) -> Result<Vec<RetentionRule>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, user_id, name, action, older_than_days, keep_pinned, keep_tagged, enabled, last_run_at, created_at, updated_at
+            FROM retention_rules
+            WHERE enabled = 1
+            ORDER BY created_at ASC
+            "#
+        )
+        .try_map(Self::map_rule)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn mark_run(&self, id: Uuid, ran_at: DateTime<Utc>) -> Result<()> {
+        sqlx::query("UPDATE retention_rules SET last_run_at = ?1 WHERE id = ?2")
+            .bind(ran_at)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to update retention rule: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool> {
+        let deleted = sqlx::query("DELETE FROM retention_rules WHERE id = ?1 AND user_id = ?2")
+            .bind(id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete retention rule: {}", e))?
+            .rows_affected();
+
+        Ok(deleted == 1)
+    }
+
+    async fn find_candidates(&self, rule: &RetentionRule, cutoff: DateTime<Utc>) -> Result<Vec<RetentionCandidate>> {
+        // Archive skips chats that are already archived; drop_content skips chats
+        // with no raw text left, so re-running a rule reports nothing new
+        let records = sqlx::query(
+            r#"
+            SELECT c.id, c.title, c.updated_at,
+                   (SELECT COUNT(*) FROM messages m WHERE m.chat_id = c.id) AS message_count
+            FROM chats c
+            WHERE c.user_id = ?1
+              AND c.updated_at < ?2
+              AND (?3 = 0 OR c.pinned = 0)
+              AND (?4 = 0 OR c.tags = '[]')
+              AND (?5 <> 'archive' OR c.archived_at IS NULL)
+              AND (?5 <> 'drop_content' OR EXISTS (
+                  SELECT 1 FROM messages m WHERE m.chat_id = c.id AND m.content <> ''
+              ))
+            ORDER BY c.updated_at ASC
+            "#
+        )
+        .bind(rule.user_id.to_string())
+        .bind(cutoff)
+        .bind(rule.keep_pinned)
+        .bind(rule.keep_tagged)
+        .bind(rule.action.as_str())
+        .try_map(Self::map_candidate)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn apply(&self, action: RetentionAction, chat_ids: &[Uuid]) -> Result<i64> {
+        let mut tx = self.pool.begin().await.map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+        let mut affected: i64 = 0;
+        let archived_at = Utc::now();
+
+        for chat_id in chat_ids {
+            match action {
+                RetentionAction::Delete => {
+                    affected += sqlx::query("DELETE FROM messages WHERE chat_id = ?1")
+                        .bind(chat_id.to_string())
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(|e| anyhow!("Failed to delete messages: {}", e))?
+                        .rows_affected() as i64;
+
+                    sqlx::query("DELETE FROM chats WHERE id = ?1")
+                        .bind(chat_id.to_string())
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(|e| anyhow!("Failed to delete chat: {}", e))?;
+                }
+                RetentionAction::Archive => {
+                    sqlx::query("UPDATE chats SET archived_at = ?1 WHERE id = ?2")
+                        .bind(archived_at)
+                        .bind(chat_id.to_string())
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(|e| anyhow!("Failed to archive chat: {}", e))?;
+                }
+                RetentionAction::DropContent => {
+                    // Summaries and importance stay for Smart RAG; the raw text goes
+                    affected += sqlx::query(
+                        "UPDATE messages SET content = '', follow_ups = NULL WHERE chat_id = ?1 AND summary IS NOT NULL AND content <> ''"
+                    )
+                        .bind(chat_id.to_string())
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(|e| anyhow!("Failed to clear message content: {}", e))?
+                        .rows_affected() as i64;
+
+                    affected += sqlx::query("DELETE FROM messages WHERE chat_id = ?1 AND summary IS NULL")
+                        .bind(chat_id.to_string())
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(|e| anyhow!("Failed to delete messages: {}", e))?
+                        .rows_affected() as i64;
+                }
+            }
+        }
+
+        tx.commit().await.map_err(|e| anyhow!("Failed to commit transaction: {}", e))?;
+
+        Ok(affected)
+    }
+}
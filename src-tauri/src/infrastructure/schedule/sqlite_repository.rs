@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::schedule::{
+    entity::ScheduledPrompt,
+    repository::ScheduledPromptRepository,
+};
+
+const SELECT_COLUMNS: &str = r#"
+    SELECT id, user_id, name, cron, prompt_preset_id, prompt, context_sources, provider_name, model, chat_id,
+           deliver_email, deliver_notification, catch_up, enabled, last_run_at, next_run_at, created_at, updated_at
+    FROM scheduled_prompts
+"#;
+
+pub struct SqliteScheduledPromptRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteScheduledPromptRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn map_schedule(row: sqlx::sqlite::SqliteRow) -> Result<ScheduledPrompt, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let user_id_str: String = row.get("user_id");
+        let chat_id_str: Option<String> = row.get("chat_id");
+        let context_sources_str: String = row.get("context_sources");
+        let catch_up_str: String = row.get("catch_up");
+
+        Ok(ScheduledPrompt {
+            id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            user_id: Uuid::parse_str(&user_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            name: row.get("name"),
+            cron: row.get("cron"),
+            prompt_preset_id: row.get("prompt_preset_id"),
+            prompt: row.get("prompt"),
+            context_sources: serde_json::from_str(&context_sources_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            provider_name: row.get("provider_name"),
+            model: row.get("model"),
+            chat_id: chat_id_str
+                .map(|s| Uuid::parse_str(&s))
+                .transpose()
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            deliver_email: row.get("deliver_email"),
+            deliver_notification: row.get("deliver_notification"),
+            catch_up: catch_up_str.parse().map_err(|e: anyhow::Error| sqlx::Error::Decode(e.into()))?,
+            enabled: row.get("enabled"),
+            last_run_at: row.get("last_run_at"),
+            next_run_at: row.get("next_run_at"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl ScheduledPromptRepository for SqliteScheduledPromptRepository {
+    async fn create(&self, schedule: &ScheduledPrompt) -> Result<ScheduledPrompt> {
+        sqlx::query(
+            r#"
+            INSERT INTO scheduled_prompts (id, user_id, name, cron, prompt_preset_id, prompt, context_sources, provider_name, model, chat_id,
+                                           deliver_email, deliver_notification, catch_up, enabled, last_run_at, next_run_at, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)
+            "#
+        )
+        .bind(schedule.id.to_string())
+        .bind(schedule.user_id.to_string())
+        .bind(schedule.name.clone())
+        .bind(schedule.cron.clone())
+        .bind(schedule.prompt_preset_id.clone())
+        .bind(schedule.prompt.clone())
+        .bind(serde_json::to_string(&schedule.context_sources)?)
+        .bind(schedule.provider_name.clone())
+        .bind(schedule.model.clone())
+        .bind(schedule.chat_id.map(|id| id.to_string()))
+        .bind(schedule.deliver_email)
+        .bind(schedule.deliver_notification)
+        .bind(schedule.catch_up.as_str())
+        .bind(schedule.enabled)
+        .bind(schedule.last_run_at)
+        .bind(schedule.next_run_at)
+        .bind(schedule.created_at)
+        .bind(schedule.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save scheduled prompt: {}", e))?;
+
+        Ok(schedule.clone())
+    }
+
+    async fn update(&self, schedule: &ScheduledPrompt) -> Result<ScheduledPrompt> {
+        sqlx::query(
+            r#"
+            UPDATE scheduled_prompts
+            SET name = ?2, cron = ?3, prompt_preset_id = ?4, prompt = ?5, context_sources = ?6, provider_name = ?7, model = ?8,
+                deliver_email = ?9, deliver_notification = ?10, catch_up = ?11, enabled = ?12, next_run_at = ?13, updated_at = ?14
+            WHERE id = ?1 AND user_id = ?15
+            "#
+        )
+        .bind(schedule.id.to_string())
+        .bind(schedule.name.clone())
+        .bind(schedule.cron.clone())
+        .bind(schedule.prompt_preset_id.clone())
+        .bind(schedule.prompt.clone())
+        .bind(serde_json::to_string(&schedule.context_sources)?)
+        .bind(schedule.provider_name.clone())
+        .bind(schedule.model.clone())
+        .bind(schedule.deliver_email)
+        .bind(schedule.deliver_notification)
+        .bind(schedule.catch_up.as_str())
+        .bind(schedule.enabled)
+        .bind(schedule.next_run_at)
+        .bind(schedule.updated_at)
+        .bind(schedule.user_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to update scheduled prompt: {}", e))?;
+
+        Ok(schedule.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ScheduledPrompt>> {
+        let record = sqlx::query(&format!("{} WHERE id = ?1", SELECT_COLUMNS))
+            .bind(id.to_string())
+            .try_map(Self::map_schedule)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(record)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<ScheduledPrompt>> {
+        let records = sqlx::query(&format!("{} WHERE user_id = ?1 ORDER BY created_at ASC", SELECT_COLUMNS))
+            .bind(user_id.to_string())
+            .try_map(Self::map_schedule)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(records)
+    }
+
+    async fn find_due(&self, now: DateTime<Utc>) -> Result<Vec<ScheduledPrompt>> {
+        let records = sqlx::query(&format!(
+            "{} WHERE enabled = 1 AND next_run_at IS NOT NULL AND next_run_at <= ?1 ORDER BY next_run_at ASC",
+            SELECT_COLUMNS
+        ))
+        .bind(now)
+        .try_map(Self::map_schedule)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn mark_run(&self, id: Uuid, last_run_at: Option<DateTime<Utc>>, next_run_at: Option<DateTime<Utc>>) -> Result<()> {
+        sqlx::query("UPDATE scheduled_prompts SET last_run_at = ?1, next_run_at = ?2 WHERE id = ?3")
+            .bind(last_run_at)
+            .bind(next_run_at)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to update scheduled prompt: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn set_chat_id(&self, id: Uuid, chat_id: Uuid) -> Result<()> {
+        sqlx::query("UPDATE scheduled_prompts SET chat_id = ?1 WHERE id = ?2")
+            .bind(chat_id.to_string())
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to update scheduled prompt: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool> {
+        let deleted = sqlx::query("DELETE FROM scheduled_prompts WHERE id = ?1 AND user_id = ?2")
+            .bind(id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete scheduled prompt: {}", e))?
+            .rows_affected();
+
+        Ok(deleted == 1)
+    }
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use crate::domain::task::{
+    entity::{Task, TaskStatus},
+    repository::TaskRepository,
+};
+
+const SELECT_COLUMNS: &str = r#"
+    SELECT id, user_id, chat_id, source_message_id, title, notes, due_at, due_all_day, status,
+           calendar_event_id, completed_at, created_at, updated_at
+    FROM tasks
+"#;
+
+pub struct SqliteTaskRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTaskRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn parse_optional_uuid(value: Option<String>) -> Result<Option<Uuid>, sqlx::Error> {
+        value
+            .map(|s| Uuid::parse_str(&s))
+            .transpose()
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))
+    }
+
+    fn map_task(row: sqlx::sqlite::SqliteRow) -> Result<Task, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let user_id_str: String = row.get("user_id");
+        let status_str: String = row.get("status");
+
+        Ok(Task {
+            id: Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            user_id: Uuid::parse_str(&user_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            chat_id: Self::parse_optional_uuid(row.get("chat_id"))?,
+            source_message_id: Self::parse_optional_uuid(row.get("source_message_id"))?,
+            title: row.get("title"),
+            notes: row.get("notes"),
+            due_at: row.get("due_at"),
+            due_all_day: row.get("due_all_day"),
+            status: status_str.parse().map_err(|e: anyhow::Error| sqlx::Error::Decode(e.into()))?,
+            calendar_event_id: Self::parse_optional_uuid(row.get("calendar_event_id"))?,
+            completed_at: row.get("completed_at"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl TaskRepository for SqliteTaskRepository {
+    async fn create(&self, task: &Task) -> Result<Task> {
+        sqlx::query(
+            r#"
+            INSERT INTO tasks (id, user_id, chat_id, source_message_id, title, notes, due_at, due_all_day, status,
+                               calendar_event_id, completed_at, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+            "#
+        )
+        .bind(task.id.to_string())
+        .bind(task.user_id.to_string())
+        .bind(task.chat_id.map(|id| id.to_string()))
+        .bind(task.source_message_id.map(|id| id.to_string()))
+        .bind(task.title.clone())
+        .bind(task.notes.clone())
+        .bind(task.due_at)
+        .bind(task.due_all_day)
+        .bind(task.status.as_str())
+        .bind(task.calendar_event_id.map(|id| id.to_string()))
+        .bind(task.completed_at)
+        .bind(task.created_at)
+        .bind(task.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save task: {}", e))?;
+
+        Ok(task.clone())
+    }
+
+    async fn update(&self, task: &Task) -> Result<Task> {
+        sqlx::query(
+            r#"
+            UPDATE tasks
+            SET title = ?2, notes = ?3, due_at = ?4, due_all_day = ?5, status = ?6,
+                calendar_event_id = ?7, completed_at = ?8, updated_at = ?9
+            WHERE id = ?1
+            "#
+        )
+        .bind(task.id.to_string())
+        .bind(task.title.clone())
+        .bind(task.notes.clone())
+        .bind(task.due_at)
+        .bind(task.due_all_day)
+        .bind(task.status.as_str())
+        .bind(task.calendar_event_id.map(|id| id.to_string()))
+        .bind(task.completed_at)
+        .bind(task.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to update task: {}", e))?;
+
+        Ok(task.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Task>> {
+        let record = sqlx::query(&format!("{} WHERE id = ?1", SELECT_COLUMNS))
+            .bind(id.to_string())
+            .try_map(Self::map_task)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(record)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid, status: Option<TaskStatus>) -> Result<Vec<Task>> {
+        let records = sqlx::query(&format!(
+            r#"{}
+            WHERE user_id = ?1 AND (?2 IS NULL OR status = ?2)
+            ORDER BY status = 'open' DESC, due_at IS NULL, due_at ASC, created_at DESC
+            "#,
+            SELECT_COLUMNS
+        ))
+        .bind(user_id.to_string())
+        .bind(status.map(|s| s.as_str()))
+        .try_map(Self::map_task)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn find_by_chat_id(&self, chat_id: Uuid) -> Result<Vec<Task>> {
+        let records = sqlx::query(&format!("{} WHERE chat_id = ?1 ORDER BY created_at ASC", SELECT_COLUMNS))
+            .bind(chat_id.to_string())
+            .try_map(Self::map_task)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(records)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM tasks WHERE id = ?1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete task: {}", e))?;
+
+        Ok(())
+    }
+}
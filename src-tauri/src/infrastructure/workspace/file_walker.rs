@@ -0,0 +1,76 @@
+use anyhow::{Result, anyhow};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// Directories that are never worth indexing when we can't ask git
+const FALLBACK_SKIPPED_DIRS: &[&str] = &["node_modules", "target", "dist", "build", "vendor", "__pycache__"];
+
+/// Lists files in a checkout as `/`-separated paths relative to `root`.
+/// Uses `git ls-files` so `.gitignore` (including nested and global excludes) is
+/// honoured exactly as git sees it; untracked-but-not-ignored files are included.
+pub fn list_files(root: &Path) -> Result<Vec<String>> {
+    if !root.is_dir() {
+        return Err(anyhow!("Workspace root is not a directory: {:?}", root));
+    }
+
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["ls-files", "-z", "--cached", "--others", "--exclude-standard"])
+        .output();
+
+    match output {
+        Ok(out) if out.status.success() => {
+            let files = out.stdout
+                .split(|&b| b == 0)
+                .filter(|p| !p.is_empty())
+                .map(|p| String::from_utf8_lossy(p).into_owned())
+                .collect();
+            Ok(files)
+        }
+        Ok(out) => {
+            log::warn!(
+                "[Workspace] git ls-files failed for {:?} ({}), falling back to directory walk",
+                root,
+                String::from_utf8_lossy(&out.stderr).trim()
+            );
+            walk_dir(root)
+        }
+        Err(e) => {
+            log::warn!("[Workspace] git not available ({}), falling back to directory walk", e);
+            walk_dir(root)
+        }
+    }
+}
+
+fn walk_dir(root: &Path) -> Result<Vec<String>> {
+    let mut files = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+
+    while let Some(dir) = stack.pop() {
+        for entry in fs::read_dir(&dir)?.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') {
+                continue;
+            }
+
+            let file_type = match entry.file_type() {
+                Ok(t) => t,
+                Err(_) => continue,
+            };
+
+            if file_type.is_dir() {
+                if !FALLBACK_SKIPPED_DIRS.contains(&name.as_str()) {
+                    stack.push(entry.path());
+                }
+            } else if file_type.is_file() {
+                if let Ok(relative) = entry.path().strip_prefix(root) {
+                    files.push(relative.to_string_lossy().replace('\\', "/"));
+                }
+            }
+        }
+    }
+
+    Ok(files)
+}
@@ -0,0 +1,2 @@
+pub mod file_walker;
+pub mod sqlite_repository;
@@ -0,0 +1,268 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::domain::workspace::{
+    entity::{Workspace, WorkspaceChunk, WorkspaceChunkMatch, WorkspaceFile},
+    repository::WorkspaceRepository,
+};
+use crate::shared::utils::fts_match_query;
+
+pub struct SqliteWorkspaceRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteWorkspaceRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn parse_uuid(value: &str) -> Result<Uuid, sqlx::Error> {
+        Uuid::parse_str(value).map_err(|e| sqlx::Error::Decode(Box::new(e)))
+    }
+
+    fn map_workspace(row: sqlx::sqlite::SqliteRow) -> Result<Workspace, sqlx::Error> {
+        let id_str: String = row.get("id");
+        let user_id_str: String = row.get("user_id");
+
+        Ok(Workspace {
+            id: Self::parse_uuid(&id_str)?,
+            user_id: Self::parse_uuid(&user_id_str)?,
+            name: row.get("name"),
+            root_path: row.get("root_path"),
+            last_indexed_at: row.get("last_indexed_at"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl WorkspaceRepository for SqliteWorkspaceRepository {
+    async fn create(&self, workspace: &Workspace) -> Result<Workspace> {
+        sqlx::query(
+            r#"
+            INSERT INTO workspaces (id, user_id, name, root_path, last_indexed_at, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#
+        )
+        .bind(workspace.id.to_string())
+        .bind(workspace.user_id.to_string())
+        .bind(workspace.name.clone())
+        .bind(workspace.root_path.clone())
+        .bind(workspace.last_indexed_at)
+        .bind(workspace.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| anyhow!("Failed to save workspace: {}", e))?;
+
+        Ok(workspace.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Workspace>> {
+        let record = sqlx::query(
+            r#"
+            SELECT id, user_id, name, root_path, last_indexed_at, created_at
+            FROM workspaces
+            WHERE id = ?1
+            "#
+        )
+        .bind(id.to_string())
+        .try_map(Self::map_workspace)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Workspace>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, user_id, name, root_path, last_indexed_at, created_at
+            FROM workspaces
+            WHERE user_id = ?1
+            ORDER BY created_at DESC
+            "#
+        )
+        .bind(user_id.to_string())
+        .try_map(Self::map_workspace)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn find_all(&self) -> Result<Vec<Workspace>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, user_id, name, root_path, last_indexed_at, created_at
+            FROM workspaces
+            "#
+        )
+        .try_map(Self::map_workspace)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn set_last_indexed_at(&self, id: Uuid, at: DateTime<Utc>) -> Result<()> {
+        sqlx::query("UPDATE workspaces SET last_indexed_at = ?1 WHERE id = ?2")
+            .bind(at)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool> {
+        let deleted = sqlx::query("DELETE FROM workspaces WHERE id = ?1 AND user_id = ?2")
+            .bind(id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| anyhow!("Failed to delete workspace: {}", e))?
+            .rows_affected();
+
+        Ok(deleted == 1)
+    }
+
+    async fn find_files(&self, workspace_id: Uuid) -> Result<Vec<WorkspaceFile>> {
+        let records = sqlx::query(
+            r#"
+            SELECT id, workspace_id, relative_path, language, mtime, content_hash
+            FROM workspace_files
+            WHERE workspace_id = ?1
+            "#
+        )
+        .bind(workspace_id.to_string())
+        .try_map(|row: sqlx::sqlite::SqliteRow| {
+            let id_str: String = row.get("id");
+            let workspace_id_str: String = row.get("workspace_id");
+
+            Ok(WorkspaceFile {
+                id: Self::parse_uuid(&id_str)?,
+                workspace_id: Self::parse_uuid(&workspace_id_str)?,
+                relative_path: row.get("relative_path"),
+                language: row.get("language"),
+                mtime: row.get("mtime"),
+                content_hash: row.get("content_hash"),
+            })
+        })
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    async fn upsert_file(&self, file: &WorkspaceFile, chunks: Option<&[WorkspaceChunk]>) -> Result<()> {
+        let mut tx = self.pool.begin().await.map_err(|e| anyhow!("Failed to begin transaction: {}", e))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO workspace_files (id, workspace_id, relative_path, language, mtime, content_hash)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            ON CONFLICT(id) DO UPDATE SET
+                language = excluded.language,
+                mtime = excluded.mtime,
+                content_hash = excluded.content_hash
+            "#
+        )
+        .bind(file.id.to_string())
+        .bind(file.workspace_id.to_string())
+        .bind(file.relative_path.clone())
+        .bind(file.language.clone())
+        .bind(file.mtime)
+        .bind(file.content_hash.clone())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| anyhow!("Failed to save workspace file: {}", e))?;
+
+        if let Some(chunks) = chunks {
+            sqlx::query("DELETE FROM workspace_chunks WHERE file_id = ?1")
+                .bind(file.id.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| anyhow!("Failed to clear workspace chunks: {}", e))?;
+
+            for chunk in chunks {
+                sqlx::query(
+                    r#"
+                    INSERT INTO workspace_chunks (id, workspace_id, file_id, symbol, content, start_line, end_line)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                    "#
+                )
+                .bind(chunk.id.to_string())
+                .bind(chunk.workspace_id.to_string())
+                .bind(chunk.file_id.to_string())
+                .bind(chunk.symbol.clone())
+                .bind(chunk.content.clone())
+                .bind(chunk.start_line)
+                .bind(chunk.end_line)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| anyhow!("Failed to save workspace chunk: {}", e))?;
+            }
+        }
+
+        tx.commit().await.map_err(|e| anyhow!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn delete_file(&self, file_id: Uuid) -> Result<()> {
+        sqlx::query("DELETE FROM workspace_files WHERE id = ?1")
+            .bind(file_id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn search_chunks(&self, workspace_id: Uuid, query: &str, limit: i64) -> Result<Vec<WorkspaceChunkMatch>> {
+        let Some(match_query) = fts_match_query(query) else {
+            return Ok(Vec::new());
+        };
+
+        // Path and symbol hits weigh more than body text
+        let records = sqlx::query(
+            r#"
+            SELECT c.id, c.workspace_id, c.file_id, c.symbol, c.content, c.start_line, c.end_line,
+                   wf.relative_path
+            FROM workspace_chunks_fts f
+            JOIN workspace_chunks c ON c.id = f.chunk_id
+            JOIN workspace_files wf ON wf.id = c.file_id
+            WHERE workspace_chunks_fts MATCH ?1
+              AND f.workspace_id = ?2
+            ORDER BY bm25(workspace_chunks_fts, 1.0, 4.0, 6.0)
+            LIMIT ?3
+            "#
+        )
+        .bind(match_query)
+        .bind(workspace_id.to_string())
+        .bind(limit)
+        .try_map(|row: sqlx::sqlite::SqliteRow| {
+            let id_str: String = row.get("id");
+            let workspace_id_str: String = row.get("workspace_id");
+            let file_id_str: String = row.get("file_id");
+
+            Ok(WorkspaceChunkMatch {
+                chunk: WorkspaceChunk {
+                    id: Self::parse_uuid(&id_str)?,
+                    workspace_id: Self::parse_uuid(&workspace_id_str)?,
+                    file_id: Self::parse_uuid(&file_id_str)?,
+                    symbol: row.get("symbol"),
+                    content: row.get("content"),
+                    start_line: row.get("start_line"),
+                    end_line: row.get("end_line"),
+                },
+                relative_path: row.get("relative_path"),
+            })
+        })
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(records)
+    }
+}
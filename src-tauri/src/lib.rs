@@ -9,3 +9,4 @@ pub mod stealth;
 pub mod clickthrough;
 pub mod visibility;
 pub mod mirror;
+pub mod selection;
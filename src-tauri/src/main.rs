@@ -2,8 +2,11 @@
 
 use app_lib::{
     app_state::AppState,
-    commands::{chat_commands, email_commands, user_commands, window_commands, screen_commands, config_commands, log_commands, prompt_preset_commands, audio_commands, whisper_commands, ollama_commands, changelog_commands, calendar_commands, notion_commands},
+    commands::{chat_commands, email_commands, user_commands, window_commands, screen_commands, config_commands, log_commands, prompt_preset_commands, audio_commands, whisper_commands, transcription_commands, ollama_commands, changelog_commands, calendar_commands, notion_commands, document_commands, workspace_commands, feedback_commands, mcp_commands, action_commands, quick_action_commands, retention_commands, schedule_commands, task_commands, meeting_commands},
     config::Config,
+    domain::workspace::usecase::index_workspace::IndexWorkspaceUseCase,
+    domain::retention::usecase::apply_retention::ApplyRetentionUseCase,
+    domain::meeting::usecase::manage_meeting::ManageMeetingUseCase,
     clickthrough,
     visibility,
     stealth,
@@ -169,6 +172,7 @@ async fn main() {
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_log::Builder::default().build())
         .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
             let _ = app.emit("tauri://deep-link", argv);
@@ -235,6 +239,11 @@ async fn main() {
             chat_commands::get_chats,
             chat_commands::get_messages,
             chat_commands::delete_chat,
+            chat_commands::compare_models,
+            chat_commands::get_message_alternatives,
+            chat_commands::pick_reply,
+            chat_commands::close_incognito_chat,
+            chat_commands::update_chat_organization,
             // email commands
             email_commands::send_email,
             email_commands::send_chat_summary,
@@ -255,6 +264,8 @@ async fn main() {
             config_commands::get_app_config,
             config_commands::set_language,
             config_commands::set_enable_smart_rag,
+            config_commands::set_tool_auto_approve,
+            config_commands::set_input_device,
             config_commands::open_system_settings,
             // prompt preset commands
             prompt_preset_commands::get_prompt_presets,
@@ -262,6 +273,11 @@ async fn main() {
             prompt_preset_commands::create_prompt_preset,
             prompt_preset_commands::update_prompt_preset,
             prompt_preset_commands::delete_prompt_preset,
+            prompt_preset_commands::export_prompt_presets,
+            prompt_preset_commands::import_prompt_presets,
+            prompt_preset_commands::get_prompt_preset_history,
+            prompt_preset_commands::diff_prompt_preset_versions,
+            prompt_preset_commands::rollback_prompt_preset,
             // Log commands
             log_commands::open_log_folder,
             log_commands::read_log_content,
@@ -280,10 +296,23 @@ async fn main() {
             audio_commands::start_recording,
             audio_commands::stop_recording,
             audio_commands::get_recording_status,
+            audio_commands::list_audio_devices,
+            audio_commands::get_vad_settings,
+            audio_commands::save_vad_settings,
             audio_commands::read_audio_file,
             // Whisper commands
             whisper_commands::transcribe_with_whisper,
+            whisper_commands::transcribe_segments_with_whisper,
+            whisper_commands::export_transcript,
+            transcription_commands::get_transcription_settings,
+            transcription_commands::save_transcription_settings,
+            transcription_commands::transcribe_audio_file,
+            whisper_commands::preload_whisper_model,
+            whisper_commands::unload_whisper_model,
             whisper_commands::check_whisper_models,
+            whisper_commands::cancel_whisper_download,
+            whisper_commands::delete_whisper_model,
+            whisper_commands::verify_whisper_model,
             whisper_commands::download_whisper_model,
             // Ollama commands
             ollama_commands::get_ollama_models,
@@ -303,6 +332,74 @@ async fn main() {
             notion_commands::delete_notion_page,
             notion_commands::update_notion_page,
             notion_commands::get_notion_page_content,
+            // Document commands
+            document_commands::ingest_document,
+            document_commands::get_documents,
+            document_commands::get_document_chunks,
+            document_commands::attach_document,
+            document_commands::delete_document,
+            // Workspace commands
+            workspace_commands::add_workspace,
+            workspace_commands::get_workspaces,
+            workspace_commands::reindex_workspace,
+            workspace_commands::delete_workspace,
+            workspace_commands::search_workspace,
+            // Feedback commands
+            feedback_commands::submit_message_feedback,
+            feedback_commands::clear_message_feedback,
+            feedback_commands::get_chat_feedback,
+            feedback_commands::get_feedback_stats,
+            // MCP commands
+            mcp_commands::add_mcp_server,
+            mcp_commands::update_mcp_server,
+            mcp_commands::get_mcp_servers,
+            mcp_commands::delete_mcp_server,
+            mcp_commands::test_mcp_server,
+            mcp_commands::read_mcp_resource,
+            mcp_commands::get_mcp_prompt,
+            // Action commands
+            action_commands::get_chat_actions,
+            action_commands::approve_action,
+            action_commands::reject_action,
+            action_commands::get_action_log,
+            action_commands::undo_action,
+            // Quick action commands
+            quick_action_commands::get_quick_actions,
+            quick_action_commands::create_quick_action,
+            quick_action_commands::update_quick_action,
+            quick_action_commands::delete_quick_action,
+            quick_action_commands::run_quick_action,
+            // Retention commands
+            retention_commands::get_retention_rules,
+            retention_commands::add_retention_rule,
+            retention_commands::update_retention_rule,
+            retention_commands::delete_retention_rule,
+            retention_commands::preview_retention,
+            retention_commands::apply_retention,
+            // Scheduled prompt commands
+            schedule_commands::get_scheduled_prompts,
+            schedule_commands::add_scheduled_prompt,
+            schedule_commands::update_scheduled_prompt,
+            schedule_commands::delete_scheduled_prompt,
+            schedule_commands::preview_schedule,
+            schedule_commands::run_scheduled_prompt_now,
+            // Task commands
+            task_commands::get_tasks,
+            task_commands::get_chat_tasks,
+            task_commands::add_task,
+            task_commands::update_task,
+            task_commands::set_task_status,
+            task_commands::delete_task,
+            task_commands::extract_tasks,
+            task_commands::push_task_to_calendar,
+            // Meeting commands
+            meeting_commands::list_meetings,
+            meeting_commands::get_meeting,
+            meeting_commands::get_meeting_audio,
+            meeting_commands::update_meeting,
+            meeting_commands::retranscribe_meeting,
+            meeting_commands::delete_meeting,
+            meeting_commands::meeting_to_chat,
         ])
         .setup(move |app| {
             let handle = app.handle().clone();
@@ -342,6 +439,100 @@ async fn main() {
                 }
             });
 
+            // Keep registered workspaces fresh; unchanged files are skipped by mtime
+            let workspace_repo = app.state::<AppState>().workspace_repo.clone();
+            tauri::async_runtime::spawn(async move {
+                let index_workspace_usecase = IndexWorkspaceUseCase::new(workspace_repo);
+                loop {
+                    sleep(Duration::from_secs(300)).await;
+                    if let Err(e) = index_workspace_usecase.refresh_all().await {
+                        log::warn!("[Workspace] Background refresh failed: {}", e);
+                    }
+                }
+            });
+
+            // Meetings whose recording was cut off by a crash never got an end
+            let manage_meeting_usecase = ManageMeetingUseCase::new(
+                app.state::<AppState>().meeting_repo.clone(),
+                app.state::<AppState>().calendar_repo.clone(),
+            );
+            let started_at = chrono::Utc::now();
+            tauri::async_runtime::spawn(async move {
+                match manage_meeting_usecase.finish_interrupted(started_at).await {
+                    Ok(0) => {}
+                    Ok(count) => log::info!("[Meeting] Finished {} interrupted recording(s)", count),
+                    Err(e) => log::warn!("[Meeting] Failed to finish interrupted recordings: {}", e),
+                }
+            });
+
+            // Retention rules: first pass shortly after startup, then hourly
+            let retention_rule_repo = app.state::<AppState>().retention_rule_repo.clone();
+            tauri::async_runtime::spawn(async move {
+                let apply_retention_usecase = ApplyRetentionUseCase::new(retention_rule_repo);
+                sleep(Duration::from_secs(60)).await;
+                loop {
+                    if let Err(e) = apply_retention_usecase.apply_all().await {
+                        log::warn!("[Retention] Background cleanup failed: {}", e);
+                    }
+                    sleep(Duration::from_secs(3600)).await;
+                }
+            });
+
+            // Scheduled prompts: occurrences missed while the app was closed are handled
+            // by each prompt's catch-up policy on the first tick
+            let schedule_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                sleep(Duration::from_secs(20)).await;
+                loop {
+                    let run_scheduled_prompt_usecase = schedule_commands::run_scheduled_prompt_usecase(&schedule_handle.state::<AppState>());
+                    match run_scheduled_prompt_usecase.run_due(chrono::Utc::now()).await {
+                        Ok(outcomes) => {
+                            for (schedule, result) in outcomes {
+                                match result {
+                                    Ok(run) => {
+                                        if run.notify {
+                                            schedule_commands::show_run_notification(&schedule_handle, &run);
+                                        }
+                                        let _ = schedule_handle.emit("scheduled-prompt-result", run);
+                                    }
+                                    Err(e) => {
+                                        log::warn!("[Schedule] '{}' failed: {}", schedule.name, e);
+                                        let _ = schedule_handle.emit("scheduled-prompt-error", schedule_commands::ScheduledRunError {
+                                            schedule_id: schedule.id.to_string(),
+                                            schedule_name: schedule.name.clone(),
+                                            error: e.to_string(),
+                                        });
+                                    }
+                                }
+                            }
+                        }
+                        Err(e) => log::warn!("[Schedule] Scheduler tick failed: {}", e),
+                    }
+                    sleep(Duration::from_secs(30)).await;
+                }
+            });
+
+            // Microphones come and go (headsets); the UI refreshes its device list
+            audio_commands::watch_audio_devices(app.handle().clone());
+
+            // Proposed actions wait for the user; resolved ones refresh the chat
+            let action_handle = app.handle().clone();
+            app.state::<AppState>().action_notifier.set_callback(move |action| {
+                let _ = action_handle.emit("proposed-action", action);
+            });
+
+            // Quick actions: global shortcuts that run a preset on the clipboard/selection
+            let quick_action_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                match quick_action_commands::reload_quick_action_shortcuts(&quick_action_handle).await {
+                    Ok(failed) if !failed.is_empty() => {
+                        log::warn!("[QuickAction] Shortcuts already in use: {}", failed.join(", "));
+                    }
+                    Ok(_) => {}
+                    Err(e) => log::error!("[QuickAction] Failed to load quick actions: {}", e),
+                }
+            });
+
             Ok(())
         })
         .run(tauri::generate_context!())
@@ -0,0 +1,43 @@
+use std::time::Duration;
+use tokio::process::Command;
+
+/// How long a helper may take; xclip waits forever on a selection owner that
+/// never answers.
+const HELPER_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Reads the PRIMARY selection (the last highlighted text) through the first
+/// available helper: `wl-paste` on Wayland, then `xclip` / `xsel` on X11.
+pub async fn read_primary_selection() -> Option<String> {
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+
+    let mut helpers: Vec<(&str, &[&str])> = Vec::new();
+    if wayland {
+        helpers.push(("wl-paste", &["--primary", "--no-newline"]));
+    }
+    helpers.push(("xclip", &["-o", "-selection", "primary"]));
+    helpers.push(("xsel", &["--output", "--primary"]));
+
+    for (program, args) in helpers {
+        // kill_on_drop stops a helper that is still running when the timeout fires
+        let output = Command::new(program).args(args).kill_on_drop(true).output();
+        let output = match tokio::time::timeout(HELPER_TIMEOUT, output).await {
+            Ok(output) => output,
+            Err(_) => {
+                log::warn!("[Selection] {} timed out", program);
+                continue;
+            }
+        };
+        match output {
+            Ok(output) if output.status.success() => {
+                let text = String::from_utf8_lossy(&output.stdout).to_string();
+                if !text.trim().is_empty() {
+                    return Some(text);
+                }
+            }
+            Ok(_) => {}
+            Err(e) => log::debug!("[Selection] {} unavailable: {}", program, e),
+        }
+    }
+
+    None
+}
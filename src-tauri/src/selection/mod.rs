@@ -0,0 +1,29 @@
+// src-tauri/src/selection/mod.rs
+
+#[cfg(target_os = "linux")]
+pub mod linux;
+
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use crate::domain::quick_action::entity::QuickActionSource;
+
+/// Reads the text a quick action works on. `Selection` uses the PRIMARY
+/// selection on Linux and falls back to the clipboard when it is empty or
+/// unavailable; other platforms have no selection buffer, so it reads the clipboard.
+pub async fn read_text<R: Runtime>(app: &AppHandle<R>, source: QuickActionSource) -> Result<String, String> {
+    if source == QuickActionSource::Selection {
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(text) = linux::read_primary_selection().await {
+                return Ok(text);
+            }
+        }
+    }
+
+    let text = app.clipboard().read_text().map_err(|e| e.to_string())?;
+    if text.trim().is_empty() {
+        return Err("The clipboard is empty".to_string());
+    }
+    Ok(text)
+}
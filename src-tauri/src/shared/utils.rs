@@ -0,0 +1,21 @@
+/// Turns free text into an FTS5 query: distinct words OR'ed together, each
+/// quoted so punctuation and FTS operators in the prompt can't break the syntax.
+pub fn fts_match_query(query: &str) -> Option<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in query.split(|c: char| !c.is_alphanumeric() && c != '_') {
+        let word = word.to_lowercase();
+        if word.chars().count() < 3 || terms.contains(&word) {
+            continue;
+        }
+        terms.push(word);
+        if terms.len() >= 16 {
+            break;
+        }
+    }
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.iter().map(|t| format!("\"{}\"", t)).collect::<Vec<_>>().join(" OR "))
+    }
+}